@@ -4,22 +4,27 @@ use std::task::{Context, Poll};
 
 use anyhow::anyhow;
 use async_trait::async_trait;
+use avail_subxt::api::runtime_types::da_control::pallet::Call;
+use avail_subxt::api::runtime_types::da_runtime::RuntimeCall::DataAvailability;
 use avail_subxt::api::runtime_types::sp_core::bounded::bounded_vec::BoundedVec;
-use avail_subxt::primitives::AvailExtrinsicParams;
+use avail_subxt::primitives::{AppUncheckedExtrinsic, AvailExtrinsicParams};
 use avail_subxt::{api, AvailConfig};
+use codec::Decode;
 use pin_project::pin_project;
 use reqwest::StatusCode;
 use sov_rollup_interface::da::DaSpec;
 use sov_rollup_interface::services::da::DaService;
 use sp_core::crypto::Pair as PairTrait;
 use sp_keyring::sr25519::sr25519::Pair;
-use subxt::tx::PairSigner;
+use subxt::tx::{PairSigner, TxProgress};
+use subxt::utils::H256;
 use subxt::OnlineClient;
 use tracing::info;
 
 use crate::avail::{Confidence, ExtrinsicsData};
 use crate::spec::block::AvailBlock;
 use crate::spec::header::AvailHeader;
+use crate::spec::proof::{extraction_proofs, DataLeaf};
 use crate::spec::transaction::AvailBlobTransaction;
 use crate::spec::DaLayerSpec;
 use crate::verifier::Verifier;
@@ -34,6 +39,8 @@ pub struct DaServiceConfig {
     pub polling_timeout: Option<u64>,
     pub polling_interval: Option<u64>,
     pub app_id: u32,
+    /// The app id the aggregated proofs are submitted to, kept apart from the rollup blobs.
+    pub proof_app_id: u32,
 }
 
 const DEFAULT_POLLING_TIMEOUT: Duration = Duration::from_secs(60);
@@ -47,6 +54,7 @@ pub struct DaProvider {
     polling_timeout: Duration,
     polling_interval: Duration,
     app_id: u32,
+    proof_app_id: u32,
 }
 
 impl DaProvider {
@@ -82,8 +90,74 @@ impl DaProvider {
                 None => DEFAULT_POLLING_INTERVAL,
             },
             app_id: config.app_id,
+            proof_app_id: config.proof_app_id,
         }
     }
+
+    /// Returns the SCALE-encoded extrinsics of the block `hash`.
+    async fn get_extrinsics(&self, hash: H256) -> anyhow::Result<Vec<Vec<u8>>> {
+        let block = match { self.node_client.rpc().block(Some(hash)).await? } {
+            Some(i) => i,
+            None => return Err(anyhow!("Block for hash: {} not found.", hash)),
+        };
+
+        Ok(block
+            .block
+            .extrinsics
+            .into_iter()
+            .map(|extrinsic| extrinsic.0)
+            .collect())
+    }
+
+    /// Returns the data submitted in the block `hash`, along with the app ids it was submitted to.
+    async fn get_submitted_data(&self, hash: H256) -> anyhow::Result<Vec<(u32, Vec<u8>)>> {
+        submitted_data(&self.get_extrinsics(hash).await?)
+    }
+
+    async fn get_block_hash(&self, height: u64) -> anyhow::Result<H256> {
+        self.node_client
+            .rpc()
+            .block_hash(Some(height.into()))
+            .await?
+            .ok_or_else(|| anyhow!("Hash for height: {} not found.", height))
+    }
+
+    async fn submit_data(
+        &self,
+        data: &[u8],
+        app_id: u32,
+    ) -> anyhow::Result<TxProgress<AvailConfig, OnlineClient<AvailConfig>>> {
+        let data_transfer = api::tx()
+            .data_availability()
+            .submit_data(BoundedVec(data.to_vec()));
+
+        let extrinsic_params = AvailExtrinsicParams::new_with_app_id(app_id.into());
+
+        let h = self
+            .node_client
+            .tx()
+            .sign_and_submit_then_watch(&data_transfer, &self.signer, extrinsic_params)
+            .await?;
+
+        info!("Transaction submitted: {:#?}", h.extrinsic_hash());
+
+        Ok(h)
+    }
+}
+
+fn submitted_data(extrinsics: &[Vec<u8>]) -> anyhow::Result<Vec<(u32, Vec<u8>)>> {
+    let mut submitted_data = Vec::new();
+    for extrinsic in extrinsics {
+        let extrinsic = AppUncheckedExtrinsic::decode(&mut &extrinsic[..])?;
+        if let DataAvailability(Call::submit_data { data }) = extrinsic.function {
+            let app_id = extrinsic
+                .signature
+                .map(|(_, _, extra)| extra.app_id.0)
+                .unwrap_or_default();
+            submitted_data.push((app_id, data.0));
+        }
+    }
+    Ok(submitted_data)
 }
 
 // TODO: Is there a way to avoid coupling to tokio?
@@ -209,10 +283,7 @@ impl DaService for DaProvider {
         .await?;
         info!("Appdata: {:?}", appdata);
 
-        let hash = match { node_client.rpc().block_hash(Some(height.into())).await? } {
-            Some(i) => i,
-            None => return Err(anyhow!("Hash for height: {} not found.", height)),
-        };
+        let hash = self.get_block_hash(height).await?;
 
         let header = match { node_client.rpc().header(Some(hash)).await? } {
             Some(i) => i,
@@ -227,9 +298,16 @@ impl DaService for DaProvider {
             .collect();
 
         let transactions = transactions?;
+        let extrinsics = self.get_extrinsics(hash).await?;
+        let data_leaves = submitted_data(&extrinsics)?
+            .iter()
+            .map(|(app_id, data)| DataLeaf::new(*app_id, data))
+            .collect();
         Ok(AvailBlock {
             header,
             transactions,
+            data_leaves,
+            extrinsics,
         })
     }
 
@@ -276,41 +354,48 @@ impl DaService for DaProvider {
 
     // Extract the inclusion and completeness proof for filtered block provided.
     // The output of this method will be passed to the verifier.
-    // NOTE: The light client here has already completed DA sampling and verification of inclusion and soundness.
+    // The proofs are built from the leaves of the data root of the block: a Merkle proof for every blob,
+    // and every data submission of the block, with the full data of the rollup blobs.
+    // The extrinsic of every blob is also proven against the extrinsics root, to authenticate its sender.
     async fn get_extraction_proof(
         &self,
-        _block: &Self::FilteredBlock,
-        _blobs: &[<Self::Spec as DaSpec>::BlobTransaction],
+        block: &Self::FilteredBlock,
+        blobs: &[<Self::Spec as DaSpec>::BlobTransaction],
     ) -> (
         <Self::Spec as DaSpec>::InclusionMultiProof,
         <Self::Spec as DaSpec>::CompletenessProof,
     ) {
-        ((), ())
+        extraction_proofs(&block.data_leaves, &block.extrinsics, self.app_id, blobs)
     }
 
     async fn send_transaction(&self, blob: &[u8]) -> Result<(), Self::Error> {
-        let data_transfer = api::tx()
-            .data_availability()
-            .submit_data(BoundedVec(blob.to_vec()));
-
-        let extrinsic_params = AvailExtrinsicParams::new_with_app_id(self.app_id.into());
-
-        let h = self
-            .node_client
-            .tx()
-            .sign_and_submit_then_watch(&data_transfer, &self.signer, extrinsic_params)
-            .await?;
-
-        info!("Transaction submitted: {:#?}", h.extrinsic_hash());
-
+        self.submit_data(blob, self.app_id).await?;
         Ok(())
     }
 
-    async fn send_aggregated_zk_proof(&self, _proof: &[u8]) -> Result<u64, Self::Error> {
-        unimplemented!()
+    async fn send_aggregated_zk_proof(&self, proof: &[u8]) -> Result<u64, Self::Error> {
+        let events = self
+            .submit_data(proof, self.proof_app_id)
+            .await?
+            .wait_for_finalized_success()
+            .await?;
+        let block_hash = events.block_hash();
+        let header = match { self.node_client.rpc().header(Some(block_hash)).await? } {
+            Some(i) => i,
+            None => return Err(anyhow!("Header for hash: {} not found.", block_hash)),
+        };
+        Ok(header.number as u64)
     }
 
-    async fn get_aggregated_proofs_at(&self, _height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
-        unimplemented!()
+    async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
+        let hash = self.get_block_hash(height).await?;
+        let proofs = self
+            .get_submitted_data(hash)
+            .await?
+            .into_iter()
+            .filter(|(app_id, _)| *app_id == self.proof_app_id)
+            .map(|(_, data)| data)
+            .collect();
+        Ok(proofs)
     }
 }
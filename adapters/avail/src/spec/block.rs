@@ -3,6 +3,7 @@ use sov_rollup_interface::da::BlockHeaderTrait;
 use sov_rollup_interface::services::da::SlotData;
 
 use super::header::AvailHeader;
+use super::proof::DataLeaf;
 use super::transaction::AvailBlobTransaction;
 use crate::verifier::ChainValidityCondition;
 
@@ -10,6 +11,10 @@ use crate::verifier::ChainValidityCondition;
 pub struct AvailBlock {
     pub header: AvailHeader,
     pub transactions: Vec<AvailBlobTransaction>,
+    /// The leaves of the data root of the block, for the data submitted to every application.
+    pub data_leaves: Vec<DataLeaf>,
+    /// The SCALE-encoded extrinsics of the block, which the extrinsics root commits to.
+    pub extrinsics: Vec<Vec<u8>>,
 }
 
 impl SlotData for AvailBlock {
@@ -1,9 +1,15 @@
 #[cfg(feature = "native")]
+use avail_subxt::api::runtime_types::da_primitives::header::extension::HeaderExtension;
+#[cfg(feature = "native")]
 use avail_subxt::primitives::Header as SubxtHeader;
+#[cfg(feature = "native")]
+use codec::Encode;
 use primitive_types::H256;
 use serde::{Deserialize, Serialize};
 use sov_rollup_interface::da::BlockHeaderTrait;
 
+use super::scale::{encode_bytes, encode_compact};
+
 const KATE_START_TIME: i64 = 1686066440;
 const KATE_SECONDS_PER_BLOCK: i64 = 20;
 
@@ -15,6 +21,108 @@ pub struct Header {
     pub state_root: H256,
     pub extrinsics_root: H256,
     pub data_root: H256,
+    pub app_lookup: DataLookup,
+    /// The SCALE-encoded digest of the block, only needed to compute its hash.
+    pub digest: Vec<u8>,
+    /// The Kate commitment of the header extension, only needed to compute the hash of the block.
+    pub kate_commitment: KateCommitment,
+}
+
+impl Header {
+    /// Computes the hash of the header, which commits to all its fields,
+    /// including the data root and the app lookup.
+    pub fn hash(&self) -> H256 {
+        H256(sp_core_hashing::blake2_256(&self.encode()))
+    }
+
+    /// The SCALE encoding of the header, as in Avail v1.6.
+    fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(self.parent_hash.as_bytes());
+        encode_compact(self.number.into(), &mut out);
+        out.extend_from_slice(self.state_root.as_bytes());
+        out.extend_from_slice(self.extrinsics_root.as_bytes());
+        out.extend_from_slice(&self.digest);
+
+        // The header extension is an enum, of which the version is the variant.
+        let kate_commitment = &self.kate_commitment;
+        match kate_commitment.version {
+            ExtensionVersion::V1 => {
+                out.push(0);
+                encode_compact(kate_commitment.rows.into(), &mut out);
+                encode_compact(kate_commitment.cols.into(), &mut out);
+                out.extend_from_slice(self.data_root.as_bytes());
+                encode_bytes(&kate_commitment.commitment, &mut out);
+            }
+            ExtensionVersion::V2 => {
+                out.push(1);
+                encode_compact(kate_commitment.rows.into(), &mut out);
+                encode_compact(kate_commitment.cols.into(), &mut out);
+                encode_bytes(&kate_commitment.commitment, &mut out);
+                out.extend_from_slice(self.data_root.as_bytes());
+            }
+        }
+
+        encode_compact(self.app_lookup.size.into(), &mut out);
+        encode_compact(self.app_lookup.index.len() as u128, &mut out);
+        for item in &self.app_lookup.index {
+            encode_compact(item.app_id.into(), &mut out);
+            encode_compact(item.start.into(), &mut out);
+        }
+        out
+    }
+}
+
+/// The version of the header extension, which sets the layout of the Kate commitment.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum ExtensionVersion {
+    #[default]
+    V1,
+    V2,
+}
+
+/// The Kate commitment of the header extension, apart from the data root.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct KateCommitment {
+    pub version: ExtensionVersion,
+    pub rows: u16,
+    pub cols: u16,
+    pub commitment: Vec<u8>,
+}
+
+/// The layout of the data of a block, which is grouped by application:
+/// the range of each application starts at its `start` and ends at the next one, or at `size`.
+/// The data submitted without app id comes before the first range.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+pub struct DataLookup {
+    /// The number of data submissions of the block.
+    pub size: u32,
+    /// The ranges of the applications, ordered by start.
+    pub index: Vec<DataLookupIndexItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DataLookupIndexItem {
+    pub app_id: u32,
+    pub start: u32,
+}
+
+impl DataLookup {
+    /// Returns the application of the data submission at `index`,
+    /// or `None` if the index is beyond the data of the block.
+    pub fn app_id_at(&self, index: u32) -> Option<u32> {
+        if index >= self.size {
+            return None;
+        }
+        let app_id = self
+            .index
+            .iter()
+            .take_while(|item| item.start <= index)
+            .last()
+            .map_or(0, |item| item.app_id);
+        Some(app_id)
+    }
 }
 
 use super::hash::AvailHash;
@@ -37,11 +145,62 @@ impl AvailHeader {
                 state_root: header.state_root,
                 data_root: header.data_root(),
                 extrinsics_root: header.extrinsics_root,
+                app_lookup: app_lookup(&header),
+                digest: header.digest.encode(),
+                kate_commitment: kate_commitment(&header),
             },
         }
     }
 }
 
+#[cfg(feature = "native")]
+fn kate_commitment(header: &SubxtHeader) -> KateCommitment {
+    match &header.extension {
+        HeaderExtension::V1(extension) => KateCommitment {
+            version: ExtensionVersion::V1,
+            rows: extension.commitment.rows,
+            cols: extension.commitment.cols,
+            commitment: extension.commitment.commitment.clone(),
+        },
+        HeaderExtension::V2(extension) => KateCommitment {
+            version: ExtensionVersion::V2,
+            rows: extension.commitment.rows,
+            cols: extension.commitment.cols,
+            commitment: extension.commitment.commitment.clone(),
+        },
+    }
+}
+
+#[cfg(feature = "native")]
+fn app_lookup(header: &SubxtHeader) -> DataLookup {
+    let app_lookup = match &header.extension {
+        HeaderExtension::V1(extension) => &extension.app_lookup,
+        HeaderExtension::V2(extension) => &extension.app_lookup,
+    };
+
+    DataLookup {
+        size: app_lookup.size,
+        index: app_lookup
+            .index
+            .iter()
+            .map(|item| DataLookupIndexItem {
+                app_id: item.app_id.0,
+                start: item.start,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+impl AvailHeader {
+    pub(crate) fn new_for_testing(header: Header) -> Self {
+        Self {
+            hash: AvailHash::new(header.hash()),
+            header,
+        }
+    }
+}
+
 impl BlockHeaderTrait for AvailHeader {
     type Hash = AvailHash;
 
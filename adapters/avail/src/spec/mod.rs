@@ -1,11 +1,13 @@
 use sov_rollup_interface::da::DaSpec;
 
-use crate::verifier::ChainValidityCondition;
+use crate::verifier::{ChainParams, ChainValidityCondition};
 
 pub mod address;
 pub mod block;
 mod hash;
 pub mod header;
+pub mod proof;
+mod scale;
 pub mod transaction;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
@@ -22,9 +24,9 @@ impl DaSpec for DaLayerSpec {
 
     type ValidityCondition = ChainValidityCondition;
 
-    type InclusionMultiProof = ();
+    type InclusionMultiProof = Vec<proof::BlobProof>;
 
-    type CompletenessProof = ();
+    type CompletenessProof = proof::AppDataProof;
 
-    type ChainParams = ();
+    type ChainParams = ChainParams;
 }
@@ -0,0 +1,331 @@
+use primitive_types::H256;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "native")]
+use sov_rollup_interface::da::BlobReaderTrait;
+use sp_core::Blake2Hasher;
+#[cfg(feature = "native")]
+use sp_trie::trie_types::TrieDBMutBuilderV1;
+use sp_trie::LayoutV1;
+#[cfg(feature = "native")]
+use sp_trie::{MemoryDB, TrieMut};
+
+use super::scale::encode_compact;
+#[cfg(feature = "native")]
+use super::transaction::AvailBlobTransaction;
+
+/// The leaf of the data root of an Avail block, for one `submit_data` extrinsic.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
+pub struct DataLeaf {
+    /// The application the data was submitted to.
+    pub app_id: u32,
+    /// The keccak-256 hash of the submitted data.
+    pub leaf: H256,
+}
+
+impl DataLeaf {
+    pub fn new(app_id: u32, data: &[u8]) -> Self {
+        Self {
+            app_id,
+            leaf: leaf_hash(data),
+        }
+    }
+}
+
+/// A data submission of an Avail block, as it appears in the completeness proof.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
+pub enum SubmittedData {
+    /// Data submitted to the app id of the rollup, in full.
+    Rollup(Vec<u8>),
+    /// Data submitted to another application, of which only the leaf is needed.
+    Other(DataLeaf),
+}
+
+impl SubmittedData {
+    pub fn leaf(&self) -> H256 {
+        match self {
+            SubmittedData::Rollup(data) => leaf_hash(data),
+            SubmittedData::Other(data_leaf) => data_leaf.leaf,
+        }
+    }
+}
+
+/// A proof that every `submit_data` extrinsic of the block is accounted for:
+/// all the submissions of the block, in order, which must hash to the data root of the header.
+pub type AppDataProof = Vec<SubmittedData>;
+
+/// A Merkle proof that a data submission is a leaf of the data root of an Avail block,
+/// in the format of the `kate_queryDataProof` RPC.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DataProof {
+    /// The sibling hashes from the leaf up to the root.
+    /// Nodes without a sibling are promoted to the next level, and have no entry.
+    pub proof: Vec<H256>,
+    pub number_of_leaves: u32,
+    pub leaf_index: u32,
+}
+
+impl DataProof {
+    /// Builds the proof of the leaf at `leaf_index` in the tree of `leaves`.
+    pub fn new(leaves: &[H256], leaf_index: usize) -> Self {
+        assert!(leaf_index < leaves.len(), "Leaf index out of bounds");
+
+        let mut proof = Vec::new();
+        let mut level = leaves.to_vec();
+        let mut index = leaf_index;
+        while level.len() > 1 {
+            let sibling = index ^ 1;
+            if sibling < level.len() {
+                proof.push(level[sibling]);
+            }
+            level = next_level(&level);
+            index /= 2;
+        }
+
+        Self {
+            proof,
+            number_of_leaves: leaves.len() as u32,
+            leaf_index: leaf_index as u32,
+        }
+    }
+
+    /// Checks that `leaf` is at `leaf_index` in the tree of `number_of_leaves` leaves with the given `root`.
+    pub fn verify(&self, root: &H256, leaf: H256) -> bool {
+        if self.leaf_index >= self.number_of_leaves {
+            return false;
+        }
+
+        let mut siblings = self.proof.iter();
+        let mut computed = leaf;
+        let mut index = self.leaf_index;
+        let mut width = self.number_of_leaves;
+        while width > 1 {
+            if index % 2 == 1 {
+                let Some(sibling) = siblings.next() else {
+                    return false;
+                };
+                computed = node_hash(sibling, &computed);
+            } else if index + 1 < width {
+                let Some(sibling) = siblings.next() else {
+                    return false;
+                };
+                computed = node_hash(&computed, sibling);
+            }
+            index /= 2;
+            width = (width + 1) / 2;
+        }
+
+        siblings.next().is_none() && &computed == root
+    }
+}
+
+/// The proof that a rollup blob was submitted to the block by its sender.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
+pub struct BlobProof {
+    /// The proof of the data of the blob in the data root.
+    pub data_proof: DataProof,
+    /// The SCALE-encoded `submit_data` extrinsic of the blob, which names its signer.
+    pub extrinsic: Vec<u8>,
+    /// The index of the extrinsic in the block.
+    pub extrinsic_index: u32,
+    /// The proof of the extrinsic in the extrinsics root of the block.
+    pub extrinsic_proof: Vec<Vec<u8>>,
+}
+
+impl BlobProof {
+    /// Checks that the extrinsic is at its index in the extrinsics trie with the given `root`.
+    pub fn verify_extrinsic(&self, root: &H256) -> bool {
+        let key = extrinsic_key(self.extrinsic_index);
+        sp_trie::verify_trie_proof::<LayoutV1<Blake2Hasher>, _, _, _>(
+            root,
+            &self.extrinsic_proof,
+            &[(key, Some(&self.extrinsic))],
+        )
+        .is_ok()
+    }
+}
+
+/// Builds the inclusion and completeness proofs of the `rollup_blobs` submitted to `app_id`
+/// in a block with the given data leaves and SCALE-encoded extrinsics.
+#[cfg(feature = "native")]
+pub fn extraction_proofs(
+    data_leaves: &[DataLeaf],
+    extrinsics: &[Vec<u8>],
+    app_id: u32,
+    rollup_blobs: &[AvailBlobTransaction],
+) -> (Vec<BlobProof>, AppDataProof) {
+    let leaves: Vec<_> = data_leaves.iter().map(|data_leaf| data_leaf.leaf).collect();
+    let extrinsics_trie = ExtrinsicsTrie::new(extrinsics);
+    let mut rollup_blobs = rollup_blobs.iter();
+
+    let mut inclusion_proof = Vec::new();
+    let mut completeness_proof = Vec::with_capacity(data_leaves.len());
+    for (index, data_leaf) in data_leaves.iter().enumerate() {
+        if data_leaf.app_id == app_id {
+            let blob = rollup_blobs
+                .next()
+                .expect("The block contains more rollup data submissions than blobs");
+            let extrinsic_index = extrinsics
+                .iter()
+                .position(|extrinsic| sp_core_hashing::blake2_256(extrinsic) == blob.hash())
+                .expect("The extrinsic of the blob is in the block");
+            inclusion_proof.push(BlobProof {
+                data_proof: DataProof::new(&leaves, index),
+                extrinsic: extrinsics[extrinsic_index].clone(),
+                extrinsic_index: extrinsic_index as u32,
+                extrinsic_proof: extrinsics_trie.proof(extrinsic_index as u32),
+            });
+            completeness_proof.push(SubmittedData::Rollup(blob.clone().full_data().to_vec()));
+        } else {
+            completeness_proof.push(SubmittedData::Other(data_leaf.clone()));
+        }
+    }
+
+    (inclusion_proof, completeness_proof)
+}
+
+/// The trie of the extrinsics of a block, whose root is the extrinsics root of the header.
+/// The extrinsics are keyed by their compact-encoded index, as in `ordered_trie_root`.
+#[cfg(feature = "native")]
+pub struct ExtrinsicsTrie {
+    db: MemoryDB<Blake2Hasher>,
+    root: H256,
+}
+
+#[cfg(feature = "native")]
+impl ExtrinsicsTrie {
+    pub fn new(extrinsics: &[Vec<u8>]) -> Self {
+        let mut db = MemoryDB::default();
+        let mut root = H256::default();
+        {
+            let mut trie = TrieDBMutBuilderV1::<Blake2Hasher>::new(&mut db, &mut root).build();
+            for (index, extrinsic) in extrinsics.iter().enumerate() {
+                trie.insert(&extrinsic_key(index as u32), extrinsic)
+                    .expect("Inserting into an in-memory trie can't fail");
+            }
+        }
+        Self { db, root }
+    }
+
+    pub fn root(&self) -> H256 {
+        self.root
+    }
+
+    /// Builds the proof of the extrinsic at `index`.
+    pub fn proof(&self, index: u32) -> Vec<Vec<u8>> {
+        sp_trie::generate_trie_proof::<LayoutV1<Blake2Hasher>, _, _, _>(
+            &self.db,
+            self.root,
+            &[extrinsic_key(index)],
+        )
+        .expect("The in-memory trie contains all its nodes")
+    }
+}
+
+fn extrinsic_key(index: u32) -> Vec<u8> {
+    let mut key = Vec::new();
+    encode_compact(index.into(), &mut key);
+    key
+}
+
+/// Computes the data root of the leaves, as Avail does: a binary Merkle tree over keccak-256,
+/// where the last node of a level without a sibling is promoted to the next level.
+pub fn data_root(leaves: &[H256]) -> H256 {
+    if leaves.is_empty() {
+        return H256::zero();
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+pub fn leaf_hash(data: &[u8]) -> H256 {
+    H256(sp_core_hashing::keccak_256(data))
+}
+
+fn node_hash(left: &H256, right: &H256) -> H256 {
+    let mut combined = [0u8; 64];
+    combined[..32].copy_from_slice(left.as_bytes());
+    combined[32..].copy_from_slice(right.as_bytes());
+    H256(sp_core_hashing::keccak_256(&combined))
+}
+
+fn next_level(level: &[H256]) -> Vec<H256> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [single] => *single,
+            _ => unreachable!("chunks of 2 are never empty"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(count: u8) -> Vec<H256> {
+        (0..count).map(|i| leaf_hash(&[i])).collect()
+    }
+
+    #[test]
+    fn test_data_proofs_of_every_leaf() {
+        for count in 1..=9 {
+            let leaves = leaves(count);
+            let root = data_root(&leaves);
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = DataProof::new(&leaves, index);
+                assert!(proof.verify(&root, *leaf), "leaf {index} of {count}");
+
+                let other_leaf = leaf_hash(&[count]);
+                assert!(!proof.verify(&root, other_leaf));
+            }
+        }
+    }
+
+    #[test]
+    fn test_data_proof_of_wrong_index_fails() {
+        let leaves = leaves(5);
+        let root = data_root(&leaves);
+        let mut proof = DataProof::new(&leaves, 1);
+        proof.leaf_index = 0;
+        assert!(!proof.verify(&root, leaves[1]));
+
+        proof.leaf_index = 5;
+        assert!(!proof.verify(&root, leaves[1]));
+    }
+
+    #[test]
+    fn test_extrinsic_proofs() {
+        let extrinsics: Vec<Vec<u8>> = (0..5u8).map(|i| vec![i; 40 * i as usize + 1]).collect();
+        let trie = ExtrinsicsTrie::new(&extrinsics);
+
+        for (index, extrinsic) in extrinsics.iter().enumerate() {
+            let mut proof = BlobProof {
+                data_proof: DataProof::new(&leaves(1), 0),
+                extrinsic: extrinsic.clone(),
+                extrinsic_index: index as u32,
+                extrinsic_proof: trie.proof(index as u32),
+            };
+            assert!(proof.verify_extrinsic(&trie.root()), "extrinsic {index}");
+
+            proof.extrinsic_index = (index as u32 + 1) % 5;
+            assert!(!proof.verify_extrinsic(&trie.root()));
+
+            proof.extrinsic_index = index as u32;
+            proof.extrinsic.push(0);
+            assert!(!proof.verify_extrinsic(&trie.root()));
+        }
+    }
+
+    #[test]
+    fn test_data_root_of_three_leaves() {
+        let leaves = leaves(3);
+        let expected = node_hash(&node_hash(&leaves[0], &leaves[1]), &leaves[2]);
+        assert_eq!(expected, data_root(&leaves));
+    }
+}
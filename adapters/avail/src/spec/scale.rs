@@ -0,0 +1,119 @@
+//! The subset of the SCALE codec needed to hash Avail headers and read `submit_data` extrinsics,
+//! which is also available without the `native` feature.
+
+/// Appends the compact encoding of `value`.
+pub(crate) fn encode_compact(value: u128, out: &mut Vec<u8>) {
+    match value {
+        0..=0x3f => out.push((value as u8) << 2),
+        0x40..=0x3fff => out.extend_from_slice(&(((value as u16) << 2) | 0b01).to_le_bytes()),
+        0x4000..=0x3fff_ffff => {
+            out.extend_from_slice(&(((value as u32) << 2) | 0b10).to_le_bytes())
+        }
+        _ => {
+            let bytes = value.to_le_bytes();
+            let len = bytes.len() - bytes.iter().rev().take_while(|byte| **byte == 0).count();
+            out.push((((len - 4) as u8) << 2) | 0b11);
+            out.extend_from_slice(&bytes[..len]);
+        }
+    }
+}
+
+/// Appends the encoding of a byte vector: its compact length, followed by the bytes.
+pub(crate) fn encode_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    encode_compact(bytes.len() as u128, out);
+    out.extend_from_slice(bytes);
+}
+
+/// Reads SCALE-encoded values from a byte slice.
+pub(crate) struct Reader<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    pub(crate) fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.input.len() {
+            return None;
+        }
+        let (bytes, rest) = self.input.split_at(len);
+        self.input = rest;
+        Some(bytes)
+    }
+
+    pub(crate) fn read_byte(&mut self) -> Option<u8> {
+        self.read_bytes(1).map(|bytes| bytes[0])
+    }
+
+    pub(crate) fn read_compact(&mut self) -> Option<u128> {
+        let first = self.read_byte()?;
+        let value = match first & 0b11 {
+            0b00 => (first >> 2) as u128,
+            0b01 => (u16::from_le_bytes([first, self.read_byte()?]) >> 2) as u128,
+            0b10 => {
+                let mut bytes = [first, 0, 0, 0];
+                bytes[1..].copy_from_slice(self.read_bytes(3)?);
+                (u32::from_le_bytes(bytes) >> 2) as u128
+            }
+            _ => {
+                let len = (first >> 2) as usize + 4;
+                if len > 16 {
+                    return None;
+                }
+                let mut bytes = [0u8; 16];
+                bytes[..len].copy_from_slice(self.read_bytes(len)?);
+                u128::from_le_bytes(bytes)
+            }
+        };
+        Some(value)
+    }
+
+    /// Reads a byte vector prefixed with its compact length.
+    pub(crate) fn read_vec(&mut self) -> Option<&'a [u8]> {
+        let len = self.read_compact()?;
+        self.read_bytes(usize::try_from(len).ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_encoding() {
+        let cases: [(u128, &[u8]); 6] = [
+            (0, &[0x00]),
+            (1, &[0x04]),
+            (64, &[0x01, 0x01]),
+            (16384, &[0x02, 0x00, 0x01, 0x00]),
+            (1 << 30, &[0x03, 0x00, 0x00, 0x00, 0x40]),
+            (
+                u64::MAX as u128,
+                &[0x13, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+            ),
+        ];
+        for (value, expected) in cases {
+            let mut encoded = Vec::new();
+            encode_compact(value, &mut encoded);
+            assert_eq!(expected, &encoded[..], "encoding of {value}");
+
+            let mut reader = Reader::new(&encoded);
+            assert_eq!(Some(value), reader.read_compact());
+            assert!(reader.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_truncated_vec_is_rejected() {
+        let mut encoded = Vec::new();
+        encode_bytes(b"blob", &mut encoded);
+        assert_eq!(Some(&b"blob"[..]), Reader::new(&encoded).read_vec());
+        assert_eq!(None, Reader::new(&encoded[..4]).read_vec());
+    }
+}
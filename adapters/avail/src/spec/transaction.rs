@@ -12,6 +12,16 @@ use serde::{Deserialize, Serialize};
 use sov_rollup_interface::da::{BlobReaderTrait, CountedBufReader};
 
 use super::address::AvailAddress;
+use super::scale::Reader;
+#[cfg(test)]
+use super::scale::{encode_bytes, encode_compact};
+
+/// The first byte of the signed extrinsics of version 4.
+const SIGNED_EXTRINSIC_V4: u8 = 0b1000_0100;
+/// The index of the `DataAvailability` pallet in the Avail runtime.
+const DATA_AVAILABILITY_PALLET_INDEX: u8 = 29;
+/// The index of the `submit_data` call in the `DataAvailability` pallet.
+const SUBMIT_DATA_CALL_INDEX: u8 = 1;
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 
@@ -77,6 +87,16 @@ impl AvailBlobTransaction {
         })
     }
 
+    #[cfg(test)]
+    pub(crate) fn new_for_testing(address: AvailAddress, app_id: u32, data: &[u8]) -> Self {
+        let extrinsic = encode_submission(address.clone(), app_id, data);
+        AvailBlobTransaction {
+            hash: sp_core_hashing::blake2_256(&extrinsic),
+            address,
+            blob: CountedBufReader::<Bytes>::new(Bytes::copy_from_slice(data)),
+        }
+    }
+
     pub fn combine_hash(&self, hash: [u8; 32]) -> [u8; 32] {
         let mut combined_hashes: Vec<u8> = Vec::with_capacity(64);
         combined_hashes.extend_from_slice(hash.as_ref());
@@ -85,3 +105,117 @@ impl AvailBlobTransaction {
         sp_core_hashing::blake2_256(&combined_hashes)
     }
 }
+
+/// A signed `submit_data` extrinsic.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DataSubmission<'a> {
+    pub signer: AvailAddress,
+    pub app_id: u32,
+    pub data: &'a [u8],
+}
+
+impl<'a> DataSubmission<'a> {
+    /// Reads a signed `submit_data` extrinsic, SCALE-encoded as in the body of the block.
+    /// The signature isn't checked again: Avail only includes extrinsics with a valid signature.
+    pub fn decode(extrinsic: &'a [u8]) -> Option<Self> {
+        let mut reader = Reader::new(extrinsic);
+        let extrinsic = reader.read_vec()?;
+        if !reader.is_empty() {
+            return None;
+        }
+
+        let mut reader = Reader::new(extrinsic);
+        if reader.read_byte()? != SIGNED_EXTRINSIC_V4 {
+            return None;
+        }
+        // Only the `MultiAddress::Id` addresses are supported.
+        if reader.read_byte()? != 0 {
+            return None;
+        }
+        let signer = AvailAddress::try_from(reader.read_bytes(32)?).ok()?;
+        let signature_len = match reader.read_byte()? {
+            // Ed25519 and Sr25519
+            0 | 1 => 64,
+            // Ecdsa
+            2 => 65,
+            _ => return None,
+        };
+        reader.read_bytes(signature_len)?;
+
+        // The signed extensions: era, nonce, tip and app id.
+        if reader.read_byte()? != 0 {
+            // The second byte of a mortal era
+            reader.read_byte()?;
+        }
+        reader.read_compact()?;
+        reader.read_compact()?;
+        let app_id = u32::try_from(reader.read_compact()?).ok()?;
+
+        if reader.read_byte()? != DATA_AVAILABILITY_PALLET_INDEX
+            || reader.read_byte()? != SUBMIT_DATA_CALL_INDEX
+        {
+            return None;
+        }
+        let data = reader.read_vec()?;
+        if !reader.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            signer,
+            app_id,
+            data,
+        })
+    }
+}
+
+/// Encodes a `submit_data` extrinsic with an immortal era and a dummy signature.
+#[cfg(test)]
+pub(crate) fn encode_submission(signer: AvailAddress, app_id: u32, data: &[u8]) -> Vec<u8> {
+    let mut extrinsic = vec![SIGNED_EXTRINSIC_V4, 0];
+    extrinsic.extend_from_slice(signer.as_ref());
+    extrinsic.push(1);
+    extrinsic.extend_from_slice(&[7; 64]);
+    // Immortal era, nonce and tip
+    extrinsic.extend_from_slice(&[0, 0, 0]);
+    encode_compact(app_id.into(), &mut extrinsic);
+    extrinsic.extend_from_slice(&[DATA_AVAILABILITY_PALLET_INDEX, SUBMIT_DATA_CALL_INDEX]);
+    encode_bytes(data, &mut extrinsic);
+
+    let mut encoded = Vec::new();
+    encode_bytes(&extrinsic, &mut encoded);
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_data_submission() {
+        let signer = AvailAddress::from([5; 32]);
+        let extrinsic = encode_submission(signer.clone(), 7, b"rollup blob");
+
+        assert_eq!(
+            Some(DataSubmission {
+                signer,
+                app_id: 7,
+                data: b"rollup blob",
+            }),
+            DataSubmission::decode(&extrinsic)
+        );
+        assert_eq!(
+            None,
+            DataSubmission::decode(&extrinsic[..extrinsic.len() - 1])
+        );
+    }
+
+    #[test]
+    fn test_decode_other_call_fails() {
+        let mut extrinsic = encode_submission(AvailAddress::from([5; 32]), 7, b"rollup blob");
+        // The call index follows the app id, which is encoded in a single byte.
+        let call_index = extrinsic.len() - b"rollup blob".len() - 2;
+        extrinsic[call_index] = SUBMIT_DATA_CALL_INDEX + 1;
+        assert_eq!(None, DataSubmission::decode(&extrinsic));
+    }
+}
@@ -1,9 +1,11 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
-use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec, DaVerifier};
+use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait, DaSpec, DaVerifier};
 use sov_rollup_interface::zk::ValidityCondition;
 use thiserror::Error;
 
+use crate::spec::proof::{data_root, leaf_hash, SubmittedData};
+use crate::spec::transaction::DataSubmission;
 use crate::spec::DaLayerSpec;
 
 #[derive(Error, Debug)]
@@ -12,6 +14,33 @@ pub enum ValidityConditionError {
     BlocksNotConsecutive,
 }
 
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    #[error("the header doesn't match the hash of the block")]
+    InvalidHeaderHash,
+    #[error("the data submissions don't match the data root of the block")]
+    InvalidDataRoot,
+    #[error("the applications of the data submissions don't match the app lookup of the block")]
+    InvalidAppLookup,
+    #[error("data submitted to the rollup is missing from the relevant transactions")]
+    MissingTx,
+    #[error("a relevant transaction was not submitted to the rollup")]
+    UnexpectedTx,
+    #[error("the data of a relevant transaction doesn't match the submitted data")]
+    InvalidTxData,
+    #[error("invalid inclusion proof: {0}")]
+    InvalidInclusionProof(&'static str),
+    #[error("the sender of a relevant transaction didn't sign its extrinsic")]
+    InvalidSender,
+}
+
+/// The parameters of the rollup on Avail.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainParams {
+    /// The app id the rollup blobs are submitted to.
+    pub app_id: u32,
+}
+
 #[derive(
     Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Copy, BorshDeserialize, BorshSerialize,
 )]
@@ -45,25 +74,114 @@ impl ValidityCondition for ChainValidityCondition {
     }
 }
 
-pub struct Verifier;
+pub struct Verifier {
+    app_id: u32,
+}
 
 impl DaVerifier for Verifier {
     type Spec = DaLayerSpec;
 
-    type Error = ValidityConditionError;
+    type Error = ValidationError;
 
     // Verify that the given list of blob transactions is complete and correct.
-    // NOTE: Function return unit since application client already verifies application data.
+    // The header is checked against the block hash, which commits to its data root and app lookup.
+    // The completeness proof contains every data submission of the block, which must hash to the
+    // data root of the header, with the full data of the rollup submissions.
+    // The data root doesn't commit to the senders: they are taken from the signed extrinsics of
+    // the submissions, which are proven against the extrinsics root of the header.
     fn verify_relevant_tx_list(
         &self,
         block_header: &<Self::Spec as DaSpec>::BlockHeader,
         txs: &[<Self::Spec as DaSpec>::BlobTransaction],
-        _inclusion_proof: <Self::Spec as DaSpec>::InclusionMultiProof,
-        _completeness_proof: <Self::Spec as DaSpec>::CompletenessProof,
+        inclusion_proof: <Self::Spec as DaSpec>::InclusionMultiProof,
+        completeness_proof: <Self::Spec as DaSpec>::CompletenessProof,
     ) -> Result<<Self::Spec as DaSpec>::ValidityCondition, Self::Error> {
+        let header = &block_header.header;
+        if header.hash().as_fixed_bytes() != block_header.hash().inner() {
+            return Err(ValidationError::InvalidHeaderHash);
+        }
+
+        // Check that the proof accounts for every data submission of the block.
+        let leaves: Vec<_> = completeness_proof.iter().map(SubmittedData::leaf).collect();
+        if data_root(&leaves) != header.data_root {
+            return Err(ValidationError::InvalidDataRoot);
+        }
+
+        // Check that each submission belongs to the application whose range of the app lookup
+        // contains it, so that no rollup submission can be passed off as data of another application.
+        if header.app_lookup.size as usize != completeness_proof.len() {
+            return Err(ValidationError::InvalidAppLookup);
+        }
+        for (index, submission) in completeness_proof.iter().enumerate() {
+            let app_id = match submission {
+                SubmittedData::Rollup(_) => self.app_id,
+                SubmittedData::Other(data_leaf) if data_leaf.app_id == self.app_id => {
+                    return Err(ValidationError::InvalidAppLookup)
+                }
+                SubmittedData::Other(data_leaf) => data_leaf.app_id,
+            };
+            if header.app_lookup.app_id_at(index as u32) != Some(app_id) {
+                return Err(ValidationError::InvalidAppLookup);
+            }
+        }
+
+        // Check that the relevant transactions are exactly the rollup submissions, in order.
+        let rollup_submissions: Vec<_> = completeness_proof
+            .iter()
+            .enumerate()
+            .filter_map(|(index, submission)| match submission {
+                SubmittedData::Rollup(data) => Some((index, data)),
+                SubmittedData::Other(_) => None,
+            })
+            .collect();
+        if rollup_submissions.len() > txs.len() {
+            return Err(ValidationError::MissingTx);
+        }
+        if rollup_submissions.len() < txs.len() {
+            return Err(ValidationError::UnexpectedTx);
+        }
+        if inclusion_proof.len() != txs.len() {
+            return Err(ValidationError::InvalidInclusionProof(
+                "not all transactions proven",
+            ));
+        }
+
         let mut txs_commitment: [u8; 32] = [0u8; 32];
+        for ((tx, (index, data)), proof) in txs.iter().zip(rollup_submissions).zip(inclusion_proof)
+        {
+            if tx.total_len() != data.len() || !data.starts_with(tx.verified_data()) {
+                return Err(ValidationError::InvalidTxData);
+            }
+            if proof.data_proof.leaf_index as usize != index {
+                return Err(ValidationError::InvalidInclusionProof("wrong leaf index"));
+            }
+            if !proof.data_proof.verify(&header.data_root, leaf_hash(data)) {
+                return Err(ValidationError::InvalidInclusionProof(
+                    "leaf not in the data root",
+                ));
+            }
+
+            // The extrinsic of the submission authenticates its sender.
+            if !proof.verify_extrinsic(&header.extrinsics_root) {
+                return Err(ValidationError::InvalidInclusionProof(
+                    "extrinsic not in the extrinsics root",
+                ));
+            }
+            let submission = DataSubmission::decode(&proof.extrinsic).ok_or(
+                ValidationError::InvalidInclusionProof("not a signed data submission"),
+            )?;
+            if submission.signer != tx.sender() {
+                return Err(ValidationError::InvalidSender);
+            }
+            if submission.app_id != self.app_id
+                || submission.data != data.as_slice()
+                || sp_core_hashing::blake2_256(&proof.extrinsic) != tx.hash()
+            {
+                return Err(ValidationError::InvalidInclusionProof(
+                    "the extrinsic doesn't match the transaction",
+                ));
+            }
 
-        for tx in txs {
             txs_commitment = tx.combine_hash(txs_commitment);
         }
 
@@ -76,7 +194,264 @@ impl DaVerifier for Verifier {
         Ok(validity_condition)
     }
 
-    fn new(_params: <Self::Spec as DaSpec>::ChainParams) -> Self {
-        Verifier {}
+    fn new(params: <Self::Spec as DaSpec>::ChainParams) -> Self {
+        Verifier {
+            app_id: params.app_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::H256;
+
+    use super::*;
+    use crate::spec::address::AvailAddress;
+    use crate::spec::header::{AvailHeader, DataLookup, DataLookupIndexItem, Header};
+    use crate::spec::proof::{extraction_proofs, BlobProof, DataLeaf, ExtrinsicsTrie};
+    use crate::spec::transaction::{encode_submission, AvailBlobTransaction};
+
+    const ROLLUP_APP_ID: u32 = 7;
+    const SENDER: [u8; 32] = [5; 32];
+
+    struct BlockFixture {
+        header: AvailHeader,
+        data_leaves: Vec<DataLeaf>,
+        extrinsics: Vec<Vec<u8>>,
+        txs: Vec<AvailBlobTransaction>,
+    }
+
+    impl BlockFixture {
+        fn proofs(&self) -> (Vec<BlobProof>, Vec<SubmittedData>) {
+            extraction_proofs(
+                &self.data_leaves,
+                &self.extrinsics,
+                ROLLUP_APP_ID,
+                &self.txs,
+            )
+        }
+    }
+
+    /// A block with data submitted without app id, to the rollup, and to another application.
+    fn block_fixture() -> BlockFixture {
+        let submissions: [(u32, &[u8]); 4] = [
+            (0, b"no app id"),
+            (3, b"another application"),
+            (ROLLUP_APP_ID, b"first rollup blob"),
+            (ROLLUP_APP_ID, b"second rollup blob"),
+        ];
+        let data_leaves: Vec<_> = submissions
+            .iter()
+            .map(|(app_id, data)| DataLeaf::new(*app_id, data))
+            .collect();
+        let leaves: Vec<_> = data_leaves.iter().map(|data_leaf| data_leaf.leaf).collect();
+        let extrinsics: Vec<_> = submissions
+            .iter()
+            .map(|(app_id, data)| encode_submission(AvailAddress::from(SENDER), *app_id, data))
+            .collect();
+
+        let header = Header {
+            parent_hash: H256([1; 32]),
+            number: 12,
+            state_root: H256::zero(),
+            extrinsics_root: ExtrinsicsTrie::new(&extrinsics).root(),
+            data_root: data_root(&leaves),
+            app_lookup: DataLookup {
+                size: 4,
+                index: vec![
+                    DataLookupIndexItem {
+                        app_id: 3,
+                        start: 1,
+                    },
+                    DataLookupIndexItem {
+                        app_id: ROLLUP_APP_ID,
+                        start: 2,
+                    },
+                ],
+            },
+            // An empty digest
+            digest: vec![0],
+            kate_commitment: Default::default(),
+        };
+        let txs = submissions
+            .iter()
+            .filter(|(app_id, _)| *app_id == ROLLUP_APP_ID)
+            .map(|(app_id, data)| {
+                AvailBlobTransaction::new_for_testing(AvailAddress::from(SENDER), *app_id, data)
+            })
+            .collect();
+
+        BlockFixture {
+            header: AvailHeader::new_for_testing(header),
+            data_leaves,
+            extrinsics,
+            txs,
+        }
+    }
+
+    fn verifier() -> Verifier {
+        Verifier::new(ChainParams {
+            app_id: ROLLUP_APP_ID,
+        })
+    }
+
+    #[test]
+    fn test_verify_relevant_tx_list() {
+        let block = block_fixture();
+        let (inclusion_proof, completeness_proof) = block.proofs();
+
+        let validity_condition = verifier()
+            .verify_relevant_tx_list(
+                &block.header,
+                &block.txs,
+                inclusion_proof,
+                completeness_proof,
+            )
+            .unwrap();
+        assert_eq!([1; 32], validity_condition.prev_hash);
+        assert_eq!(
+            block.header.header.hash().to_fixed_bytes(),
+            validity_condition.block_hash
+        );
+    }
+
+    #[test]
+    fn test_tampered_header_is_rejected() {
+        let block = block_fixture();
+        let (inclusion_proof, completeness_proof) = block.proofs();
+
+        // Moving the start of the rollup range, without changing the block hash.
+        let mut header = block.header.clone();
+        header.header.app_lookup.index[1].start = 3;
+        let result = verifier().verify_relevant_tx_list(
+            &header,
+            &block.txs,
+            inclusion_proof,
+            completeness_proof,
+        );
+        assert_eq!(Err(ValidationError::InvalidHeaderHash), result.map(|_| ()));
+    }
+
+    #[test]
+    fn test_wrong_sender_is_rejected() {
+        let mut block = block_fixture();
+        let (inclusion_proof, completeness_proof) = block.proofs();
+
+        // The same blob, attributed to another sender.
+        block.txs[0] = AvailBlobTransaction::new_for_testing(
+            AvailAddress::from([6; 32]),
+            ROLLUP_APP_ID,
+            b"first rollup blob",
+        );
+        let result = verifier().verify_relevant_tx_list(
+            &block.header,
+            &block.txs,
+            inclusion_proof,
+            completeness_proof,
+        );
+        assert_eq!(Err(ValidationError::InvalidSender), result.map(|_| ()));
+    }
+
+    #[test]
+    fn test_missing_tx_is_rejected() {
+        let block = block_fixture();
+        let (inclusion_proof, completeness_proof) = block.proofs();
+
+        let result = verifier().verify_relevant_tx_list(
+            &block.header,
+            &block.txs[..1],
+            inclusion_proof[..1].to_vec(),
+            completeness_proof,
+        );
+        assert_eq!(Err(ValidationError::MissingTx), result.map(|_| ()));
+    }
+
+    #[test]
+    fn test_tampered_tx_data_is_rejected() {
+        let mut block = block_fixture();
+        let (inclusion_proof, completeness_proof) = block.proofs();
+
+        block.txs[1] = AvailBlobTransaction::new_for_testing(
+            AvailAddress::from(SENDER),
+            ROLLUP_APP_ID,
+            b"second rollup blot",
+        );
+        // The rollup reads the whole blob.
+        block.txs[1].full_data();
+        let result = verifier().verify_relevant_tx_list(
+            &block.header,
+            &block.txs,
+            inclusion_proof,
+            completeness_proof,
+        );
+        assert_eq!(Err(ValidationError::InvalidTxData), result.map(|_| ()));
+    }
+
+    #[test]
+    fn test_rollup_submission_hidden_as_other_app_is_rejected() {
+        // App 0 comes before the first range of the app lookup, and app 3 is present in the block.
+        for app_id in [0, 3] {
+            let block = block_fixture();
+            let (inclusion_proof, mut completeness_proof) = block.proofs();
+
+            // The leaf doesn't commit to the app id, so the data root still matches.
+            completeness_proof[3] =
+                SubmittedData::Other(DataLeaf::new(app_id, b"second rollup blob"));
+            let result = verifier().verify_relevant_tx_list(
+                &block.header,
+                &block.txs[..1],
+                inclusion_proof[..1].to_vec(),
+                completeness_proof,
+            );
+            assert_eq!(
+                Err(ValidationError::InvalidAppLookup),
+                result.map(|_| ()),
+                "hidden as app {app_id}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_app_lookup_of_submissions() {
+        let block = block_fixture();
+        let app_ids: Vec<_> = (0..5)
+            .map(|index| block.header.header.app_lookup.app_id_at(index))
+            .collect();
+        assert_eq!(
+            vec![
+                Some(0),
+                Some(3),
+                Some(ROLLUP_APP_ID),
+                Some(ROLLUP_APP_ID),
+                None
+            ],
+            app_ids
+        );
+    }
+
+    #[test]
+    fn test_hidden_rollup_submission_is_rejected() {
+        let block = block_fixture();
+        let (inclusion_proof, mut completeness_proof) = block.proofs();
+
+        // Claiming that a rollup submission belongs to an application absent from the block.
+        completeness_proof[3] = SubmittedData::Other(DataLeaf::new(4, b"second rollup blob"));
+        let result = verifier().verify_relevant_tx_list(
+            &block.header,
+            &block.txs[..1],
+            inclusion_proof[..1].to_vec(),
+            completeness_proof.clone(),
+        );
+        assert_eq!(Err(ValidationError::InvalidAppLookup), result.map(|_| ()));
+
+        // Dropping the rollup submission from the proof.
+        completeness_proof.remove(3);
+        let result = verifier().verify_relevant_tx_list(
+            &block.header,
+            &block.txs[..1],
+            inclusion_proof[..1].to_vec(),
+            completeness_proof,
+        );
+        assert_eq!(Err(ValidationError::InvalidDataRoot), result.map(|_| ()));
     }
 }
@@ -1,5 +1,8 @@
+use std::ops::RangeInclusive;
 use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use async_trait::async_trait;
 use celestia_rpc::prelude::*;
@@ -12,9 +15,9 @@ use celestia_types::ExtendedHeader;
 use jsonrpsee::core::client::Subscription;
 use jsonrpsee::http_client::{HeaderMap, HttpClient};
 use pin_project::pin_project;
-use sov_rollup_interface::da::CountedBufReader;
+use sov_rollup_interface::da::{BlockHeaderTrait, CountedBufReader};
 use sov_rollup_interface::services::da::DaService;
-use tracing::{debug, info, instrument, trace};
+use tracing::{debug, info, instrument, trace, warn};
 
 use crate::shares::Blob;
 use crate::types::FilteredCelestiaBlock;
@@ -25,13 +28,20 @@ use crate::{BlobWithSender, CelestiaHeader};
 
 // Approximate value, just to make it work.
 const GAS_PER_BYTE: usize = 20;
-const GAS_PRICE: usize = 1;
+
+/// Delay before retrying to wait for a DA height, when the previous request failed.
+const WAIT_FOR_HEIGHT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// The error message of the Celestia node when a block has no blob of the requested namespaces.
+const BLOB_NOT_FOUND: &str = "blob: not found";
 
 #[derive(Debug, Clone)]
 pub struct CelestiaService {
     client: HttpClient,
     rollup_batch_namespace: Namespace,
     rollup_proof_namespace: Namespace,
+    gas_price: GasPriceEstimator,
+    blocks_before_resubmission: u64,
 }
 
 impl CelestiaService {
@@ -39,15 +49,49 @@ impl CelestiaService {
         client: HttpClient,
         rollup_batch_namespace: Namespace,
         rollup_proof_namespace: Namespace,
+    ) -> Self {
+        Self::with_client_and_fees(
+            client,
+            rollup_batch_namespace,
+            rollup_proof_namespace,
+            GasPriceEstimator::new(
+                default_min_gas_price(),
+                default_max_gas_price(),
+                default_gas_price_bump_multiplier(),
+            )
+            .expect("The default gas prices are valid"),
+            default_blocks_before_resubmission(),
+        )
+    }
+
+    fn with_client_and_fees(
+        client: HttpClient,
+        rollup_batch_namespace: Namespace,
+        rollup_proof_namespace: Namespace,
+        gas_price: GasPriceEstimator,
+        blocks_before_resubmission: u64,
     ) -> Self {
         Self {
             client,
             rollup_batch_namespace,
             rollup_proof_namespace,
+            gas_price,
+            blocks_before_resubmission,
         }
     }
 }
 
+/// The identifier of a blob submitted to Celestia.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CelestiaTxId {
+    /// The height of the Celestia block which includes the blob.
+    pub height: u64,
+    /// The fee paid for the inclusion of the blob, in utia.
+    pub fee: u64,
+    /// The gas limit of the transaction which included the blob.
+    pub gas_limit: u64,
+}
+
 /// Runtime configuration for the [`DaService`] implementation.
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct CelestiaConfig {
@@ -62,6 +106,20 @@ pub struct CelestiaConfig {
     /// The timeout for a Celestia RPC request, in seconds
     #[serde(default = "default_request_timeout_seconds")]
     pub celestia_rpc_timeout_seconds: u64,
+    /// The lowest gas price paid for blob submissions, in utia per gas unit
+    #[serde(default = "default_min_gas_price")]
+    pub min_gas_price: f64,
+    /// The highest gas price paid for blob submissions, in utia per gas unit
+    #[serde(default = "default_max_gas_price")]
+    pub max_gas_price: f64,
+    /// The factor applied to the gas price each time a blob is resubmitted
+    #[serde(default = "default_gas_price_bump_multiplier")]
+    pub gas_price_bump_multiplier: f64,
+    /// The number of Celestia blocks after which a blob which hasn't been included
+    /// is resubmitted with a higher gas price. It shouldn't be lower than the mempool TTL
+    /// of the Celestia validators, so that the previous submission is evicted.
+    #[serde(default = "default_blocks_before_resubmission")]
+    pub blocks_before_resubmission: u64,
 }
 
 fn default_rpc_addr() -> String {
@@ -76,8 +134,80 @@ const fn default_request_timeout_seconds() -> u64 {
     60
 }
 
+const fn default_min_gas_price() -> f64 {
+    1.0
+}
+
+const fn default_max_gas_price() -> f64 {
+    10.0
+}
+
+const fn default_gas_price_bump_multiplier() -> f64 {
+    1.5
+}
+
+const fn default_blocks_before_resubmission() -> u64 {
+    5
+}
+
+/// Estimates the gas price of blob submissions from the prices of the previous inclusions,
+/// within the bounds of the [`CelestiaConfig`].
+#[derive(Debug, Clone)]
+struct GasPriceEstimator {
+    min_gas_price: f64,
+    max_gas_price: f64,
+    bump_multiplier: f64,
+    /// The gas price of the last included blob, shared by the clones of the service.
+    last_included: Arc<Mutex<f64>>,
+}
+
+impl GasPriceEstimator {
+    fn new(min_gas_price: f64, max_gas_price: f64, bump_multiplier: f64) -> Result<Self, BoxError> {
+        anyhow::ensure!(
+            0.0 < min_gas_price && min_gas_price <= max_gas_price,
+            "The gas prices must satisfy 0 < min_gas_price <= max_gas_price, got min_gas_price = {} and max_gas_price = {}",
+            min_gas_price,
+            max_gas_price
+        );
+        anyhow::ensure!(
+            bump_multiplier > 1.0,
+            "The gas price bump multiplier must be greater than 1, got {}",
+            bump_multiplier
+        );
+        Ok(Self {
+            min_gas_price,
+            max_gas_price,
+            bump_multiplier,
+            last_included: Arc::new(Mutex::new(min_gas_price)),
+        })
+    }
+
+    /// Starts one step below the price of the last included blob,
+    /// so that the price goes back to the minimum once the congestion is over.
+    fn estimate(&self) -> f64 {
+        let last_included = *self.last_included.lock().unwrap();
+        (last_included / self.bump_multiplier).clamp(self.min_gas_price, self.max_gas_price)
+    }
+
+    /// Returns the gas price of a resubmission, or `None` if the maximum price was already paid.
+    fn bump(&self, gas_price: f64) -> Option<f64> {
+        let bumped = (gas_price * self.bump_multiplier).min(self.max_gas_price);
+        (bumped > gas_price).then_some(bumped)
+    }
+
+    fn record_inclusion(&self, gas_price: f64) {
+        *self.last_included.lock().unwrap() = gas_price;
+    }
+}
+
+fn get_fee(gas_limit: u64, gas_price: f64) -> u64 {
+    (gas_limit as f64 * gas_price).ceil() as u64
+}
+
 impl CelestiaService {
-    pub async fn new(config: CelestiaConfig, chain_params: RollupParams) -> Self {
+    /// Creates a service connected to the Celestia node of `config`.
+    /// Fails if the gas prices of `config` are inconsistent.
+    pub async fn new(config: CelestiaConfig, chain_params: RollupParams) -> Result<Self, BoxError> {
         let client = {
             let mut headers = HeaderMap::new();
             headers.insert(
@@ -94,14 +224,121 @@ impl CelestiaService {
                     config.celestia_rpc_timeout_seconds,
                 ))
                 .build(&config.celestia_rpc_address)
-        }
-        .expect("Client initialization is valid");
+        }?;
 
-        Self::with_client(
+        Ok(Self::with_client_and_fees(
             client,
             chain_params.rollup_batch_namespace,
             chain_params.rollup_proof_namespace,
-        )
+            GasPriceEstimator::new(
+                config.min_gas_price,
+                config.max_gas_price,
+                config.gas_price_bump_multiplier,
+            )?,
+            config.blocks_before_resubmission,
+        ))
+    }
+
+    /// Submits the blob, and resubmits it with a higher gas price each time it isn't included
+    /// within `blocks_before_resubmission` blocks.
+    ///
+    /// Dropping a pending submission doesn't remove its transaction from the mempool, so the blocks
+    /// produced since the first submission are searched for the blob before each resubmission.
+    /// A transaction still pending after that search could land along with the resubmission,
+    /// which is why `blocks_before_resubmission` shouldn't be lower than the mempool TTL.
+    async fn submit_blob(&self, blob: JsonBlob) -> Result<CelestiaTxId, BoxError> {
+        let gas_limit = get_gas_limit_for_bytes(blob.data.len()) as u64;
+        let mut gas_price = self.gas_price.estimate();
+        let first_submission_height = self.get_head_block_header().await?.height();
+        let mut deadline = first_submission_height;
+        let blobs = [blob];
+
+        loop {
+            let fee = get_fee(gas_limit, gas_price);
+            deadline += self.blocks_before_resubmission;
+            let submission = self.client.blob_submit(
+                &blobs,
+                SubmitOptions {
+                    fee: Some(fee),
+                    gas_limit: Some(gas_limit),
+                },
+            );
+
+            tokio::select! {
+                height = submission => {
+                    let height = height?;
+                    self.gas_price.record_inclusion(gas_price);
+                    return Ok(CelestiaTxId {
+                        height,
+                        fee,
+                        gas_limit,
+                    });
+                }
+                _ = self.wait_for_height(deadline) => {
+                    if let Some(height) = self
+                        .find_inclusion_height(&blobs[0], first_submission_height + 1..=deadline)
+                        .await?
+                    {
+                        info!(
+                            "Blob {:?} was included at height {} by a previous submission",
+                            blobs[0].commitment, height
+                        );
+                        self.gas_price.record_inclusion(gas_price);
+                        return Ok(CelestiaTxId {
+                            height,
+                            fee,
+                            gas_limit,
+                        });
+                    }
+
+                    gas_price = self.gas_price.bump(gas_price).ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Blob {:?} was not included by height {} with the maximum fee of {}",
+                            blobs[0].commitment, deadline, fee
+                        )
+                    })?;
+                    warn!(
+                        "Blob {:?} was not included by height {} with a fee of {}, resubmitting it with a gas price of {}",
+                        blobs[0].commitment, deadline, fee, gas_price
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns the height of the first block among `heights` which includes `blob`, if any.
+    async fn find_inclusion_height(
+        &self,
+        blob: &JsonBlob,
+        heights: RangeInclusive<u64>,
+    ) -> Result<Option<u64>, BoxError> {
+        for height in heights {
+            match self.client.blob_get_all(height, &[blob.namespace]).await {
+                Ok(blobs) => {
+                    if blobs.iter().any(|b| b.commitment == blob.commitment) {
+                        return Ok(Some(height));
+                    }
+                }
+                // The node reports the blocks without blobs of the namespace as an error.
+                Err(jsonrpsee::core::Error::Call(e)) if e.message().contains(BLOB_NOT_FOUND) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Resolves once Celestia reaches `height`.
+    /// The failed requests, for example the ones timing out while waiting for the blocks, are retried.
+    async fn wait_for_height(&self, height: u64) {
+        loop {
+            match self.client.header_wait_for_height(height).await {
+                Ok(_) => return,
+                Err(e) => {
+                    debug!("Failed to wait for height {}: {}", height, e);
+                    tokio::time::sleep(WAIT_FOR_HEIGHT_RETRY_DELAY).await;
+                }
+            }
+        }
     }
 }
 
@@ -145,7 +382,7 @@ impl DaService for CelestiaService {
 
     type FilteredBlock = FilteredCelestiaBlock;
     type HeaderStream = CelestiaBlockHeaderSubscription;
-    type TransactionId = ();
+    type TransactionId = CelestiaTxId;
     type Error = BoxError;
 
     #[instrument(skip(self), err)]
@@ -242,49 +479,25 @@ impl DaService for CelestiaService {
     }
 
     #[instrument(skip_all, err)]
-    async fn send_transaction(&self, blob: &[u8]) -> Result<(), Self::Error> {
+    async fn send_transaction(&self, blob: &[u8]) -> Result<Self::TransactionId, Self::Error> {
         debug!("Sending {} bytes of raw data to Celestia.", blob.len());
 
-        let gas_limit = get_gas_limit_for_bytes(blob.len()) as u64;
-        let fee = gas_limit * GAS_PRICE as u64;
-
         let blob = JsonBlob::new(self.rollup_batch_namespace, blob.to_vec())?;
         info!("Submitting: {:?}", blob.commitment);
 
-        let height = self
-            .client
-            .blob_submit(
-                &[blob],
-                SubmitOptions {
-                    fee: Some(fee),
-                    gas_limit: Some(gas_limit),
-                },
-            )
-            .await?;
+        let tx_id = self.submit_blob(blob).await?;
         info!(
-            "Blob has been submitted to Celestia. block-height={}",
-            height,
+            "Blob has been submitted to Celestia. block-height={} fee={}",
+            tx_id.height, tx_id.fee,
         );
-        Ok(())
+        Ok(tx_id)
     }
 
     async fn send_aggregated_zk_proof(&self, aggregated_proof: &[u8]) -> Result<u64, Self::Error> {
-        let gas_limit = get_gas_limit_for_bytes(aggregated_proof.len()) as u64;
-        let fee = gas_limit * GAS_PRICE as u64;
         let blob = JsonBlob::new(self.rollup_proof_namespace, aggregated_proof.to_vec())?;
 
-        let height = self
-            .client
-            .blob_submit(
-                &[blob],
-                SubmitOptions {
-                    fee: Some(fee),
-                    gas_limit: Some(gas_limit),
-                },
-            )
-            .await?;
-
-        Ok(height)
+        let tx_id = self.submit_blob(blob).await?;
+        Ok(tx_id.height)
     }
 
     async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
@@ -317,11 +530,16 @@ mod tests {
     use serde_json::json;
     use sov_rollup_interface::da::{BlockHeaderTrait, DaVerifier};
     use sov_rollup_interface::services::da::DaService;
-    use wiremock::matchers::{bearer_token, body_json, method, path};
+    use wiremock::matchers::{bearer_token, body_partial_json, method, path};
     use wiremock::{Mock, MockServer, Request, ResponseTemplate};
 
-    use super::default_request_timeout_seconds;
-    use crate::da_service::{get_gas_limit_for_bytes, CelestiaConfig, CelestiaService, GAS_PRICE};
+    use super::{
+        default_blocks_before_resubmission, default_gas_price_bump_multiplier,
+        default_max_gas_price, default_min_gas_price, default_request_timeout_seconds,
+    };
+    use crate::da_service::{
+        get_fee, get_gas_limit_for_bytes, CelestiaConfig, CelestiaService, CelestiaTxId,
+    };
     use crate::parse_pfb_namespace;
     use crate::shares::NamespaceGroup;
     use crate::types::tests::{with_rollup_data, without_rollup_data};
@@ -372,6 +590,10 @@ mod tests {
             celestia_rpc_address: mock_server.uri(),
             max_celestia_response_body_size: 120_000,
             celestia_rpc_timeout_seconds: timeout_sec,
+            min_gas_price: default_min_gas_price(),
+            max_gas_price: default_max_gas_price(),
+            gas_price_bump_multiplier: default_gas_price_bump_multiplier(),
+            blocks_before_resubmission: default_blocks_before_resubmission(),
         };
         let rollup_batch_namespace = Namespace::new_v0(b"sov-test").unwrap();
         let rollup_proof_namespace = Namespace::new_v0(b"sov-proof").unwrap();
@@ -380,11 +602,53 @@ mod tests {
             rollup_proof_namespace,
         };
 
-        let da_service = CelestiaService::new(config.clone(), params.clone()).await;
+        let da_service = CelestiaService::new(config.clone(), params.clone())
+            .await
+            .unwrap();
+
+        // The blob submissions start by fetching the current height
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({ "method": "header.NetworkHead" })))
+            .respond_with(|req: &Request| {
+                let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
+                let header: serde_json::Value =
+                    serde_json::from_str(with_rollup_data::HEADER_JSON).unwrap();
+                json_rpc_response(request.id, header)
+            })
+            .mount(&mock_server)
+            .await;
 
         (mock_server, config, da_service, params)
     }
 
+    fn json_rpc_response(id: u64, result: serde_json::Value) -> ResponseTemplate {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "result": result,
+        });
+
+        ResponseTemplate::new(200)
+            .append_header("Content-Type", "application/json")
+            .set_body_json(response_json)
+    }
+
+    fn json_rpc_error(id: u64, message: &str) -> ResponseTemplate {
+        let response_json = json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": {
+                "code": 1,
+                "message": message,
+            },
+        });
+
+        ResponseTemplate::new(200)
+            .append_header("Content-Type", "application/json")
+            .set_body_json(response_json)
+    }
+
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
     struct BasicJsonRpcRequest {
         jsonrpc: String,
@@ -398,18 +662,17 @@ mod tests {
         let (mock_server, config, da_service, rollup_params) = setup_service(None).await;
 
         let blob = [1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
-        let gas_limit = get_gas_limit_for_bytes(blob.len());
+        let gas_limit = get_gas_limit_for_bytes(blob.len()) as u64;
+        let fee = get_fee(gas_limit, config.min_gas_price);
 
-        // TODO: Fee is hardcoded for now
         let expected_body = json!({
-            "id": 0,
             "jsonrpc": "2.0",
             "method": "blob.Submit",
             "params": [
                 [JsonBlob::new(rollup_params.rollup_batch_namespace, blob.to_vec()).unwrap()],
                 {
                     "GasLimit": gas_limit,
-                    "Fee": gas_limit * GAS_PRICE,
+                    "Fee": fee,
                 },
             ]
         });
@@ -417,24 +680,24 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/"))
             .and(bearer_token(config.celestia_rpc_auth_token))
-            .and(body_json(&expected_body))
+            .and(body_partial_json(&expected_body))
             .respond_with(|req: &Request| {
                 let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
-                let response_json = json!({
-                    "jsonrpc": "2.0",
-                    "id": request.id,
-                    "result": 14, // just some block-height
-                });
-
-                ResponseTemplate::new(200)
-                    .append_header("Content-Type", "application/json")
-                    .set_body_json(response_json)
+                json_rpc_response(request.id, json!(14)) // just some block-height
             })
             .up_to_n_times(1)
             .mount(&mock_server)
             .await;
 
-        da_service.send_transaction(&blob).await?;
+        let tx_id = da_service.send_transaction(&blob).await?;
+        assert_eq!(
+            CelestiaTxId {
+                height: 14,
+                fee,
+                gas_limit,
+            },
+            tx_id
+        );
 
         Ok(())
     }
@@ -446,10 +709,11 @@ mod tests {
 
         let blob: Vec<u8> = vec![1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
 
-        // Do not check API token or expected body here.
+        // Do not check API token or expected params here.
         // Only interested in behaviour on response
         Mock::given(method("POST"))
             .and(path("/"))
+            .and(body_partial_json(json!({ "method": "blob.Submit" })))
             .respond_with(|req: &Request| {
                 let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
                 let response_json = json!({
@@ -486,10 +750,11 @@ mod tests {
 
         let blob: Vec<u8> = vec![1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
 
-        // Do not check API token or expected body here.
+        // Do not check API token or expected params here.
         // Only interested in behaviour on response
         Mock::given(method("POST"))
             .and(path("/"))
+            .and(body_partial_json(json!({ "method": "blob.Submit" })))
             .respond_with(error_response)
             .up_to_n_times(1)
             .mount(&mock_server)
@@ -539,10 +804,11 @@ mod tests {
 
         let blob: Vec<u8> = vec![1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
 
-        // Do not check API token or expected body here.
+        // Do not check API token or expected params here.
         // Only interested in behaviour on response
         Mock::given(method("POST"))
             .and(path("/"))
+            .and(body_partial_json(json!({ "method": "blob.Submit" })))
             .respond_with(error_response)
             .up_to_n_times(1)
             .mount(&mock_server)
@@ -673,17 +939,16 @@ mod tests {
         let (mock_server, config, da_service, rollup_params) = setup_service(None).await;
 
         let zk_proof: Vec<u8> = vec![1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
-        let gas_limit = get_gas_limit_for_bytes(zk_proof.len());
+        let gas_limit = get_gas_limit_for_bytes(zk_proof.len()) as u64;
 
         let expected_body = json!({
-            "id": 0,
             "jsonrpc": "2.0",
             "method": "blob.Submit",
             "params": [
                 [JsonBlob::new(rollup_params.rollup_proof_namespace, zk_proof.to_vec()).unwrap()],
                 {
                     "GasLimit": gas_limit,
-                    "Fee": gas_limit * GAS_PRICE,
+                    "Fee": get_fee(gas_limit, config.min_gas_price),
                 },
             ]
         });
@@ -691,25 +956,194 @@ mod tests {
         Mock::given(method("POST"))
             .and(path("/"))
             .and(bearer_token(config.celestia_rpc_auth_token))
-            .and(body_json(&expected_body))
+            .and(body_partial_json(&expected_body))
             .respond_with(|req: &Request| {
                 let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
-                let response_json = json!({
-                    "jsonrpc": "2.0",
-                    "id": request.id,
-                    "result": 14, // just some block-height
-                });
+                json_rpc_response(request.id, json!(14)) // just some block-height
+            })
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
 
-                ResponseTemplate::new(200)
-                    .append_header("Content-Type", "application/json")
-                    .set_body_json(response_json)
+        let height = da_service.send_aggregated_zk_proof(&zk_proof).await?;
+        assert_eq!(14, height);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submit_blob_resubmission_with_bumped_fee() -> anyhow::Result<()> {
+        let (mock_server, config, da_service, rollup_params) = setup_service(None).await;
+
+        let blob = [1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
+        let json_blob = JsonBlob::new(rollup_params.rollup_batch_namespace, blob.to_vec())?;
+        let gas_limit = get_gas_limit_for_bytes(blob.len()) as u64;
+        let initial_fee = get_fee(gas_limit, config.min_gas_price);
+        let bumped_fee = get_fee(
+            gas_limit,
+            config.min_gas_price * config.gas_price_bump_multiplier,
+        );
+
+        // The first submission is stuck, while the DA layer reaches the resubmission height
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({
+                "method": "blob.Submit",
+                "params": [[json_blob], { "GasLimit": gas_limit, "Fee": initial_fee }],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(30)))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        // The header at height 11 is returned by `header.NetworkHead`
+        let resubmission_height = 11 + config.blocks_before_resubmission;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({
+                "method": "header.WaitForHeight",
+                "params": [resubmission_height],
+            })))
+            .respond_with(|req: &Request| {
+                let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
+                let header: serde_json::Value =
+                    serde_json::from_str(with_rollup_data::HEADER_JSON).unwrap();
+                json_rpc_response(request.id, header)
+            })
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        // The first submission didn't land in the meantime
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({ "method": "blob.GetAll" })))
+            .respond_with(|req: &Request| {
+                let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
+                json_rpc_error(request.id, "blob: not found")
+            })
+            .expect(config.blocks_before_resubmission)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({
+                "method": "blob.Submit",
+                "params": [[json_blob], { "GasLimit": gas_limit, "Fee": bumped_fee }],
+            })))
+            .respond_with(|req: &Request| {
+                let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
+                json_rpc_response(request.id, json!(17)) // just some block-height
             })
             .up_to_n_times(1)
             .mount(&mock_server)
             .await;
 
-        da_service.send_aggregated_zk_proof(&zk_proof).await?;
+        let tx_id = da_service.send_transaction(&blob).await?;
+        assert!(bumped_fee > initial_fee);
+        assert_eq!(
+            CelestiaTxId {
+                height: 17,
+                fee: bumped_fee,
+                gas_limit,
+            },
+            tx_id
+        );
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_submit_blob_not_resubmitted_when_included() -> anyhow::Result<()> {
+        let (mock_server, config, da_service, rollup_params) = setup_service(None).await;
+
+        let blob = [1, 2, 3, 4, 5, 11, 12, 13, 14, 15];
+        let json_blob = JsonBlob::new(rollup_params.rollup_batch_namespace, blob.to_vec())?;
+        let gas_limit = get_gas_limit_for_bytes(blob.len()) as u64;
+        let fee = get_fee(gas_limit, config.min_gas_price);
+
+        // The response to the submission is lost, although the blob lands at height 13
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({ "method": "blob.Submit" })))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(30)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let resubmission_height = 11 + config.blocks_before_resubmission;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({
+                "method": "header.WaitForHeight",
+                "params": [resubmission_height],
+            })))
+            .respond_with(|req: &Request| {
+                let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
+                let header: serde_json::Value =
+                    serde_json::from_str(with_rollup_data::HEADER_JSON).unwrap();
+                json_rpc_response(request.id, header)
+            })
+            .mount(&mock_server)
+            .await;
+
+        let included_blob = json_blob.clone();
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_partial_json(json!({ "method": "blob.GetAll" })))
+            .respond_with(move |req: &Request| {
+                let request: BasicJsonRpcRequest = serde_json::from_slice(&req.body).unwrap();
+                if request.params[0] == json!(13) {
+                    json_rpc_response(request.id, json!([included_blob]))
+                } else {
+                    json_rpc_error(request.id, "blob: not found")
+                }
+            })
+            .mount(&mock_server)
+            .await;
+
+        let tx_id = da_service.send_transaction(&blob).await?;
+        assert_eq!(
+            CelestiaTxId {
+                height: 13,
+                fee,
+                gas_limit,
+            },
+            tx_id
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalid_gas_prices_are_rejected() {
+        let (_, config, _, rollup_params) = setup_service(None).await;
+
+        let config = CelestiaConfig {
+            min_gas_price: 2.0,
+            max_gas_price: 1.0,
+            ..config
+        };
+        let error = CelestiaService::new(config.clone(), rollup_params.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The gas prices must satisfy 0 < min_gas_price <= max_gas_price, got min_gas_price = 2 and max_gas_price = 1"
+        );
+
+        let config = CelestiaConfig {
+            min_gas_price: 1.0,
+            gas_price_bump_multiplier: 1.0,
+            ..config
+        };
+        let error = CelestiaService::new(config, rollup_params)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The gas price bump multiplier must be greater than 1, got 1"
+        );
+    }
 }
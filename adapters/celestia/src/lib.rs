@@ -7,6 +7,6 @@ mod utils;
 pub mod verifier;
 
 #[cfg(feature = "native")]
-pub use da_service::{CelestiaConfig, CelestiaService};
+pub use da_service::{CelestiaConfig, CelestiaService, CelestiaTxId};
 
 pub use crate::celestia::*;
@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
 use std::task::{Context, Poll};
 use std::time::Duration;
@@ -70,6 +71,12 @@ pub struct MockDaService {
     finalized_header_sender: broadcast::Sender<MockBlockHeader>,
     wait_attempts: usize,
     planned_fork: Arc<Mutex<Option<PlannedFork>>>,
+    /// Number of upcoming calls to `send_aggregated_zk_proof` which fail.
+    failing_proof_submissions: Arc<AtomicUsize>,
+    /// Number of upcoming calls to `send_transaction` which succeed before the failing ones.
+    transactions_before_failures: Arc<AtomicUsize>,
+    /// Number of upcoming calls to `send_transaction` which fail.
+    failing_transactions: Arc<AtomicUsize>,
 }
 
 impl MockDaService {
@@ -95,6 +102,9 @@ impl MockDaService {
             finalized_header_sender: tx,
             wait_attempts: 100_0000,
             planned_fork: Arc::new(Mutex::new(None)),
+            failing_proof_submissions: Arc::new(AtomicUsize::new(0)),
+            transactions_before_failures: Arc::new(AtomicUsize::new(0)),
+            failing_transactions: Arc::new(AtomicUsize::new(0)),
         }
     }
 
@@ -108,6 +118,25 @@ impl MockDaService {
         self.wait_attempts = wait_attempts;
     }
 
+    /// Makes the next `count` calls to `send_aggregated_zk_proof` fail, in all the clones of the service.
+    pub fn fail_next_proof_submissions(&self, count: usize) {
+        self.failing_proof_submissions
+            .store(count, Ordering::SeqCst);
+    }
+
+    /// Makes the next `count` calls to `send_transaction` fail, in all the clones of the service.
+    pub fn fail_next_transactions(&self, count: usize) {
+        self.fail_transactions_after(0, count);
+    }
+
+    /// Makes `count` calls to `send_transaction` fail after the next `successes` ones,
+    /// in all the clones of the service.
+    pub fn fail_transactions_after(&self, successes: usize, count: usize) {
+        self.transactions_before_failures
+            .store(successes, Ordering::SeqCst);
+        self.failing_transactions.store(count, Ordering::SeqCst);
+    }
+
     async fn wait_for_height(&self, height: u64) -> anyhow::Result<()> {
         // Waits self.wait_attempts * 10ms to get block at height
         for _ in 0..self.wait_attempts {
@@ -361,12 +390,39 @@ impl DaService for MockDaService {
     }
 
     async fn send_transaction(&self, blob: &[u8]) -> Result<(), Self::Error> {
+        let succeeds = self
+            .transactions_before_failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                count.checked_sub(1)
+            })
+            .is_ok();
+        if !succeeds
+            && self
+                .failing_transactions
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                    count.checked_sub(1)
+                })
+                .is_ok()
+        {
+            anyhow::bail!("Transaction submission failed");
+        }
+
         let mut blocks = self.blocks.write().await;
         let _ = self.add_blob(blob, Default::default(), &mut blocks).await?;
         Ok(())
     }
 
     async fn send_aggregated_zk_proof(&self, proof: &[u8]) -> Result<u64, Self::Error> {
+        if self
+            .failing_proof_submissions
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                count.checked_sub(1)
+            })
+            .is_ok()
+        {
+            anyhow::bail!("Proof submission failed");
+        }
+
         let mut blocks = self.blocks.write().await;
         self.add_blob(Default::default(), proof.to_vec(), &mut blocks)
             .await
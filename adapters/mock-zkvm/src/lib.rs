@@ -92,12 +92,16 @@ impl Notifier {
     }
 }
 
+/// A program run by a [`MockZkvm`] on a [`MockZkGuest`], which reads the hints of the host.
+pub type MockProgram = Arc<dyn Fn(MockZkGuest) -> Result<(), anyhow::Error> + Send + Sync>;
+
 /// A mock implementing the zkVM trait.
 #[derive(Clone)]
 pub struct MockZkvm<ValidityCond> {
     worker_thread_notifier: Notifier,
     committed_data: VecDeque<Vec<u8>>,
     validity_condition: ValidityCond,
+    program: Option<(MockCodeCommitment, MockProgram)>,
 }
 
 impl<ValidityCond> MockZkvm<ValidityCond> {
@@ -107,6 +111,21 @@ impl<ValidityCond> MockZkvm<ValidityCond> {
             worker_thread_notifier: Default::default(),
             committed_data: Default::default(),
             validity_condition,
+            program: None,
+        }
+    }
+
+    /// Creates a new MockZkvm which runs `program` and proves its execution:
+    /// the proofs returned by [`ZkvmHost::run`](sov_rollup_interface::zk::ZkvmHost::run)
+    /// are [`MockProof`]s for `code_commitment`, which can be verified by a [`MockZkGuest`].
+    pub fn with_program(
+        validity_condition: ValidityCond,
+        code_commitment: MockCodeCommitment,
+        program: impl Fn(MockZkGuest) -> Result<(), anyhow::Error> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            program: Some((code_commitment, Arc::new(program))),
+            ..Self::new(validity_condition)
         }
     }
 
@@ -115,6 +134,21 @@ impl<ValidityCond> MockZkvm<ValidityCond> {
         // We notify the worket thread.
         self.worker_thread_notifier.notify();
     }
+
+    /// Proves the execution of `guest` by the program of `code_commitment`:
+    /// the proof is a serialized [`MockProof`] whose log is the data committed by the guest.
+    pub fn prove_guest_execution(
+        &self,
+        guest: &MockZkGuest,
+        code_commitment: &MockCodeCommitment,
+    ) -> Vec<u8> {
+        MockProof {
+            program_id: code_commitment.clone(),
+            is_valid: true,
+            log: &guest.committed_log(),
+        }
+        .encode_to_vec()
+    }
 }
 
 impl<ValidityCond: ValidityCondition> sov_rollup_interface::zk::Zkvm for MockZkvm<ValidityCond> {
@@ -126,13 +160,7 @@ impl<ValidityCond: ValidityCondition> sov_rollup_interface::zk::Zkvm for MockZkv
         serialized_proof: &'a [u8],
         code_commitment: &Self::CodeCommitment,
     ) -> Result<&'a [u8], Self::Error> {
-        let proof = MockProof::decode(serialized_proof)?;
-        anyhow::ensure!(
-            proof.program_id.matches(code_commitment),
-            "Proof failed to verify against requested code commitment"
-        );
-        anyhow::ensure!(proof.is_valid, "Proof is not valid");
-        Ok(proof.log)
+        verify_mock_proof(serialized_proof, code_commitment)
     }
 
     fn verify_and_extract_output<
@@ -164,13 +192,32 @@ impl<ValidityCond: ValidityCondition> sov_rollup_interface::zk::ZkvmHost
     }
 
     fn simulate_with_hints(&mut self) -> Self::Guest {
-        MockZkGuest {}
+        let hints = std::mem::take(&mut self.committed_data)
+            .into_iter()
+            .map(|data| {
+                let proof_info: ProofInfo<ValidityCond> =
+                    bincode::deserialize(&data).expect("Hints are serialized by `add_hint`");
+                proof_info.hint
+            })
+            .collect();
+        MockZkGuest::with_hints(hints)
     }
 
     fn run(&mut self, _with_proof: bool) -> Result<sov_rollup_interface::zk::Proof, anyhow::Error> {
         self.worker_thread_notifier.wait();
-        let data = self.committed_data.pop_front().unwrap_or_default();
-        Ok(sov_rollup_interface::zk::Proof::PublicInput(data))
+        match self.program.clone() {
+            // A mock proof costs nothing, so the execution is proven even if no proof is requested.
+            Some((code_commitment, program)) => {
+                let guest = self.simulate_with_hints();
+                program(guest.clone())?;
+                let proof = self.prove_guest_execution(&guest, &code_commitment);
+                Ok(sov_rollup_interface::zk::Proof::Full(proof))
+            }
+            None => {
+                let data = self.committed_data.pop_front().unwrap_or_default();
+                Ok(sov_rollup_interface::zk::Proof::PublicInput(data))
+            }
+        }
     }
 
     fn extract_output<
@@ -191,49 +238,100 @@ impl<ValidityCond: ValidityCondition> sov_rollup_interface::zk::ZkvmHost
                     validity_condition: data.validity_condition,
                 })
             }
-            sov_rollup_interface::zk::Proof::Full(_) => {
-                panic!("Mock DA doesn't generate real proofs")
+            sov_rollup_interface::zk::Proof::Full(serialized_proof) => {
+                let proof = MockProof::decode(serialized_proof)?;
+                Ok(bincode::deserialize(proof.log)?)
             }
         }
     }
 }
 
 /// A mock implementing the Guest.
-pub struct MockZkGuest {}
+/// It reads the hints provided by the host in order, and records the committed data,
+/// which makes up the log of the corresponding [`MockProof`].
+/// The clones of a guest share its hints and its log, so the host can still prove the execution
+/// once the guest has been moved into the program.
+#[derive(Clone, Default)]
+pub struct MockZkGuest {
+    hints: Arc<Mutex<VecDeque<Vec<u8>>>>,
+    commits: Arc<Mutex<Vec<u8>>>,
+}
+
+impl MockZkGuest {
+    /// Constructs a new guest without hints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs a new guest with the provided serialized hints.
+    pub fn with_hints(hints: Vec<Vec<u8>>) -> Self {
+        Self {
+            hints: Arc::new(Mutex::new(hints.into())),
+            commits: Default::default(),
+        }
+    }
+
+    /// Returns the data committed so far, serialized in order.
+    pub fn committed_log(&self) -> Vec<u8> {
+        self.commits.lock().unwrap().clone()
+    }
+}
 
 impl sov_rollup_interface::zk::Zkvm for MockZkGuest {
     type CodeCommitment = MockCodeCommitment;
 
     type Error = anyhow::Error;
 
+    /// Mock recursive verification: the [`MockProof`] is checked in the same way as by the host.
     fn verify<'a>(
-        _serialized_proof: &'a [u8],
-        _code_commitment: &Self::CodeCommitment,
+        serialized_proof: &'a [u8],
+        code_commitment: &Self::CodeCommitment,
     ) -> Result<&'a [u8], Self::Error> {
-        unimplemented!()
+        verify_mock_proof(serialized_proof, code_commitment)
     }
 
     fn verify_and_extract_output<
         Da: sov_rollup_interface::da::DaSpec,
         Root: Serialize + serde::de::DeserializeOwned,
     >(
-        _serialized_proof: &[u8],
-        _code_commitment: &Self::CodeCommitment,
+        serialized_proof: &[u8],
+        code_commitment: &Self::CodeCommitment,
     ) -> Result<sov_rollup_interface::zk::StateTransition<Da, Root>, Self::Error> {
-        unimplemented!()
+        let output = Self::verify(serialized_proof, code_commitment)?;
+        Ok(bincode::deserialize(output)?)
     }
 }
 
 impl sov_rollup_interface::zk::ZkvmGuest for MockZkGuest {
     fn read_from_host<T: serde::de::DeserializeOwned>(&self) -> T {
-        unimplemented!()
+        let hint = self
+            .hints
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("The guest has read all the hints provided by the host");
+        bincode::deserialize(&hint).expect("The hint doesn't match the requested type")
     }
 
-    fn commit<T: Serialize>(&self, _item: &T) {
-        unimplemented!()
+    fn commit<T: Serialize>(&self, item: &T) {
+        let mut commits = self.commits.lock().unwrap();
+        bincode::serialize_into(&mut *commits, item).expect("Serialization to vec is infallible");
     }
 }
 
+fn verify_mock_proof<'a>(
+    serialized_proof: &'a [u8],
+    code_commitment: &MockCodeCommitment,
+) -> Result<&'a [u8], anyhow::Error> {
+    let proof = MockProof::decode(serialized_proof)?;
+    anyhow::ensure!(
+        proof.program_id.matches(code_commitment),
+        "Proof failed to verify against requested code commitment"
+    );
+    anyhow::ensure!(proof.is_valid, "Proof is not valid");
+    Ok(proof.log)
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ProofInfo<ValidityCond> {
     hint: Vec<u8>,
@@ -254,3 +352,202 @@ fn test_mock_proof_round_trip() {
     let decoded = MockProof::decode(&encoded).unwrap();
     assert_eq!(proof, decoded);
 }
+
+#[cfg(test)]
+mod tests {
+    use sov_mock_da::{MockBlockHeader, MockDaSpec, MockDaVerifier, MockValidityCond};
+    use sov_rollup_interface::zk::{
+        AggregatedStateTransition, Proof, StateTransition, Zkvm, ZkvmGuest, ZkvmHost,
+    };
+    use sov_stf_runner::aggregator::StateTransitionAggregator;
+    use sov_stf_runner::mock::MockStf;
+    use sov_stf_runner::verifier::StateTransitionVerifier;
+
+    use super::*;
+
+    #[test]
+    fn test_simulate_with_hints() {
+        let mut vm = MockZkvm::new(MockValidityCond::default());
+        vm.add_hint(7u64);
+        vm.add_hint("hint".to_string());
+
+        let guest = vm.simulate_with_hints();
+        assert_eq!(7u64, guest.read_from_host::<u64>());
+        assert_eq!("hint", guest.read_from_host::<String>());
+    }
+
+    #[test]
+    fn test_guest_recursive_verification() {
+        let state_transition = StateTransition::<MockDaSpec, [u8; 32]> {
+            initial_state_root: [1; 32],
+            final_state_root: [2; 32],
+            slot_hash: [3; 32].into(),
+            validity_condition: MockValidityCond::default(),
+        };
+
+        let guest = MockZkGuest::new();
+        guest.commit(&state_transition);
+        let log = guest.committed_log();
+
+        let code_commitment = MockCodeCommitment([4; 32]);
+        let proof = MockProof {
+            program_id: code_commitment.clone(),
+            is_valid: true,
+            log: &log,
+        }
+        .encode_to_vec();
+
+        let output = MockZkGuest::verify_and_extract_output::<MockDaSpec, [u8; 32]>(
+            &proof,
+            &code_commitment,
+        )
+        .unwrap();
+        assert_eq!(state_transition, output);
+
+        let err = MockZkGuest::verify(&proof, &MockCodeCommitment([5; 32])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Proof failed to verify against requested code commitment"
+        );
+    }
+
+    #[test]
+    fn test_prove_state_transition_verifier() {
+        let header = MockBlockHeader::from_height(7);
+        let data: StateTransitionData<[u8; 0], (), MockDaSpec> = StateTransitionData {
+            initial_state_root: [],
+            final_state_root: [],
+            da_block_header: header.clone(),
+            inclusion_proof: [0; 32],
+            completeness_proof: (),
+            blobs: vec![],
+            state_transition_witness: (),
+        };
+
+        let mut host = MockZkvm::new(MockValidityCond::default());
+        host.add_hint(data);
+        let guest = host.simulate_with_hints();
+
+        let verifier = StateTransitionVerifier::<MockStf<MockValidityCond>, _, MockZkGuest>::new(
+            MockStf::default(),
+            MockDaVerifier::default(),
+        );
+        verifier.run_block(guest.clone(), ()).unwrap();
+
+        let code_commitment = MockCodeCommitment([1; 32]);
+        let proof = host.prove_guest_execution(&guest, &code_commitment);
+
+        let output =
+            MockZkvm::<MockValidityCond>::verify_and_extract_output::<MockDaSpec, [u8; 0]>(
+                &proof,
+                &code_commitment,
+            )
+            .unwrap();
+        assert_eq!(
+            StateTransition {
+                initial_state_root: [],
+                final_state_root: [],
+                slot_hash: header.hash(),
+                validity_condition: MockValidityCond::default(),
+            },
+            output
+        );
+
+        let err = verify_mock_proof(&proof, &MockCodeCommitment([2; 32])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Proof failed to verify against requested code commitment"
+        );
+    }
+
+    fn run_block_program(guest: MockZkGuest) -> Result<(), anyhow::Error> {
+        StateTransitionVerifier::<MockStf<MockValidityCond>, _, MockZkGuest>::new(
+            MockStf::default(),
+            MockDaVerifier::default(),
+        )
+        .run_block(guest, ())
+    }
+
+    fn aggregator_vm(
+        aggregator_code_commitment: MockCodeCommitment,
+        block_code_commitment: MockCodeCommitment,
+    ) -> MockZkvm<MockValidityCond> {
+        let vm = MockZkvm::with_program(
+            MockValidityCond::default(),
+            aggregator_code_commitment,
+            move |guest| {
+                StateTransitionAggregator::<MockDaSpec, [u8; 0], MockZkGuest>::new(
+                    block_code_commitment.clone(),
+                )
+                .run_aggregation(guest)
+            },
+        );
+        vm.make_proof();
+        vm
+    }
+
+    #[test]
+    fn test_prove_aggregation() {
+        let block_code_commitment = MockCodeCommitment([1; 32]);
+        let block_vm = MockZkvm::with_program(
+            MockValidityCond::default(),
+            block_code_commitment.clone(),
+            run_block_program,
+        );
+        block_vm.make_proof();
+
+        let headers = [1, 2].map(MockBlockHeader::from_height);
+        let block_proofs: Vec<Vec<u8>> = headers
+            .iter()
+            .map(|header| {
+                let data: StateTransitionData<[u8; 0], (), MockDaSpec> = StateTransitionData {
+                    initial_state_root: [],
+                    final_state_root: [],
+                    da_block_header: header.clone(),
+                    inclusion_proof: [0; 32],
+                    completeness_proof: (),
+                    blobs: vec![],
+                    state_transition_witness: (),
+                };
+                let mut vm = block_vm.clone();
+                vm.add_hint(data);
+                match vm.run(true).unwrap() {
+                    Proof::Full(proof) => proof,
+                    Proof::PublicInput(_) => panic!("The mock zkVM must prove its program"),
+                }
+            })
+            .collect();
+
+        let aggregator_code_commitment = MockCodeCommitment([2; 32]);
+        let mut aggregator =
+            aggregator_vm(aggregator_code_commitment.clone(), block_code_commitment);
+        aggregator.add_hint(&block_proofs);
+        let Proof::Full(aggregated_proof) = aggregator.run(true).unwrap() else {
+            panic!("The mock zkVM must prove its program");
+        };
+
+        let output =
+            MockZkvm::<MockValidityCond>::verify(&aggregated_proof, &aggregator_code_commitment)
+                .unwrap();
+        let state_transition: AggregatedStateTransition<MockDaSpec, [u8; 0]> =
+            bincode::deserialize(output).unwrap();
+        assert_eq!(
+            AggregatedStateTransition {
+                initial_state_root: [],
+                final_state_root: [],
+                initial_slot_hash: headers[0].hash(),
+                final_slot_hash: headers[1].hash(),
+                validity_condition: MockValidityCond::default(),
+            },
+            state_transition
+        );
+
+        // The proofs of another program are rejected.
+        let mut aggregator = aggregator_vm(aggregator_code_commitment, MockCodeCommitment([3; 32]));
+        aggregator.add_hint(&block_proofs);
+        let err = aggregator.run(true).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Proof failed to verify against requested code commitment"));
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::Context as _;
+use clap::Parser;
+use sov_demo_rollup::{initialize_logging, CelestiaDemoRollup, MockDemoRollup};
+use sov_modules_rollup_blueprint::RollupBlueprint;
+use sov_stf_runner::{
+    from_toml_path, start_remote_prover_server, ProverService, RollupConfig, RollupProverConfig,
+};
+
+/// Prover server of the demo-rollup. Full nodes delegate the proof generation to it
+/// with a `RemoteProverService`.
+/// The server is configured by the `[prover_server]` section of the rollup config, and posts the
+/// aggregated proofs to the DA layer of the rollup config.
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// The data layer type.
+    #[arg(long, default_value = "mock")]
+    da_layer: SupportedDaLayer,
+
+    /// The path to the rollup config.
+    #[arg(long, default_value = "mock_rollup_config.toml")]
+    rollup_config_path: String,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+enum SupportedDaLayer {
+    Celestia,
+    Mock,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), anyhow::Error> {
+    initialize_logging();
+
+    let args = Args::parse();
+    let rollup_config_path = args.rollup_config_path.as_str();
+
+    match args.da_layer {
+        SupportedDaLayer::Mock => run_prover_server(MockDemoRollup {}, rollup_config_path).await,
+        SupportedDaLayer::Celestia => {
+            run_prover_server(CelestiaDemoRollup {}, rollup_config_path).await
+        }
+    }
+}
+
+async fn run_prover_server<B>(blueprint: B, rollup_config_path: &str) -> Result<(), anyhow::Error>
+where
+    B: RollupBlueprint,
+    B::DaConfig: serde::de::DeserializeOwned,
+    B::ProverService: Send + Sync + 'static,
+    <B::ProverService as ProverService>::StateRoot: serde::de::DeserializeOwned + Send,
+    <B::ProverService as ProverService>::Witness: serde::de::DeserializeOwned + Send,
+{
+    let mut rollup_config: RollupConfig<B::DaConfig> =
+        from_toml_path(rollup_config_path).context("Failed to read rollup configuration")?;
+    let server_config = rollup_config
+        .prover_server
+        .take()
+        .context("The rollup configuration has no [prover_server] section")?;
+    // The server generates the proofs itself, and keeps its proving jobs apart from the ledger of the full node.
+    rollup_config.remote_prover = None;
+    rollup_config.storage = server_config.storage.clone();
+
+    let da_service = blueprint.create_da_service(&rollup_config).await;
+    let ledger_db = blueprint.create_ledger_db(&rollup_config);
+    let prover_service = blueprint
+        .create_prover_service(
+            RollupProverConfig::Execute,
+            &rollup_config,
+            &da_service,
+            &ledger_db,
+        )
+        .await;
+
+    let (_, server_handle) =
+        start_remote_prover_server(prover_service, ledger_db, &server_config).await?;
+    server_handle.stopped().await;
+
+    Ok(())
+}
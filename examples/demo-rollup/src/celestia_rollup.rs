@@ -12,7 +12,9 @@ use sov_prover_storage_manager::ProverStorageManager;
 use sov_risc0_adapter::host::Risc0Host;
 use sov_rollup_interface::zk::ZkvmHost;
 use sov_state::{DefaultStorageSpec, Storage, ZkStorage};
-use sov_stf_runner::{ParallelProverService, RollupConfig, RollupProverConfig};
+use sov_stf_runner::{
+    LocalOrRemoteProverService, ParallelProverService, RollupConfig, RollupProverConfig,
+};
 
 use crate::{ROLLUP_BATCH_NAMESPACE, ROLLUP_PROOF_NAMESPACE};
 
@@ -37,17 +39,19 @@ impl RollupBlueprint for CelestiaDemoRollup {
     type NativeKernel = BasicKernel<Self::NativeContext, Self::DaSpec>;
     type ZkKernel = BasicKernel<Self::ZkContext, Self::DaSpec>;
 
-    type ProverService = ParallelProverService<
-        <<Self::NativeContext as Spec>::Storage as Storage>::Root,
-        <<Self::NativeContext as Spec>::Storage as Storage>::Witness,
-        Self::DaService,
-        Self::Vm,
-        StfBlueprint<
-            Self::ZkContext,
-            Self::DaSpec,
-            <Self::Vm as ZkvmHost>::Guest,
-            Self::ZkRuntime,
-            Self::ZkKernel,
+    type ProverService = LocalOrRemoteProverService<
+        ParallelProverService<
+            <<Self::NativeContext as Spec>::Storage as Storage>::Root,
+            <<Self::NativeContext as Spec>::Storage as Storage>::Witness,
+            Self::DaService,
+            Self::Vm,
+            StfBlueprint<
+                Self::ZkContext,
+                Self::DaSpec,
+                <Self::Vm as ZkvmHost>::Guest,
+                Self::ZkRuntime,
+                Self::ZkKernel,
+            >,
         >,
     >;
 
@@ -56,6 +60,7 @@ impl RollupBlueprint for CelestiaDemoRollup {
         storage: &<Self::NativeContext as sov_modules_api::Spec>::Storage,
         ledger_db: &sov_db::ledger_db::LedgerDB,
         da_service: &Self::DaService,
+        rollup_config: &RollupConfig<Self::DaConfig>,
     ) -> Result<jsonrpsee::RpcModule<()>, anyhow::Error> {
         // TODO set the sequencer address
         let sequencer = Address::new([0; 32]);
@@ -65,12 +70,19 @@ impl RollupBlueprint for CelestiaDemoRollup {
             Self::NativeRuntime,
             Self::NativeContext,
             Self::DaService,
-        >(storage, ledger_db, da_service, sequencer)?;
+        >(
+            storage,
+            ledger_db,
+            da_service,
+            sequencer,
+            &rollup_config.sequencer,
+        )?;
 
         #[cfg(feature = "experimental")]
         crate::eth::register_ethereum::<Self::DaService>(
             da_service.clone(),
             storage.clone(),
+            ledger_db,
             &mut rpc_methods,
         )?;
 
@@ -89,13 +101,15 @@ impl RollupBlueprint for CelestiaDemoRollup {
             },
         )
         .await
+        .expect("Invalid Celestia configuration")
     }
 
     async fn create_prover_service(
         &self,
         prover_config: RollupProverConfig,
         rollup_config: &RollupConfig<Self::DaConfig>,
-        _da_service: &Self::DaService,
+        da_service: &Self::DaService,
+        ledger_db: &sov_db::ledger_db::LedgerDB,
     ) -> Self::ProverService {
         let vm = Risc0Host::new(risc0::ROLLUP_ELF);
         let zk_stf = StfBlueprint::new();
@@ -105,14 +119,21 @@ impl RollupBlueprint for CelestiaDemoRollup {
             rollup_namespace: ROLLUP_BATCH_NAMESPACE,
         };
 
-        ParallelProverService::new_with_default_workers(
-            vm,
-            zk_stf,
-            da_verifier,
-            prover_config,
-            zk_storage,
-            rollup_config.prover_service,
-        )
+        LocalOrRemoteProverService::new(rollup_config.remote_prover.as_ref(), || {
+            ParallelProverService::new_with_default_workers(
+                vm,
+                // Risc0 doesn't support recursion yet, so the proofs are not aggregated.
+                None,
+                zk_stf,
+                da_verifier,
+                da_service.clone(),
+                ledger_db.clone(),
+                prover_config,
+                zk_storage,
+                rollup_config.prover_service,
+            )
+        })
+        .expect("Failed to create the client of the prover server")
     }
 
     fn create_storage_manager(
@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use anyhow::Context as _;
 use sov_cli::wallet_state::PrivateKeyAndAddress;
+use sov_db::ledger_db::LedgerDB;
 use sov_ethereum::experimental::EthRpcConfig;
 use sov_ethereum::GasPriceOracleConfig;
 use sov_modules_api::default_context::DefaultContext;
@@ -27,6 +28,7 @@ fn read_sov_tx_signer_priv_key() -> Result<DefaultPrivateKey, anyhow::Error> {
 pub(crate) fn register_ethereum<Da: DaService>(
     da_service: Da,
     storage: ProverStorage<sov_state::DefaultStorageSpec, SnapshotManager>,
+    ledger_db: &LedgerDB,
     methods: &mut jsonrpsee::RpcModule<()>,
 ) -> Result<(), anyhow::Error> {
     let eth_rpc_config = {
@@ -39,8 +41,12 @@ pub(crate) fn register_ethereum<Da: DaService>(
         }
     };
 
-    let ethereum_rpc =
-        sov_ethereum::get_ethereum_rpc::<DefaultContext, Da>(da_service, eth_rpc_config, storage);
+    let ethereum_rpc = sov_ethereum::get_ethereum_rpc::<DefaultContext, Da>(
+        da_service,
+        eth_rpc_config,
+        storage,
+        ledger_db,
+    );
     methods
         .merge(ethereum_rpc)
         .context("Failed to merge Ethereum RPC modules")
@@ -27,6 +27,10 @@ struct Args {
     /// The path to the rollup config.
     #[arg(long, default_value = "mock_rollup_config.toml")]
     rollup_config_path: String,
+
+    /// Runs the rollup as a full node which doesn't prove the DA blocks.
+    #[arg(long)]
+    no_prover: bool,
 }
 
 #[derive(clap::ValueEnum, Clone, Debug)]
@@ -41,6 +45,7 @@ async fn main() -> Result<(), anyhow::Error> {
 
     let args = Args::parse();
     let rollup_config_path = args.rollup_config_path.as_str();
+    let prover_config = (!args.no_prover).then_some(RollupProverConfig::Execute);
 
     match args.da_layer {
         SupportedDaLayer::Mock => {
@@ -50,7 +55,7 @@ async fn main() -> Result<(), anyhow::Error> {
                     chain_state: "../test-data/genesis/demo-tests/mock/chain_state.json".into(),
                 },
                 rollup_config_path,
-                RollupProverConfig::Execute,
+                prover_config,
             )
             .await?;
             rollup.run().await
@@ -62,7 +67,7 @@ async fn main() -> Result<(), anyhow::Error> {
                     chain_state: "../test-data/genesis/demo-tests/celestia/chain_state.json".into(),
                 },
                 rollup_config_path,
-                RollupProverConfig::Execute,
+                prover_config,
             )
             .await?;
             rollup.run().await
@@ -74,7 +79,7 @@ async fn new_rollup_with_celestia_da(
     rt_genesis_paths: &GenesisPaths,
     kernel_genesis_paths: &BasicKernelGenesisPaths,
     rollup_config_path: &str,
-    prover_config: RollupProverConfig,
+    prover_config: Option<RollupProverConfig>,
 ) -> Result<Rollup<CelestiaDemoRollup>, anyhow::Error> {
     debug!(
         "Starting celestia rollup with config {}",
@@ -106,7 +111,7 @@ async fn new_rollup_with_mock_da(
     rt_genesis_paths: &GenesisPaths,
     kernel_genesis_paths: &BasicKernelGenesisPaths,
     rollup_config_path: &str,
-    prover_config: RollupProverConfig,
+    prover_config: Option<RollupProverConfig>,
 ) -> Result<Rollup<MockDemoRollup>, anyhow::Error> {
     debug!("Starting mock rollup with config {}", rollup_config_path);
 
@@ -12,7 +12,9 @@ use sov_prover_storage_manager::ProverStorageManager;
 use sov_risc0_adapter::host::Risc0Host;
 use sov_rollup_interface::zk::ZkvmHost;
 use sov_state::{DefaultStorageSpec, Storage, ZkStorage};
-use sov_stf_runner::{ParallelProverService, RollupConfig, RollupProverConfig};
+use sov_stf_runner::{
+    LocalOrRemoteProverService, ParallelProverService, RollupConfig, RollupProverConfig,
+};
 
 /// Rollup with MockDa
 pub struct MockDemoRollup {}
@@ -35,17 +37,19 @@ impl RollupBlueprint for MockDemoRollup {
     type NativeKernel = BasicKernel<Self::NativeContext, Self::DaSpec>;
     type ZkKernel = BasicKernel<Self::ZkContext, Self::DaSpec>;
 
-    type ProverService = ParallelProverService<
-        <<Self::NativeContext as Spec>::Storage as Storage>::Root,
-        <<Self::NativeContext as Spec>::Storage as Storage>::Witness,
-        Self::DaService,
-        Self::Vm,
-        StfBlueprint<
-            Self::ZkContext,
-            Self::DaSpec,
-            <Self::Vm as ZkvmHost>::Guest,
-            Self::ZkRuntime,
-            Self::ZkKernel,
+    type ProverService = LocalOrRemoteProverService<
+        ParallelProverService<
+            <<Self::NativeContext as Spec>::Storage as Storage>::Root,
+            <<Self::NativeContext as Spec>::Storage as Storage>::Witness,
+            Self::DaService,
+            Self::Vm,
+            StfBlueprint<
+                Self::ZkContext,
+                Self::DaSpec,
+                <Self::Vm as ZkvmHost>::Guest,
+                Self::ZkRuntime,
+                Self::ZkKernel,
+            >,
         >,
     >;
 
@@ -54,6 +58,7 @@ impl RollupBlueprint for MockDemoRollup {
         storage: &<Self::NativeContext as Spec>::Storage,
         ledger_db: &LedgerDB,
         da_service: &Self::DaService,
+        rollup_config: &RollupConfig<Self::DaConfig>,
     ) -> Result<jsonrpsee::RpcModule<()>, anyhow::Error> {
         // TODO set the sequencer address
         let sequencer = Address::new([0; 32]);
@@ -63,12 +68,19 @@ impl RollupBlueprint for MockDemoRollup {
             Self::NativeRuntime,
             Self::NativeContext,
             Self::DaService,
-        >(storage, ledger_db, da_service, sequencer)?;
+        >(
+            storage,
+            ledger_db,
+            da_service,
+            sequencer,
+            &rollup_config.sequencer,
+        )?;
 
         #[cfg(feature = "experimental")]
         crate::eth::register_ethereum::<Self::DaService>(
             da_service.clone(),
             storage.clone(),
+            ledger_db,
             &mut rpc_methods,
         )?;
 
@@ -86,21 +98,29 @@ impl RollupBlueprint for MockDemoRollup {
         &self,
         prover_config: RollupProverConfig,
         rollup_config: &RollupConfig<Self::DaConfig>,
-        _da_service: &Self::DaService,
+        da_service: &Self::DaService,
+        ledger_db: &LedgerDB,
     ) -> Self::ProverService {
         let vm = Risc0Host::new(risc0::MOCK_DA_ELF);
         let zk_stf = StfBlueprint::new();
         let zk_storage = ZkStorage::new();
         let da_verifier = Default::default();
 
-        ParallelProverService::new_with_default_workers(
-            vm,
-            zk_stf,
-            da_verifier,
-            prover_config,
-            zk_storage,
-            rollup_config.prover_service,
-        )
+        LocalOrRemoteProverService::new(rollup_config.remote_prover.as_ref(), || {
+            ParallelProverService::new_with_default_workers(
+                vm,
+                // Risc0 doesn't support recursion yet, so the proofs are not aggregated.
+                None,
+                zk_stf,
+                da_verifier,
+                da_service.clone(),
+                ledger_db.clone(),
+                prover_config,
+                zk_storage,
+                rollup_config.prover_service,
+            )
+        })
+        .expect("Failed to create the client of the prover server")
     }
 
     fn create_storage_manager(
@@ -69,7 +69,7 @@ fn test_helper(test_queries: Vec<TestExpect>, slots: Vec<SlotCommit<MockBlock, u
             .unwrap();
         let addr = server.local_addr().unwrap();
         let server_rpc_module =
-            sov_ledger_rpc::server::rpc_module::<LedgerDB, u32, u32>(ledger_db).unwrap();
+            sov_ledger_rpc::server::rpc_module::<LedgerDB, u32, u32, ()>(ledger_db).unwrap();
         let _server_handle = server.start(server_rpc_module);
 
         let rpc_config = RpcConfig {
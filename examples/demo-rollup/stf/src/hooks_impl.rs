@@ -67,14 +67,17 @@ impl<C: Context, Da: DaSpec> ApplyBlobHooks<Da::BlobTransaction> for Runtime<C,
         working_set: &mut WorkingSet<C>,
     ) -> anyhow::Result<()> {
         match result {
-            SequencerOutcome::Rewarded(_reward) => {
-                // TODO: Process reward here or above.
-                <SequencerRegistry<C, Da> as ApplyBlobHooks<Da::BlobTransaction>>::end_blob_hook(
-                    &self.sequencer_registry,
-                    sov_sequencer_registry::SequencerOutcome::Completed,
-                    working_set,
-                )
-            }
+            SequencerOutcome::Rewarded {
+                reward,
+                sequencer_da_address,
+            } => <SequencerRegistry<C, Da> as ApplyBlobHooks<Da::BlobTransaction>>::end_blob_hook(
+                &self.sequencer_registry,
+                sov_sequencer_registry::SequencerOutcome::Rewarded {
+                    sequencer: sequencer_da_address,
+                    reward,
+                },
+                working_set,
+            ),
             SequencerOutcome::Ignored => Ok(()),
             SequencerOutcome::Slashed {
                 reason,
@@ -45,7 +45,7 @@ use sov_modules_api::macros::DefaultRuntime;
 use sov_modules_api::macros::{expose_rpc, CliWallet};
 #[cfg(feature = "native")]
 use sov_modules_api::Spec;
-use sov_modules_api::{Context, DispatchCall, Genesis, MessageCodec};
+use sov_modules_api::{Context, DispatchCall, Event, Genesis, MessageCodec};
 #[cfg(feature = "native")]
 use sov_nft_module::{NonFungibleTokenRpcImpl, NonFungibleTokenRpcServer};
 use sov_rollup_interface::da::DaSpec;
@@ -59,7 +59,7 @@ use crate::genesis_config::GenesisPaths;
 
 /// The `demo-stf runtime`.
 #[cfg_attr(feature = "native", derive(CliWallet), expose_rpc)]
-#[derive(Genesis, DispatchCall, MessageCodec, DefaultRuntime)]
+#[derive(Genesis, DispatchCall, Event, MessageCodec, DefaultRuntime)]
 #[serialization(borsh::BorshDeserialize, borsh::BorshSerialize)]
 #[cfg_attr(feature = "serde", serialization(serde::Serialize, serde::Deserialize))]
 pub struct Runtime<C: Context, Da: DaSpec> {
@@ -89,6 +89,9 @@ where
     #[cfg(feature = "native")]
     type GenesisPaths = GenesisPaths;
 
+    #[cfg(feature = "native")]
+    type Event = RuntimeEvent<C, Da>;
+
     #[cfg(feature = "native")]
     fn rpc_methods(storage: <C as Spec>::Storage) -> jsonrpsee::RpcModule<()> {
         get_rpc_methods::<C, Da>(storage)
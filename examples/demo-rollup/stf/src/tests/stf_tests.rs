@@ -1,11 +1,16 @@
+use borsh::BorshSerialize;
 use sov_cli::wallet_state::PrivateKeyAndAddress;
 use sov_data_generators::bank_data::get_default_token_address;
 use sov_data_generators::{has_tx_events, new_test_blob_from_batch};
-use sov_mock_da::{MockBlock, MockDaSpec, MOCK_SEQUENCER_DA_ADDRESS};
+use sov_mock_da::{MockAddress, MockBlob, MockBlock, MockDaSpec, MOCK_SEQUENCER_DA_ADDRESS};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
-use sov_modules_api::{Context, PrivateKey, WorkingSet};
-use sov_modules_stf_blueprint::{Batch, SequencerOutcome, StfBlueprint};
+use sov_modules_api::{Context, PrivateKey, Spec, WorkingSet};
+use sov_modules_stf_blueprint::{
+    Batch, SequencerOutcome, SlashingReason, StfBlueprint, CHUNKED_BATCH_TIMEOUT_SLOTS,
+};
+use sov_rollup_interface::chunks::{split_batch, BlobChunk};
+use sov_rollup_interface::digest::Digest;
 use sov_rollup_interface::services::da::SlotData;
 use sov_rollup_interface::stf::StateTransitionFunction;
 use sov_rollup_interface::storage::HierarchicalStorageManager;
@@ -59,7 +64,10 @@ fn test_demo_values_in_db() {
 
         let apply_blob_outcome = result.batch_receipts[0].clone();
         assert_eq!(
-            SequencerOutcome::Rewarded(0),
+            SequencerOutcome::Rewarded {
+                reward: 0,
+                sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+            },
             apply_blob_outcome.inner,
             "Sequencer execution should have succeeded but failed "
         );
@@ -131,7 +139,10 @@ fn test_demo_values_in_cache() {
     let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
 
     assert_eq!(
-        SequencerOutcome::Rewarded(0),
+        SequencerOutcome::Rewarded {
+            reward: 0,
+            sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+        },
         apply_blob_outcome.inner,
         "Sequencer execution should have succeeded but failed"
     );
@@ -195,7 +206,10 @@ fn test_demo_values_not_in_db() {
         let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
 
         assert_eq!(
-            SequencerOutcome::Rewarded(0),
+            SequencerOutcome::Rewarded {
+                reward: 0,
+                sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+            },
             apply_blob_outcome.inner,
             "Sequencer execution should have succeeded but failed",
         );
@@ -274,6 +288,262 @@ fn test_sequencer_unknown_sequencer() {
     assert!(!has_tx_events(&apply_blob_outcome));
 }
 
+#[test]
+fn test_batch_split_across_slots() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let genesis_block = MockBlock::default();
+    let storage = storage_manager
+        .create_storage_on(genesis_block.header())
+        .unwrap();
+    let (mut state_root, storage) = stf.init_chain(storage, get_genesis_config_for_tests());
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    let private_key = read_private_key::<DefaultContext>().private_key;
+    let batch = Batch {
+        txs: simulate_da(private_key),
+    }
+    .try_to_vec()
+    .unwrap();
+    let batch_id = [7; 32];
+    let chunks = split_batch(&batch, batch_id, batch.len() / 2 + 64);
+    assert_eq!(2, chunks.len());
+
+    let sequencer = MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS);
+    let mut block = genesis_block;
+    let mut batch_receipts = Vec::new();
+    for (i, chunk) in chunks.into_iter().enumerate() {
+        block = block.next_mock();
+        let mut blobs = [MockBlob::new(chunk, sequencer, [i as u8; 32])];
+        let storage = storage_manager.create_storage_on(block.header()).unwrap();
+        let result = stf.apply_slot(
+            &state_root,
+            storage,
+            Default::default(),
+            &block.header,
+            &block.validity_cond,
+            &mut blobs,
+        );
+        state_root = result.state_root;
+        storage_manager
+            .save_change_set(block.header(), result.change_set)
+            .unwrap();
+        assert_eq!(1, result.batch_receipts.len());
+        batch_receipts.push(result.batch_receipts[0].clone());
+    }
+
+    // The first chunk is only stored
+    assert!(batch_receipts[0].tx_receipts.is_empty());
+    assert!(!has_tx_events(&batch_receipts[0]));
+
+    // The last chunk completes the batch, which is executed
+    let apply_blob_outcome = &batch_receipts[1];
+    // The batch is identified by the hash of its data, rather than by the id chosen by the sequencer
+    let batch_hash: [u8; 32] = <DefaultContext as Spec>::Hasher::digest(&batch).into();
+    assert_eq!(batch_hash, apply_blob_outcome.batch_hash);
+    assert_eq!(4, apply_blob_outcome.tx_receipts.len());
+    assert_eq!(
+        SequencerOutcome::Rewarded {
+            reward: 0,
+            sequencer_da_address: sequencer,
+        },
+        apply_blob_outcome.inner,
+    );
+    assert!(has_tx_events(apply_blob_outcome));
+
+    let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+    let storage = storage_manager
+        .create_storage_on(block.next_mock().header())
+        .unwrap();
+    let mut working_set = WorkingSet::new(storage);
+    let resp = runtime.value_setter.query_value(&mut working_set).unwrap();
+    assert_eq!(resp, sov_value_setter::Response { value: Some(33) });
+}
+
+#[test]
+fn test_incomplete_split_batch_slashes_sequencer() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let genesis_block = MockBlock::default();
+    let storage = storage_manager
+        .create_storage_on(genesis_block.header())
+        .unwrap();
+    let (mut state_root, storage) = stf.init_chain(storage, get_genesis_config_for_tests());
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    let private_key = read_private_key::<DefaultContext>().private_key;
+    let batch = Batch {
+        txs: simulate_da(private_key),
+    }
+    .try_to_vec()
+    .unwrap();
+    let batch_id = [7; 32];
+    let mut chunks = split_batch(&batch, batch_id, batch.len() / 2 + 64);
+
+    // Only the first chunk is sent, and the batch is never completed
+    let sequencer = MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS);
+    let mut blobs = vec![MockBlob::new(chunks.remove(0), sequencer, [0; 32])];
+    let mut block = genesis_block;
+    let mut batch_receipts = Vec::new();
+    for _ in 0..CHUNKED_BATCH_TIMEOUT_SLOTS + 2 {
+        block = block.next_mock();
+        let storage = storage_manager.create_storage_on(block.header()).unwrap();
+        let result = stf.apply_slot(
+            &state_root,
+            storage,
+            Default::default(),
+            &block.header,
+            &block.validity_cond,
+            &mut blobs,
+        );
+        blobs.clear();
+        state_root = result.state_root;
+        storage_manager
+            .save_change_set(block.header(), result.change_set)
+            .unwrap();
+        batch_receipts.extend(result.batch_receipts);
+    }
+
+    assert_eq!(2, batch_receipts.len());
+    assert!(batch_receipts[0].tx_receipts.is_empty());
+    assert_eq!(batch_id, batch_receipts[1].batch_hash);
+    assert_eq!(
+        SequencerOutcome::Slashed {
+            reason: SlashingReason::IncompleteChunkSet,
+            sequencer_da_address: sequencer,
+        },
+        batch_receipts[1].inner,
+    );
+}
+
+#[test]
+fn test_invalid_chunk_slashes_sequencer() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let genesis_block = MockBlock::default();
+    let storage = storage_manager
+        .create_storage_on(genesis_block.header())
+        .unwrap();
+    let (state_root, storage) = stf.init_chain(storage, get_genesis_config_for_tests());
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    // The index of the chunk is out of the range of the batch
+    let chunk = BlobChunk {
+        batch_id: [7; 32],
+        index: 2,
+        total: 2,
+        data: vec![1, 2, 3],
+    }
+    .encode();
+    let sequencer = MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS);
+    let mut blobs = [MockBlob::new(chunk, sequencer, [1; 32])];
+
+    let block = genesis_block.next_mock();
+    let storage = storage_manager.create_storage_on(block.header()).unwrap();
+    let result = stf.apply_slot(
+        &state_root,
+        storage,
+        Default::default(),
+        &block.header,
+        &block.validity_cond,
+        &mut blobs,
+    );
+
+    assert_eq!(1, result.batch_receipts.len());
+    let apply_blob_outcome = &result.batch_receipts[0];
+    assert_eq!([1; 32], apply_blob_outcome.batch_hash);
+    assert!(apply_blob_outcome.tx_receipts.is_empty());
+    assert_eq!(
+        SequencerOutcome::Slashed {
+            reason: SlashingReason::InvalidChunk,
+            sequencer_da_address: sequencer,
+        },
+        apply_blob_outcome.inner,
+    );
+}
+
+#[test]
+fn test_resent_chunks_are_ignored() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let genesis_block = MockBlock::default();
+    let storage = storage_manager
+        .create_storage_on(genesis_block.header())
+        .unwrap();
+    let (mut state_root, storage) = stf.init_chain(storage, get_genesis_config_for_tests());
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    let private_key = read_private_key::<DefaultContext>().private_key;
+    let batch = Batch {
+        txs: simulate_da(private_key),
+    }
+    .try_to_vec()
+    .unwrap();
+    let chunks = split_batch(&batch, [7; 32], batch.len() / 2 + 64);
+    assert_eq!(2, chunks.len());
+
+    // The sequencer resends each chunk once, before and after the batch is complete
+    let sequencer = MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS);
+    let blob = |chunk: &Vec<u8>, hash: u8| MockBlob::new(chunk.clone(), sequencer, [hash; 32]);
+    let mut slots = vec![
+        vec![blob(&chunks[0], 0)],
+        vec![blob(&chunks[0], 1), blob(&chunks[1], 2)],
+        vec![blob(&chunks[1], 3)],
+    ];
+    // The batch is forgotten after its deadline, without slashing the sequencer
+    slots.resize(CHUNKED_BATCH_TIMEOUT_SLOTS as usize + 3, vec![]);
+
+    let mut block = genesis_block;
+    let mut batch_receipts = Vec::new();
+    for mut blobs in slots {
+        block = block.next_mock();
+        let storage = storage_manager.create_storage_on(block.header()).unwrap();
+        let result = stf.apply_slot(
+            &state_root,
+            storage,
+            Default::default(),
+            &block.header,
+            &block.validity_cond,
+            &mut blobs,
+        );
+        state_root = result.state_root;
+        storage_manager
+            .save_change_set(block.header(), result.change_set)
+            .unwrap();
+        batch_receipts.extend(result.batch_receipts);
+    }
+
+    assert_eq!(4, batch_receipts.len());
+    for (index, receipt) in batch_receipts.iter().enumerate() {
+        assert_eq!(
+            SequencerOutcome::Rewarded {
+                reward: 0,
+                sequencer_da_address: sequencer,
+            },
+            receipt.inner,
+        );
+        // The batch is executed once, when its last chunk is received
+        let expected_txs = if index == 2 { 4 } else { 0 };
+        assert_eq!(expected_txs, receipt.tx_receipts.len());
+    }
+}
+
 fn read_private_key<C: Context>() -> PrivateKeyAndAddress<C> {
     let token_deployer_data =
         std::fs::read_to_string("../../test-data/keys/token_deployer_private_key.json")
@@ -13,8 +13,8 @@ use sov_rollup_interface::storage::HierarchicalStorageManager;
 use super::{create_storage_manager_for_tests, get_genesis_config_for_tests, RuntimeTest};
 use crate::runtime::Runtime;
 use crate::tests::da_simulation::{
-    simulate_da_with_bad_nonce, simulate_da_with_bad_serialization, simulate_da_with_bad_sig,
-    simulate_da_with_revert_msg,
+    simulate_da_with_bad_chain_id, simulate_da_with_bad_nonce, simulate_da_with_bad_serialization,
+    simulate_da_with_bad_sig, simulate_da_with_revert_msg,
 };
 use crate::tests::StfBlueprintTest;
 
@@ -67,7 +67,10 @@ fn test_tx_revert() {
         let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
 
         assert_eq!(
-            SequencerOutcome::Rewarded(0),
+            SequencerOutcome::Rewarded {
+                reward: 0,
+                sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+            },
             apply_blob_outcome.inner,
             "Unexpected outcome: Batch execution should have succeeded",
         );
@@ -198,6 +201,82 @@ fn test_tx_bad_signature() {
     }
 }
 
+#[test]
+fn test_tx_bad_chain_id() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+
+    let config = get_genesis_config_for_tests();
+
+    let genesis_block = MockBlock::default();
+    let block_1 = genesis_block.next_mock();
+    let storage = {
+        let mut storage_manager = create_storage_manager_for_tests(path);
+        let stf: StfBlueprintTest = StfBlueprint::new();
+        let (genesis_root, storage) = stf.init_chain(
+            storage_manager
+                .create_storage_on(genesis_block.header())
+                .unwrap(),
+            config,
+        );
+        storage_manager
+            .save_change_set(genesis_block.header(), storage)
+            .unwrap();
+
+        // The transaction is correctly signed, but for a different chain.
+        let txs = simulate_da_with_bad_chain_id();
+
+        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS, [0; 32]);
+        let mut blobs = [blob];
+
+        let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+        let apply_block_result = stf.apply_slot(
+            &genesis_root,
+            storage,
+            Default::default(),
+            &block_1.header,
+            &block_1.validity_cond,
+            &mut blobs,
+        );
+
+        assert_eq!(1, apply_block_result.batch_receipts.len());
+        let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
+
+        // The transaction replayed from another chain is skipped, without slashing the sequencer.
+        assert_eq!(1, apply_blob_outcome.tx_receipts.len());
+        assert_eq!(
+            apply_blob_outcome.tx_receipts[0].receipt,
+            TxEffect::Reverted
+        );
+        assert_eq!(
+            apply_blob_outcome.inner,
+            SequencerOutcome::Rewarded {
+                reward: 0,
+                sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+            }
+        );
+
+        // The batch receipt contains no events.
+        assert!(!has_tx_events(&apply_blob_outcome));
+        apply_block_result.change_set
+    };
+
+    {
+        let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+        let mut working_set = WorkingSet::new(storage);
+        let nonce = match runtime
+            .accounts
+            .get_account(get_default_private_key().pub_key(), &mut working_set)
+            .unwrap()
+        {
+            Response::AccountExists { nonce, .. } => nonce,
+            Response::AccountEmpty => 0,
+        };
+        // The replayed transaction was never executed.
+        assert_eq!(0, nonce);
+    }
+}
+
 #[test]
 fn test_tx_bad_nonce() {
     let tempdir = tempfile::tempdir().unwrap();
@@ -245,7 +324,10 @@ fn test_tx_bad_nonce() {
         // We're asserting that here to track if the logic changes
         assert_eq!(
             apply_block_result.batch_receipts[0].inner,
-            SequencerOutcome::Rewarded(0)
+            SequencerOutcome::Rewarded {
+                reward: 0,
+                sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+            }
         );
     }
 }
@@ -31,7 +31,7 @@ async fn bank_tx_tests() -> Result<(), anyhow::Error> {
             BasicKernelGenesisPaths {
                 chain_state: "../test-data/genesis/integration-tests/chain_state.json".into(),
             },
-            RollupProverConfig::Execute,
+            Some(RollupProverConfig::Execute),
         )
         .await;
     });
@@ -27,7 +27,7 @@ async fn evm_tx_tests() -> Result<(), anyhow::Error> {
             BasicKernelGenesisPaths {
                 chain_state: "../test-data/genesis/integration-tests/chain_state.json".into(),
             },
-            RollupProverConfig::Skip,
+            Some(RollupProverConfig::Skip),
         )
         .await;
     });
@@ -8,7 +8,8 @@ use sov_modules_stf_blueprint::kernels::basic::{
     BasicKernelGenesisConfig, BasicKernelGenesisPaths,
 };
 use sov_stf_runner::{
-    ProverServiceConfig, RollupConfig, RollupProverConfig, RpcConfig, RunnerConfig, StorageConfig,
+    ProofSubmissionConfig, ProverServiceConfig, RollupConfig, RollupProverConfig, RpcConfig,
+    RunnerConfig, SequencerConfig, StorageConfig,
 };
 use tokio::sync::oneshot;
 
@@ -16,7 +17,7 @@ pub async fn start_rollup(
     rpc_reporting_channel: oneshot::Sender<SocketAddr>,
     rt_genesis_paths: GenesisPaths,
     kernel_genesis_paths: BasicKernelGenesisPaths,
-    rollup_prover_config: RollupProverConfig,
+    rollup_prover_config: Option<RollupProverConfig>,
 ) {
     let temp_dir = tempfile::tempdir().unwrap();
     let temp_path = temp_dir.path();
@@ -37,7 +38,11 @@ pub async fn start_rollup(
         },
         prover_service: ProverServiceConfig {
             aggregated_proof_block_jump: 1,
+            proof_submission: ProofSubmissionConfig::default(),
         },
+        sequencer: SequencerConfig::default(),
+        remote_prover: None,
+        prover_server: None,
     };
 
     let mock_demo_rollup = MockDemoRollup {};
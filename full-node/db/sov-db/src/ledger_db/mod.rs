@@ -1,25 +1,40 @@
+use std::collections::VecDeque;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 use serde::Serialize;
+use sov_rollup_interface::rpc::NumberedEvent;
 use sov_rollup_interface::services::da::SlotData;
 use sov_rollup_interface::stf::{BatchReceipt, Event};
 use sov_schema_db::{Schema, SchemaBatch, SeekKeyEncoder, DB};
 
 use crate::rocks_db_config::gen_rocksdb_options;
 use crate::schema::tables::{
-    BatchByHash, BatchByNumber, EventByKey, EventByNumber, SlotByHash, SlotByNumber, TxByHash,
-    TxByNumber, LEDGER_TABLES,
+    BatchByHash, BatchByNumber, EventByKey, EventByNumber, LedgerSchemaVersion,
+    ProofSubmissionBySlotHash, ProverAggregationWindow, ProverStatusBySlotHash,
+    ProverWitnessBySlotHash, SlotByHash, SlotByNumber, TxByHash, TxByNumber, LEDGER_TABLES,
 };
 use crate::schema::types::{
-    split_tx_for_storage, BatchNumber, EventNumber, SlotNumber, StoredBatch, StoredSlot,
-    StoredTransaction, TxNumber,
+    split_tx_for_storage, BatchNumber, DbBytes, DbHash, EventNumber, SlotNumber, StoredBatch,
+    StoredProofSubmission, StoredProverStatus, StoredSlot, StoredTransaction, TxNumber,
 };
 
 mod rpc;
 
 const LEDGER_DB_PATH_SUFFIX: &str = "ledger";
 
+/// The version of the layout of the ledger tables. It must be bumped whenever the encoding of a table
+/// changes, so that a database written with another layout is refused instead of being misread.
+/// Version 1 escapes and terminates the event keys of the `EventByKey` table.
+const LEDGER_SCHEMA_VERSION: u64 = 1;
+
+/// How many transactions dropped by a reorganization of the DA layer are remembered,
+/// so that their status can be reported.
+const MAX_DROPPED_TXS: usize = 10_000;
+
+/// The reason reported for the transactions dropped by a reorganization of the DA layer.
+pub(crate) const REORG_DROP_REASON: &str = "reorg";
+
 #[derive(Clone, Debug)]
 /// A database which stores the ledger history (slots, transactions, events, etc).
 /// Ledger data is first ingested into an in-memory map before being fed to the state-transition function.
@@ -30,6 +45,20 @@ pub struct LedgerDB {
     db: Arc<DB>,
     next_item_numbers: Arc<Mutex<ItemNumbers>>,
     slot_subscriptions: tokio::sync::broadcast::Sender<u64>,
+    event_subscriptions: tokio::sync::broadcast::Sender<Arc<Vec<NumberedEvent>>>,
+    /// The slots which were executed but aren't finalized yet, in execution order.
+    unfinalized_slots: Arc<Mutex<Vec<UnfinalizedSlot>>>,
+    /// The transactions of the slots dropped by a reorganization, most recent last.
+    dropped_txs: Arc<Mutex<VecDeque<DbHash>>>,
+    tx_status_subscriptions: tokio::sync::broadcast::Sender<Arc<Vec<DbHash>>>,
+}
+
+/// The transactions of a slot which was executed but isn't committed yet.
+#[derive(Debug)]
+struct UnfinalizedSlot {
+    hash: DbHash,
+    /// The hashes of the transactions, along with their serialized receipts.
+    txs: Vec<(DbHash, DbBytes)>,
 }
 
 /// A SlotNumber, BatchNumber, TxNumber, and EventNumber which are grouped together, typically representing
@@ -96,6 +125,7 @@ impl LedgerDB {
             LEDGER_TABLES.iter().copied(),
             &gen_rocksdb_options(&Default::default(), false),
         )?;
+        Self::check_schema_version(&inner)?;
 
         let next_item_numbers = ItemNumbers {
             slot_number: Self::last_version_written(&inner, SlotByNumber)?.unwrap_or_default() + 1,
@@ -110,6 +140,10 @@ impl LedgerDB {
             db: Arc::new(inner),
             next_item_numbers: Arc::new(Mutex::new(next_item_numbers)),
             slot_subscriptions: tokio::sync::broadcast::channel(10).0,
+            event_subscriptions: tokio::sync::broadcast::channel(10).0,
+            unfinalized_slots: Arc::new(Mutex::new(Vec::new())),
+            dropped_txs: Arc::new(Mutex::new(VecDeque::new())),
+            tx_status_subscriptions: tokio::sync::broadcast::channel(10).0,
         })
     }
 
@@ -232,6 +266,10 @@ impl LedgerDB {
         };
 
         let mut schema_batch = SchemaBatch::new();
+        // The events are only collected if someone is listening
+        let mut new_events = (self.event_subscriptions.receiver_count() > 0)
+            .then(|| Vec::with_capacity(data_to_commit.num_events));
+        let mut committed_txs = Vec::with_capacity(data_to_commit.num_txs);
 
         let first_batch_number = current_item_numbers.batch_number;
         let last_batch_number = first_batch_number + data_to_commit.batch_receipts.len() as u64;
@@ -250,6 +288,13 @@ impl LedgerDB {
                         TxNumber(current_item_numbers.tx_number),
                         &mut schema_batch,
                     )?;
+                    if let Some(new_events) = new_events.as_mut() {
+                        new_events.push(NumberedEvent {
+                            number: current_item_numbers.event_number,
+                            tx_number: current_item_numbers.tx_number,
+                            event,
+                        });
+                    }
                     current_item_numbers.event_number += 1;
                 }
                 self.put_transaction(
@@ -257,6 +302,7 @@ impl LedgerDB {
                     &TxNumber(current_item_numbers.tx_number),
                     &mut schema_batch,
                 )?;
+                committed_txs.push(tx_to_store.hash);
                 current_item_numbers.tx_number += 1;
             }
 
@@ -290,15 +336,193 @@ impl LedgerDB {
         )?;
 
         self.db.write_schemas(schema_batch)?;
+        self.take_unfinalized_slot(&slot_to_store.hash);
 
         // Notify subscribers. This call returns an error IFF there are no subscribers, so we don't need to check the result
         let _ = self
             .slot_subscriptions
             .send(current_item_numbers.slot_number);
+        if let Some(new_events) = new_events {
+            let _ = self.event_subscriptions.send(Arc::new(new_events));
+        }
+        let _ = self.tx_status_subscriptions.send(Arc::new(committed_txs));
 
         Ok(())
     }
 
+    /// Records the transactions of a slot which was executed but isn't finalized yet,
+    /// so that their status is known before the slot is committed.
+    pub fn add_unfinalized_slot<S: SlotData, B, T: Serialize>(
+        &self,
+        data: &SlotCommit<S, B, T>,
+    ) -> Result<(), anyhow::Error> {
+        let txs = data
+            .batch_receipts
+            .iter()
+            .flat_map(|batch_receipt| batch_receipt.tx_receipts.iter())
+            .map(|tx| Ok((tx.tx_hash, bincode::serialize(&tx.receipt)?.into())))
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+        let tx_hashes = txs.iter().map(|(hash, _)| *hash).collect();
+
+        self.unfinalized_slots
+            .lock()
+            .unwrap()
+            .push(UnfinalizedSlot {
+                hash: data.slot_data.hash(),
+                txs,
+            });
+        let _ = self.tx_status_subscriptions.send(Arc::new(tx_hashes));
+        Ok(())
+    }
+
+    /// Forgets the transactions of a slot which wasn't finalized, because it was dropped by a reorganization
+    /// of the DA layer. The transactions are reported as dropped, unless they are executed again.
+    pub fn remove_unfinalized_slot(&self, slot_hash: &DbHash) {
+        if let Some(slot) = self.take_unfinalized_slot(slot_hash) {
+            let tx_hashes: Vec<_> = slot.txs.into_iter().map(|(hash, _)| hash).collect();
+            {
+                let mut dropped_txs = self.dropped_txs.lock().unwrap();
+                dropped_txs.extend(tx_hashes.iter().copied());
+                let excess = dropped_txs.len().saturating_sub(MAX_DROPPED_TXS);
+                dropped_txs.drain(..excess);
+            }
+            let _ = self.tx_status_subscriptions.send(Arc::new(tx_hashes));
+        }
+    }
+
+    fn take_unfinalized_slot(&self, slot_hash: &DbHash) -> Option<UnfinalizedSlot> {
+        let mut unfinalized_slots = self.unfinalized_slots.lock().unwrap();
+        let index = unfinalized_slots
+            .iter()
+            .position(|slot| &slot.hash == slot_hash)?;
+        Some(unfinalized_slots.remove(index))
+    }
+
+    /// Records an aggregated proof posted to the DA layer, by the hash of the last slot it covers.
+    pub fn put_proof_submission(
+        &self,
+        final_slot_hash: &DbHash,
+        proof_submission: &StoredProofSubmission,
+    ) -> Result<(), anyhow::Error> {
+        let mut schema_batch = SchemaBatch::new();
+        schema_batch.put::<ProofSubmissionBySlotHash>(final_slot_hash, proof_submission)?;
+        self.db.write_schemas(schema_batch)
+    }
+
+    /// Gets the aggregated proof posted to the DA layer which covers the slots up to the given one, if any.
+    pub fn get_proof_submission(
+        &self,
+        final_slot_hash: &DbHash,
+    ) -> anyhow::Result<Option<StoredProofSubmission>> {
+        self.db.get::<ProofSubmissionBySlotHash>(final_slot_hash)
+    }
+
+    /// Persists the witness of a new proving job, so that the job can be recovered after a restart.
+    pub fn put_prover_witness(
+        &self,
+        slot_hash: &DbHash,
+        witness: &DbBytes,
+    ) -> Result<(), anyhow::Error> {
+        let mut schema_batch = SchemaBatch::new();
+        schema_batch.put::<ProverWitnessBySlotHash>(slot_hash, witness)?;
+        schema_batch
+            .put::<ProverStatusBySlotHash>(slot_hash, &StoredProverStatus::WitnessSubmitted)?;
+        self.db.write_schemas(schema_batch)
+    }
+
+    /// Updates the status of a proving job.
+    pub fn put_prover_status(
+        &self,
+        slot_hash: &DbHash,
+        status: &StoredProverStatus,
+    ) -> Result<(), anyhow::Error> {
+        let mut schema_batch = SchemaBatch::new();
+        schema_batch.put::<ProverStatusBySlotHash>(slot_hash, status)?;
+        self.db.write_schemas(schema_batch)
+    }
+
+    /// Gets the witness of the proving job of the given slot, if any.
+    pub fn get_prover_witness(&self, slot_hash: &DbHash) -> anyhow::Result<Option<DbBytes>> {
+        self.db.get::<ProverWitnessBySlotHash>(slot_hash)
+    }
+
+    /// Gets the status of the proving job of the given slot, if any.
+    pub fn get_prover_status(
+        &self,
+        slot_hash: &DbHash,
+    ) -> anyhow::Result<Option<StoredProverStatus>> {
+        self.db.get::<ProverStatusBySlotHash>(slot_hash)
+    }
+
+    /// Gets the status of all the persisted proving jobs.
+    pub fn get_prover_jobs(&self) -> anyhow::Result<Vec<(DbHash, StoredProverStatus)>> {
+        let mut iter = self.db.iter::<ProverStatusBySlotHash>()?;
+        iter.seek_to_first();
+        iter.map(|item| item.map(|item| item.into_tuple()))
+            .collect()
+    }
+
+    /// Removes a proving job, once its proof has been sent to the DA layer.
+    pub fn delete_prover_job(&self, slot_hash: &DbHash) -> Result<(), anyhow::Error> {
+        let mut schema_batch = SchemaBatch::new();
+        schema_batch.delete::<ProverWitnessBySlotHash>(slot_hash)?;
+        schema_batch.delete::<ProverStatusBySlotHash>(slot_hash)?;
+        self.db.write_schemas(schema_batch)
+    }
+
+    /// Removes a proving job whose proof has been sent to the DA layer, and records the aggregation
+    /// window the proof was added to, in the same write.
+    pub fn complete_prover_job(
+        &self,
+        slot_hash: &DbHash,
+        aggregation_window: &DbBytes,
+    ) -> Result<(), anyhow::Error> {
+        let mut schema_batch = SchemaBatch::new();
+        schema_batch.delete::<ProverWitnessBySlotHash>(slot_hash)?;
+        schema_batch.delete::<ProverStatusBySlotHash>(slot_hash)?;
+        schema_batch.put::<ProverAggregationWindow>(&(), aggregation_window)?;
+        self.db.write_schemas(schema_batch)
+    }
+
+    /// Records the proofs which are not aggregated yet, and the aggregations
+    /// which are not posted yet, so that they can be recovered after a restart.
+    pub fn put_prover_aggregation_window(
+        &self,
+        aggregation_window: &DbBytes,
+    ) -> Result<(), anyhow::Error> {
+        let mut schema_batch = SchemaBatch::new();
+        schema_batch.put::<ProverAggregationWindow>(&(), aggregation_window)?;
+        self.db.write_schemas(schema_batch)
+    }
+
+    /// Gets the aggregation window recorded with [`LedgerDB::put_prover_aggregation_window`], if any.
+    pub fn get_prover_aggregation_window(&self) -> anyhow::Result<Option<DbBytes>> {
+        self.db.get::<ProverAggregationWindow>(&())
+    }
+
+    /// Records the schema version in a new database, and refuses a database written with another layout.
+    fn check_schema_version(db: &DB) -> anyhow::Result<()> {
+        match db.get::<LedgerSchemaVersion>(&())? {
+            Some(version) if version == LEDGER_SCHEMA_VERSION => Ok(()),
+            Some(version) => anyhow::bail!(
+                "The ledger database has schema version {}, but this node supports version {}. Remove the ledger database and resync the rollup.",
+                version,
+                LEDGER_SCHEMA_VERSION
+            ),
+            // The databases written before the schema version was recorded use the legacy encoding
+            // of the event keys, which can't be read by this node.
+            None if Self::last_version_written(db, SlotByNumber)?.is_some() => anyhow::bail!(
+                "The ledger database was written with a legacy schema, but this node supports version {}. Remove the ledger database and resync the rollup.",
+                LEDGER_SCHEMA_VERSION
+            ),
+            None => {
+                let mut schema_batch = SchemaBatch::new();
+                schema_batch.put::<LedgerSchemaVersion>(&(), &LEDGER_SCHEMA_VERSION)?;
+                db.write_schemas(schema_batch)
+            }
+        }
+    }
+
     fn last_version_written<T: Schema<Key = U>, U: Into<u64>>(
         db: &DB,
         _schema: T,
@@ -325,3 +549,38 @@ impl LedgerDB {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sov_mock_da::MockBlock;
+
+    use super::*;
+
+    #[test]
+    fn test_schema_version_is_checked() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+        db.commit_slot(SlotCommit::<_, (), ()>::new(MockBlock::default()))
+            .unwrap();
+        drop(db);
+        LedgerDB::with_path(temp_dir.path()).unwrap();
+
+        // A database written with another layout is refused
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+        db.db
+            .put::<LedgerSchemaVersion>(&(), &(LEDGER_SCHEMA_VERSION + 1))
+            .unwrap();
+        drop(db);
+        let err = LedgerDB::with_path(temp_dir.path()).unwrap_err();
+        assert!(err.to_string().contains("schema version"));
+
+        // A database written before the schema version was recorded is refused as well
+        let db = LedgerDB::with_path(temp_dir.path().join("legacy")).unwrap();
+        db.db.delete::<LedgerSchemaVersion>(&()).unwrap();
+        db.commit_slot(SlotCommit::<_, (), ()>::new(MockBlock::default()))
+            .unwrap();
+        drop(db);
+        let err = LedgerDB::with_path(temp_dir.path().join("legacy")).unwrap_err();
+        assert!(err.to_string().contains("legacy schema"));
+    }
+}
@@ -1,14 +1,18 @@
+use std::sync::Arc;
+
 use serde::de::DeserializeOwned;
 use sov_rollup_interface::rpc::{
-    BatchIdAndOffset, BatchIdentifier, BatchResponse, EventIdentifier, ItemOrHash,
-    LedgerRpcProvider, QueryMode, SlotIdAndOffset, SlotIdentifier, SlotResponse, TxIdAndOffset,
-    TxIdentifier, TxResponse,
+    BatchIdAndOffset, BatchIdentifier, BatchResponse, EventCursor, EventFilter, EventIdentifier,
+    EventPage, ItemOrHash, LedgerRpcProvider, LedgerTxStatus, NumberedEvent, QueryMode,
+    SlotIdAndOffset, SlotIdentifier, SlotResponse, TxIdAndKey, TxIdAndOffset, TxIdentifier,
+    TxResponse,
 };
-use sov_rollup_interface::stf::Event;
+use sov_rollup_interface::stf::{Event, EventKey};
 use tokio::sync::broadcast::Receiver;
 
 use crate::schema::tables::{
-    BatchByHash, BatchByNumber, EventByNumber, SlotByHash, SlotByNumber, TxByHash, TxByNumber,
+    AfterEventKey, BatchByHash, BatchByNumber, EventByKey, EventByNumber, SlotByHash, SlotByNumber,
+    TxByHash, TxByNumber,
 };
 use crate::schema::types::{
     BatchNumber, EventNumber, SlotNumber, StoredBatch, StoredSlot, TxNumber,
@@ -23,7 +27,7 @@ const MAX_TRANSACTIONS_PER_REQUEST: u64 = 100;
 /// The maximum number of events that can be requested in a single RPC range query
 const MAX_EVENTS_PER_REQUEST: u64 = 500;
 
-use super::LedgerDB;
+use super::{LedgerDB, REORG_DROP_REASON};
 
 impl LedgerRpcProvider for LedgerDB {
     fn get_slots<B: DeserializeOwned, T: DeserializeOwned>(
@@ -270,9 +274,122 @@ impl LedgerRpcProvider for LedgerDB {
         self.get_transactions(&ids, query_mode)
     }
 
+    fn get_tx_status<T: DeserializeOwned>(
+        &self,
+        hash: &[u8; 32],
+    ) -> Result<Option<LedgerTxStatus<T>>, anyhow::Error> {
+        // The unfinalized slots are checked first, because a slot is only forgotten once it's committed
+        {
+            let unfinalized_slots = self.unfinalized_slots.lock().unwrap();
+            for slot in unfinalized_slots.iter().rev() {
+                if let Some((_, receipt)) = slot.txs.iter().find(|(tx_hash, _)| tx_hash == hash) {
+                    return Ok(Some(LedgerTxStatus::Executed {
+                        slot_hash: slot.hash,
+                        receipt: bincode::deserialize(receipt.as_ref())?,
+                    }));
+                }
+            }
+        }
+
+        let Some(tx_number) = self.db.get::<TxByHash>(hash)? else {
+            let dropped = self.dropped_txs.lock().unwrap().contains(hash);
+            return Ok(dropped.then(|| LedgerTxStatus::Dropped {
+                reason: REORG_DROP_REASON.to_string(),
+            }));
+        };
+        let tx = self
+            .db
+            .get::<TxByNumber>(&tx_number)?
+            .ok_or_else(|| anyhow::anyhow!("Transaction {} is indexed but missing", tx_number.0))?;
+        Ok(Some(LedgerTxStatus::Finalized {
+            tx_number: tx_number.0,
+            receipt: bincode::deserialize(tx.custom_receipt.as_ref())?,
+        }))
+    }
+
+    fn get_events_by_filter(
+        &self,
+        filter: &EventFilter,
+        cursor: Option<&EventCursor>,
+        limit: u64,
+    ) -> Result<EventPage<NumberedEvent>, anyhow::Error> {
+        anyhow::ensure!(limit > 0, "the limit of events must be positive");
+        anyhow::ensure!(
+            limit <= MAX_EVENTS_PER_REQUEST,
+            "requested too many events. Requested: {}. Max: {}",
+            limit,
+            MAX_EVENTS_PER_REQUEST
+        );
+
+        let mut iter = self.db.iter::<EventByKey>()?;
+        match cursor {
+            Some(cursor) => iter.seek(&(
+                EventKey::from(cursor.key.clone()),
+                TxNumber(cursor.tx_number),
+                EventNumber(cursor.number),
+            ))?,
+            None => iter.seek(&(
+                EventKey::from(filter.key_prefix.clone()),
+                TxNumber(filter.start_tx),
+                EventNumber(0),
+            ))?,
+        }
+
+        let mut events = Vec::new();
+        while let Some(item) = iter.next() {
+            let (key, tx_number, number) = item?.key;
+            if !key.inner().starts_with(&filter.key_prefix) {
+                break;
+            }
+            // The events of a key are sorted by tx number, so the events outside of the range are skipped
+            // by seeking to the first selected event of the key, or past the last event of the key.
+            if tx_number.0 < filter.start_tx {
+                iter.seek(&(key, TxNumber(filter.start_tx), EventNumber(0)))?;
+                continue;
+            }
+            if !filter.matches(&key, tx_number.0) {
+                iter.seek(&AfterEventKey(&key))?;
+                continue;
+            }
+
+            if events.len() as u64 == limit {
+                return Ok(EventPage {
+                    events,
+                    next_cursor: Some(EventCursor {
+                        key: key.inner().clone(),
+                        tx_number: tx_number.0,
+                        number: number.0,
+                    }),
+                });
+            }
+            let event = self
+                .db
+                .get::<EventByNumber>(&number)?
+                .ok_or_else(|| anyhow::anyhow!("Event {} is indexed but missing", number.0))?;
+            events.push(NumberedEvent {
+                number: number.0,
+                tx_number: tx_number.0,
+                event,
+            });
+        }
+
+        Ok(EventPage {
+            events,
+            next_cursor: None,
+        })
+    }
+
     fn subscribe_slots(&self) -> Result<Receiver<u64>, anyhow::Error> {
         Ok(self.slot_subscriptions.subscribe())
     }
+
+    fn subscribe_events(&self) -> Result<Receiver<Arc<Vec<NumberedEvent>>>, anyhow::Error> {
+        Ok(self.event_subscriptions.subscribe())
+    }
+
+    fn subscribe_tx_statuses(&self) -> Result<Receiver<Arc<Vec<[u8; 32]>>>, anyhow::Error> {
+        Ok(self.tx_status_subscriptions.subscribe())
+    }
 }
 
 impl LedgerDB {
@@ -342,7 +459,21 @@ impl LedgerDB {
                 }
             }
             EventIdentifier::Number(num) => Ok(Some(EventNumber(*num))),
-            EventIdentifier::TxIdAndKey(_) => todo!(),
+            EventIdentifier::TxIdAndKey(TxIdAndKey { tx_id, key }) => {
+                let Some(tx_num) = self.resolve_tx_identifier(tx_id)? else {
+                    return Ok(None);
+                };
+                // The first event with this key emitted by the transaction
+                let mut iter = self.db.iter::<EventByKey>()?;
+                iter.seek(&(key.clone(), tx_num, EventNumber(0)))?;
+                match iter.next() {
+                    Some(item) => {
+                        let (found_key, found_tx_num, event_num) = item?.key;
+                        Ok((&found_key == key && found_tx_num == tx_num).then_some(event_num))
+                    }
+                    None => Ok(None),
+                }
+            }
         }
     }
 
@@ -429,9 +560,48 @@ impl LedgerDB {
 #[cfg(test)]
 mod tests {
     use sov_mock_da::{MockBlob, MockBlock};
-    use sov_rollup_interface::rpc::LedgerRpcProvider;
+    use sov_rollup_interface::rpc::{EventFilter, LedgerRpcProvider, LedgerTxStatus};
+    use sov_rollup_interface::services::da::SlotData;
+    use sov_rollup_interface::stf::{BatchReceipt, Event, TransactionReceipt};
 
     use crate::ledger_db::{LedgerDB, SlotCommit};
+
+    /// A slot with three transactions emitting events with the given keys,
+    /// numbered from 1 to 5 in emission order.
+    fn slot_with_events() -> SlotCommit<MockBlock, (), ()> {
+        let tx_receipts = [
+            vec!["bank/a", "bank"],
+            vec!["bank/b", "seq"],
+            vec!["bank/a"],
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, keys)| TransactionReceipt {
+            tx_hash: [i as u8; 32],
+            body_to_save: None,
+            events: keys
+                .into_iter()
+                .map(|key| Event::new(key, "value"))
+                .collect(),
+            receipt: (),
+            gas_used: vec![],
+        })
+        .collect();
+        let mut slot = SlotCommit::new(MockBlock::default());
+        slot.add_batch(BatchReceipt {
+            batch_hash: [0; 32],
+            tx_receipts,
+            inner: (),
+        });
+        slot
+    }
+
+    fn event_numbers(db: &LedgerDB, filter: &EventFilter) -> Vec<u64> {
+        let page = db.get_events_by_filter(filter, None, 10).unwrap();
+        assert!(page.next_cursor.is_none());
+        page.events.into_iter().map(|event| event.number).collect()
+    }
+
     #[test]
     fn test_slot_subscription() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -444,4 +614,142 @@ mod tests {
 
         assert_eq!(rx.blocking_recv().unwrap(), 1);
     }
+
+    #[test]
+    fn test_events_by_filter() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+        db.commit_slot(slot_with_events()).unwrap();
+
+        // Events are ordered by key, and a key is listed before the longer keys it prefixes
+        let bank = EventFilter {
+            key_prefix: b"bank".to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(vec![2, 1, 5, 3], event_numbers(&db, &bank));
+        assert_eq!(
+            vec![5, 3],
+            event_numbers(
+                &db,
+                &EventFilter {
+                    start_tx: 2,
+                    ..bank.clone()
+                }
+            )
+        );
+        assert_eq!(
+            vec![2, 1],
+            event_numbers(
+                &db,
+                &EventFilter {
+                    end_tx: Some(2),
+                    ..bank.clone()
+                }
+            )
+        );
+        assert_eq!(
+            vec![2, 1, 5, 3, 4],
+            event_numbers(&db, &EventFilter::default())
+        );
+
+        let first_page = db.get_events_by_filter(&bank, None, 2).unwrap();
+        assert_eq!(
+            vec![2, 1],
+            first_page
+                .events
+                .iter()
+                .map(|e| e.number)
+                .collect::<Vec<_>>()
+        );
+        let cursor = first_page.next_cursor.unwrap();
+        let second_page = db.get_events_by_filter(&bank, Some(&cursor), 2).unwrap();
+        assert_eq!(
+            vec![5, 3],
+            second_page
+                .events
+                .iter()
+                .map(|e| e.number)
+                .collect::<Vec<_>>()
+        );
+        assert!(second_page.next_cursor.is_none());
+
+        assert!(db.get_events_by_filter(&bank, None, 0).is_err());
+    }
+
+    #[test]
+    fn test_event_subscription() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+
+        let mut rx = db.subscribe_events().unwrap();
+        db.commit_slot(slot_with_events()).unwrap();
+
+        let events = rx.blocking_recv().unwrap();
+        assert_eq!(
+            vec![(1, 1), (2, 1), (3, 2), (4, 2), (5, 3)],
+            events
+                .iter()
+                .map(|e| (e.number, e.tx_number))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(b"seq", events[3].event.key().inner().as_slice());
+    }
+
+    #[test]
+    fn test_tx_status() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+        let mut rx = db.subscribe_tx_statuses().unwrap();
+
+        let slot = slot_with_events();
+        let slot_hash = slot.slot_data().hash();
+        db.add_unfinalized_slot(&slot).unwrap();
+        assert_eq!(3, rx.blocking_recv().unwrap().len());
+        assert_eq!(
+            Some(LedgerTxStatus::Executed {
+                slot_hash,
+                receipt: ()
+            }),
+            db.get_tx_status(&[1; 32]).unwrap()
+        );
+
+        db.commit_slot(slot).unwrap();
+        assert_eq!(3, rx.blocking_recv().unwrap().len());
+        assert_eq!(
+            Some(LedgerTxStatus::Finalized {
+                tx_number: 2,
+                receipt: ()
+            }),
+            db.get_tx_status(&[1; 32]).unwrap()
+        );
+        assert_eq!(None, db.get_tx_status::<()>(&[3; 32]).unwrap());
+    }
+
+    #[test]
+    fn test_tx_status_of_dropped_slot() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+
+        let slot = slot_with_events();
+        db.add_unfinalized_slot(&slot).unwrap();
+        db.remove_unfinalized_slot(&slot.slot_data().hash());
+        assert_eq!(
+            Some(LedgerTxStatus::Dropped {
+                reason: "reorg".to_string()
+            }),
+            db.get_tx_status::<()>(&[1; 32]).unwrap()
+        );
+
+        // A dropped transaction can be executed again in another slot
+        db.add_unfinalized_slot(&slot).unwrap();
+        assert!(matches!(
+            db.get_tx_status::<()>(&[1; 32]).unwrap(),
+            Some(LedgerTxStatus::Executed { .. })
+        ));
+        db.commit_slot(slot).unwrap();
+        assert!(matches!(
+            db.get_tx_status::<()>(&[1; 32]).unwrap(),
+            Some(LedgerTxStatus::Finalized { .. })
+        ));
+    }
 }
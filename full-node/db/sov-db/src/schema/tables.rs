@@ -17,6 +17,16 @@
 //! - `(EventKey, TxNumber) -> EventNumber`
 //! - `EventNumber -> (EventKey, EventValue)`
 //!
+//! Ledger Schema Version Table:
+//! - `() -> u64`
+//!
+//! Proof Tables:
+//! - `SlotHash -> StoredProofSubmission`
+//!
+//! Prover Tables:
+//! - `SlotHash -> Witness`
+//! - `SlotHash -> StoredProverStatus`
+//!
 //! JMT Tables:
 //! - `KeyHash -> Key`
 //! - `(Key, Version) -> JmtValue`
@@ -34,8 +44,9 @@ use sov_schema_db::schema::{KeyDecoder, KeyEncoder, ValueCodec};
 use sov_schema_db::{CodecError, SeekKeyEncoder};
 
 use super::types::{
-    AccessoryKey, AccessoryStateValue, BatchNumber, DbHash, EventNumber, JmtValue, SlotNumber,
-    StateKey, StoredBatch, StoredSlot, StoredTransaction, TxNumber,
+    AccessoryKey, AccessoryStateValue, BatchNumber, DbBytes, DbHash, EventNumber, JmtValue,
+    SlotNumber, StateKey, StoredBatch, StoredProofSubmission, StoredProverStatus, StoredSlot,
+    StoredTransaction, TxNumber,
 };
 
 /// A list of all tables used by the StateDB. These tables store rollup state - meaning
@@ -57,6 +68,11 @@ pub const LEDGER_TABLES: &[&str] = &[
     TxByNumber::table_name(),
     EventByKey::table_name(),
     EventByNumber::table_name(),
+    ProofSubmissionBySlotHash::table_name(),
+    ProverWitnessBySlotHash::table_name(),
+    ProverStatusBySlotHash::table_name(),
+    ProverAggregationWindow::table_name(),
+    LedgerSchemaVersion::table_name(),
 ];
 
 /// A list of all tables used by the NativeDB. These tables store
@@ -243,11 +259,132 @@ define_table_with_seek_key_codec!(
     (EventByNumber) EventNumber => Event
 );
 
-define_table_with_default_codec!(
+define_table_without_codec!(
     /// A "secondary index" for event data by key
     (EventByKey) (EventKey, TxNumber, EventNumber) => ()
 );
 
+// The event key is escaped and terminated, rather than prefixed with its length, so that the events
+// are sorted by key and the events whose key starts with a given prefix are contiguous.
+// A zero byte of the key is written as `[0x00, 0xff]`, and the key is terminated by `[0x00, 0x00]`.
+// The numbers are written in big-endian order, so that the events of a key are sorted by number.
+const EVENT_KEY_ESCAPE: [u8; 2] = [0x00, 0xff];
+const EVENT_KEY_TERMINATOR: [u8; 2] = [0x00, 0x00];
+
+fn encode_event_key_prefix(key: &[u8], out: &mut Vec<u8>) {
+    for byte in key {
+        if *byte == 0 {
+            out.extend_from_slice(&EVENT_KEY_ESCAPE);
+        } else {
+            out.push(*byte);
+        }
+    }
+}
+
+impl KeyEncoder<EventByKey> for (EventKey, TxNumber, EventNumber) {
+    fn encode_key(&self) -> sov_schema_db::schema::Result<Vec<u8>> {
+        let key = self.0.inner();
+        let mut out = Vec::with_capacity(key.len() + EVENT_KEY_TERMINATOR.len() + 16);
+        encode_event_key_prefix(key, &mut out);
+        out.extend_from_slice(&EVENT_KEY_TERMINATOR);
+        out.write_u64::<BigEndian>(self.1 .0)
+            .expect("serialization to vec is infallible");
+        out.write_u64::<BigEndian>(self.2 .0)
+            .expect("serialization to vec is infallible");
+        Ok(out)
+    }
+}
+
+impl SeekKeyEncoder<EventByKey> for (EventKey, TxNumber, EventNumber) {
+    fn encode_seek_key(&self) -> sov_schema_db::schema::Result<Vec<u8>> {
+        <Self as KeyEncoder<EventByKey>>::encode_key(self)
+    }
+}
+
+impl KeyDecoder<EventByKey> for (EventKey, TxNumber, EventNumber) {
+    fn decode_key(data: &[u8]) -> sov_schema_db::schema::Result<Self> {
+        let mut key = Vec::new();
+        let mut remaining = data;
+        loop {
+            match remaining {
+                [0x00, 0xff, rest @ ..] => {
+                    key.push(0);
+                    remaining = rest;
+                }
+                [0x00, 0x00, rest @ ..] => {
+                    remaining = rest;
+                    break;
+                }
+                [byte, rest @ ..] if *byte != 0 => {
+                    key.push(*byte);
+                    remaining = rest;
+                }
+                _ => {
+                    return Err(CodecError::Wrapped(anyhow::anyhow!(
+                        "Invalid event key encoding"
+                    )))
+                }
+            }
+        }
+        if remaining.len() != 16 {
+            // The tx number and the event number follow the key.
+            return Err(CodecError::InvalidKeyLength {
+                expected: 16,
+                got: remaining.len(),
+            });
+        }
+        let tx_number = remaining.read_u64::<BigEndian>()?;
+        let event_number = remaining.read_u64::<BigEndian>()?;
+        Ok((
+            EventKey::from(key),
+            TxNumber(tx_number),
+            EventNumber(event_number),
+        ))
+    }
+}
+
+/// A position in [`EventByKey`] which sorts after all the events with the given key,
+/// and before the events whose key is longer and starts with it.
+pub(crate) struct AfterEventKey<'a>(pub(crate) &'a EventKey);
+
+impl SeekKeyEncoder<EventByKey> for AfterEventKey<'_> {
+    fn encode_seek_key(&self) -> sov_schema_db::schema::Result<Vec<u8>> {
+        let key = self.0.inner();
+        let mut out = Vec::with_capacity(key.len() + EVENT_KEY_TERMINATOR.len());
+        encode_event_key_prefix(key, &mut out);
+        out.extend_from_slice(&[0x00, 0x01]);
+        Ok(out)
+    }
+}
+
+impl_borsh_value_codec!(EventByKey, ());
+
+define_table_with_default_codec!(
+    /// The aggregated proofs posted to the DA layer, by the hash of the last slot they cover
+    (ProofSubmissionBySlotHash) DbHash => StoredProofSubmission
+);
+
+define_table_with_default_codec!(
+    /// The serialized witnesses of the proving jobs which are not sent to the DA layer yet
+    (ProverWitnessBySlotHash) DbHash => DbBytes
+);
+
+define_table_with_default_codec!(
+    /// The status of the proving jobs which are not sent to the DA layer yet
+    (ProverStatusBySlotHash) DbHash => StoredProverStatus
+);
+
+define_table_with_default_codec!(
+    /// The serialized proofs sent to the DA layer which are not aggregated yet,
+    /// and the aggregations which are not posted yet
+    (ProverAggregationWindow) () => DbBytes
+);
+
+define_table_with_default_codec!(
+    /// The version of the layout of the ledger tables the database was written with
+    (LedgerSchemaVersion) () => u64
+);
+
 define_table_without_codec!(
     /// The source of truth for JMT nodes
     (JmtNodes) NodeKey => Node
@@ -92,6 +92,30 @@ impl<B: DeserializeOwned, T> TryFrom<StoredBatch> for BatchResponse<B, T> {
     }
 }
 
+/// The on-disk format of an aggregated proof posted to the DA layer.
+#[derive(Debug, PartialEq, BorshDeserialize, BorshSerialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(proptest_derive::Arbitrary))]
+pub struct StoredProofSubmission {
+    /// The hash of the first slot covered by the proof.
+    pub initial_slot_hash: DbHash,
+    /// The height of the DA block containing the proof.
+    pub da_height: u64,
+}
+
+/// The on-disk status of a proving job.
+#[derive(Debug, PartialEq, BorshDeserialize, BorshSerialize, Clone)]
+#[cfg_attr(feature = "arbitrary", derive(proptest_derive::Arbitrary))]
+pub enum StoredProverStatus {
+    /// The witness has been submitted, but the proof generation has not started.
+    WitnessSubmitted,
+    /// The proof is being generated.
+    ProvingInProgress,
+    /// The serialized proof, waiting to be sent to the DA layer.
+    Proved(DbBytes),
+    /// The proof generation failed with the given error.
+    Failed(String),
+}
+
 /// The on-disk format of a transaction. Includes the txhash, the serialized tx data,
 /// and identifies the events emitted by this transaction
 #[derive(Debug, PartialEq, BorshSerialize, BorshDeserialize, Clone)]
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use reth_primitives::{BlockNumberOrTag, U256};
+use reth_rpc_types::{Filter, FilterBlockOption};
+use sov_evm::MAX_LOGS_BLOCK_RANGE;
+
+/// How long a filter stays installed without being polled.
+pub const FILTER_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// The maximum number of filters installed at the same time.
+pub const MAX_FILTERS: usize = 1000;
+
+/// A log filter installed with `eth_newFilter`.
+pub struct LogFilter {
+    filter: Filter,
+    /// The first block that has not been returned by `eth_getFilterChanges` yet.
+    next_block: u64,
+    last_polled: Instant,
+}
+
+impl LogFilter {
+    /// Returns the filter for the blocks which were not polled yet, up to the head `head_number`,
+    /// and marks them as polled. Returns `None` if there is nothing new to poll.
+    /// At most [`MAX_LOGS_BLOCK_RANGE`] blocks are returned by a poll, the next ones are left for the following polls.
+    pub fn poll(&mut self, head_number: u64) -> Option<Filter> {
+        let to_block = match self.filter.block_option {
+            FilterBlockOption::Range {
+                to_block: Some(BlockNumberOrTag::Number(to_block)),
+                ..
+            } => to_block.min(head_number),
+            _ => head_number,
+        }
+        .min(self.next_block.saturating_add(MAX_LOGS_BLOCK_RANGE - 1));
+
+        if self.next_block > to_block {
+            return None;
+        }
+
+        let filter = self
+            .filter
+            .clone()
+            .from_block(self.next_block)
+            .to_block(to_block);
+        self.next_block = to_block + 1;
+        Some(filter)
+    }
+}
+
+/// Filters installed by the clients, identified by their id.
+/// Filters which are not polled for `timeout` are uninstalled.
+pub struct EthFilters {
+    filters: HashMap<U256, LogFilter>,
+    next_id: U256,
+    timeout: Duration,
+    max_filters: usize,
+}
+
+impl Default for EthFilters {
+    fn default() -> Self {
+        Self::new(FILTER_TIMEOUT, MAX_FILTERS)
+    }
+}
+
+impl EthFilters {
+    /// Creates an empty registry of at most `max_filters` filters, which expire after `timeout` without polling.
+    pub fn new(timeout: Duration, max_filters: usize) -> Self {
+        Self {
+            filters: HashMap::new(),
+            next_id: U256::ZERO,
+            timeout,
+            max_filters,
+        }
+    }
+
+    /// Installs a new log filter and returns its id, or `None` if too many filters are installed.
+    /// Logs are returned from the `from_block` of the filter, or from the block after the head `head_number`.
+    pub fn install(&mut self, filter: Filter, head_number: u64) -> Option<U256> {
+        self.remove_expired();
+        if self.filters.len() >= self.max_filters {
+            return None;
+        }
+
+        let next_block = match filter.block_option {
+            FilterBlockOption::Range {
+                from_block: Some(BlockNumberOrTag::Number(from_block)),
+                ..
+            } => from_block,
+            FilterBlockOption::Range {
+                from_block: Some(BlockNumberOrTag::Earliest),
+                ..
+            } => 0,
+            _ => head_number + 1,
+        };
+
+        let id = self.next_id;
+        self.next_id += U256::from(1);
+        self.filters.insert(
+            id,
+            LogFilter {
+                filter,
+                next_block,
+                last_polled: Instant::now(),
+            },
+        );
+        Some(id)
+    }
+
+    /// Returns the filter with the given id, if it is installed and hasn't expired,
+    /// and resets its expiration.
+    pub fn get_mut(&mut self, id: &U256) -> Option<&mut LogFilter> {
+        self.remove_expired();
+        let filter = self.filters.get_mut(id)?;
+        filter.last_polled = Instant::now();
+        Some(filter)
+    }
+
+    /// Removes the filter with the given id. Returns `true` if the filter was installed.
+    pub fn uninstall(&mut self, id: &U256) -> bool {
+        self.filters.remove(id).is_some()
+    }
+
+    fn remove_expired(&mut self) {
+        let timeout = self.timeout;
+        self.filters
+            .retain(|_, filter| filter.last_polled.elapsed() < timeout);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn poll_returns_each_block_once() {
+        let mut filters = EthFilters::default();
+        let id = filters.install(Filter::new(), 5).unwrap();
+
+        let filter = filters.get_mut(&id).unwrap();
+        assert!(filter.poll(5).is_none());
+
+        let polled = filter.poll(7).unwrap();
+        assert_eq!(polled.get_from_block(), Some(6));
+        assert_eq!(polled.get_to_block(), Some(7));
+        assert!(filter.poll(7).is_none());
+
+        assert!(filters.uninstall(&id));
+        assert!(!filters.uninstall(&id));
+    }
+
+    #[test]
+    fn poll_respects_the_filter_range() {
+        let mut filters = EthFilters::default();
+        let id = filters
+            .install(Filter::new().from_block(2u64).to_block(4u64), 5)
+            .unwrap();
+
+        let filter = filters.get_mut(&id).unwrap();
+        let polled = filter.poll(10).unwrap();
+        assert_eq!(polled.get_from_block(), Some(2));
+        assert_eq!(polled.get_to_block(), Some(4));
+        assert!(filter.poll(12).is_none());
+    }
+
+    #[test]
+    fn poll_is_limited_to_the_max_range() {
+        let mut filters = EthFilters::default();
+        let id = filters.install(Filter::new().from_block(0u64), 5).unwrap();
+
+        let filter = filters.get_mut(&id).unwrap();
+        let head_number = 2 * MAX_LOGS_BLOCK_RANGE;
+        let polled = filter.poll(head_number).unwrap();
+        assert_eq!(polled.get_from_block(), Some(0));
+        assert_eq!(polled.get_to_block(), Some(MAX_LOGS_BLOCK_RANGE - 1));
+        let polled = filter.poll(head_number).unwrap();
+        assert_eq!(polled.get_from_block(), Some(MAX_LOGS_BLOCK_RANGE));
+    }
+
+    #[test]
+    fn filters_expire() {
+        let mut filters = EthFilters::new(Duration::ZERO, MAX_FILTERS);
+        let id = filters.install(Filter::new(), 5).unwrap();
+        assert!(filters.get_mut(&id).is_none());
+        assert!(!filters.uninstall(&id));
+    }
+
+    #[test]
+    fn number_of_filters_is_limited() {
+        let mut filters = EthFilters::new(FILTER_TIMEOUT, 2);
+        let id = filters.install(Filter::new(), 5).unwrap();
+        filters.install(Filter::new(), 5).unwrap();
+        assert!(filters.install(Filter::new(), 5).is_none());
+
+        assert!(filters.uninstall(&id));
+        assert!(filters.install(Filter::new(), 5).is_some());
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(feature = "experimental")]
 mod batch_builder;
 #[cfg(feature = "experimental")]
+mod filters;
+#[cfg(feature = "experimental")]
 mod gas_price;
 #[cfg(feature = "experimental")]
 pub use experimental::{get_ethereum_rpc, Ethereum};
@@ -17,16 +19,20 @@ pub mod experimental {
     use borsh::ser::BorshSerialize;
     use demo_stf::runtime::Runtime;
     use ethers::types::{Bytes, H256};
+    use futures::future::Either;
     use jsonrpsee::types::ErrorObjectOwned;
-    use jsonrpsee::RpcModule;
+    use jsonrpsee::{RpcModule, SubscriptionMessage};
     use reth_primitives::{TransactionSignedNoHash as RethTransactionSignedNoHash, U128, U256};
-    use reth_rpc_types::{CallRequest, TransactionRequest, TypedTransactionRequest};
+    use reth_rpc_types::{CallRequest, Filter, TransactionRequest, TypedTransactionRequest};
     use sov_evm::{CallMessage, Evm, RlpEvmTransaction};
     use sov_modules_api::utils::to_jsonrpsee_error_object;
     use sov_modules_api::{EncodeCall, PrivateKey, WorkingSet};
+    use sov_rollup_interface::rpc::LedgerRpcProvider;
     use sov_rollup_interface::services::da::DaService;
+    use tokio::sync::broadcast;
 
     use super::batch_builder::EthBatchBuilder;
+    use super::filters::EthFilters;
     #[cfg(feature = "local")]
     use super::DevSigner;
     use crate::gas_price::gas_oracle::GasPriceOracle;
@@ -47,6 +53,7 @@ pub mod experimental {
         da_service: Da,
         eth_rpc_config: EthRpcConfig<C>,
         storage: C::Storage,
+        ledger_db: &impl LedgerRpcProvider,
     ) -> RpcModule<Ethereum<C, Da>> {
         // Unpack config
         let EthRpcConfig {
@@ -70,6 +77,11 @@ pub mod experimental {
             sov_accounts::Response::AccountEmpty { .. } => 0,
         };
 
+        // Subscriptions are notified about new blocks after each processed slot
+        let slot_subscriptions = ledger_db
+            .subscribe_slots()
+            .expect("Failed to subscribe to the processed slots");
+
         let mut rpc = RpcModule::new(Ethereum::new(
             da_service,
             Arc::new(Mutex::new(EthBatchBuilder::new(
@@ -81,6 +93,7 @@ pub mod experimental {
             #[cfg(feature = "local")]
             eth_signer,
             storage,
+            slot_subscriptions,
         ));
 
         register_rpc_methods(&mut rpc).expect("Failed to register sequencer RPC methods");
@@ -94,6 +107,8 @@ pub mod experimental {
         #[cfg(feature = "local")]
         eth_signer: DevSigner,
         storage: C::Storage,
+        filters: Mutex<EthFilters>,
+        slot_subscriptions: broadcast::Receiver<u64>,
     }
 
     impl<C: sov_modules_api::Context, Da: DaService> Ethereum<C, Da> {
@@ -103,6 +118,7 @@ pub mod experimental {
             gas_price_oracle_config: GasPriceOracleConfig,
             #[cfg(feature = "local")] eth_signer: DevSigner,
             storage: C::Storage,
+            slot_subscriptions: broadcast::Receiver<u64>,
         ) -> Self {
             let evm = Evm::<C>::default();
             let gas_price_oracle = GasPriceOracle::new(evm, gas_price_oracle_config);
@@ -113,10 +129,72 @@ pub mod experimental {
                 #[cfg(feature = "local")]
                 eth_signer,
                 storage,
+                filters: Mutex::new(EthFilters::default()),
+                slot_subscriptions,
             }
         }
     }
 
+    /// The kind of an `eth_subscribe` subscription.
+    enum EthSubscription {
+        /// Sends the header of every new block.
+        NewHeads,
+        /// Sends every new log matching the filter.
+        Logs(Filter),
+    }
+
+    impl<C: sov_modules_api::Context, Da: DaService> Ethereum<C, Da> {
+        fn head_block_number(&self) -> Result<u64, ErrorObjectOwned> {
+            let mut working_set = WorkingSet::<C>::new(self.storage.clone());
+            let head_number = Evm::<C>::default().block_number(&mut working_set)?;
+            Ok(head_number.to::<u64>())
+        }
+
+        fn get_logs(&self, filter: Filter) -> Result<Vec<reth_rpc_types::Log>, ErrorObjectOwned> {
+            let mut working_set = WorkingSet::<C>::new(self.storage.clone());
+            Evm::<C>::default().eth_get_logs(filter, &mut working_set)
+        }
+
+        /// Builds the messages of a subscription for the blocks from `from_block` to `to_block`.
+        fn subscription_messages(
+            &self,
+            subscription: &EthSubscription,
+            from_block: u64,
+            to_block: u64,
+        ) -> Result<Vec<SubscriptionMessage>, ErrorObjectOwned> {
+            let mut messages = Vec::new();
+            match subscription {
+                EthSubscription::NewHeads => {
+                    let evm = Evm::<C>::default();
+                    let mut working_set = WorkingSet::<C>::new(self.storage.clone());
+                    for block_number in from_block..=to_block {
+                        let block = evm.get_block_by_number(
+                            Some(format!("0x{:x}", block_number)),
+                            None,
+                            &mut working_set,
+                        )?;
+                        if let Some(block) = block {
+                            messages.push(
+                                SubscriptionMessage::from_json(&block.inner.header)
+                                    .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?,
+                            );
+                        }
+                    }
+                }
+                EthSubscription::Logs(filter) => {
+                    let filter = filter.clone().from_block(from_block).to_block(to_block);
+                    for log in self.get_logs(filter)? {
+                        messages.push(
+                            SubscriptionMessage::from_json(&log)
+                                .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?,
+                        );
+                    }
+                }
+            }
+            Ok(messages)
+        }
+    }
+
     impl<C: sov_modules_api::Context, Da: DaService> Ethereum<C, Da> {
         fn make_raw_tx(
             &self,
@@ -243,6 +321,105 @@ pub mod experimental {
             },
         )?;
 
+        rpc.register_async_method("eth_newFilter", |parameters, ethereum| async move {
+            let filter: Filter = parameters.one()?;
+            let head_number = ethereum.head_block_number()?;
+            ethereum
+                .filters
+                .lock()
+                .unwrap()
+                .install(filter, head_number)
+                .ok_or_else(|| {
+                    to_jsonrpsee_error_object("Too many filters installed", ETH_RPC_ERROR)
+                })
+        })?;
+
+        rpc.register_async_method("eth_getFilterChanges", |parameters, ethereum| async move {
+            let id: U256 = parameters.one()?;
+            let head_number = ethereum.head_block_number()?;
+            let filter = ethereum
+                .filters
+                .lock()
+                .unwrap()
+                .get_mut(&id)
+                .ok_or_else(|| to_jsonrpsee_error_object("Filter not found", ETH_RPC_ERROR))?
+                .poll(head_number);
+
+            match filter {
+                Some(filter) => ethereum.get_logs(filter),
+                None => Ok(Vec::new()),
+            }
+        })?;
+
+        rpc.register_async_method("eth_uninstallFilter", |parameters, ethereum| async move {
+            let id: U256 = parameters.one()?;
+            Ok::<_, ErrorObjectOwned>(ethereum.filters.lock().unwrap().uninstall(&id))
+        })?;
+
+        rpc.register_subscription(
+            "eth_subscribe",
+            "eth_subscription",
+            "eth_unsubscribe",
+            |parameters, pending_subscription, ethereum| async move {
+                let mut params = parameters.sequence();
+                let kind: String = params.next()?;
+                let subscription_kind = match kind.as_str() {
+                    "newHeads" => EthSubscription::NewHeads,
+                    "logs" => EthSubscription::Logs(params.optional_next()?.unwrap_or_default()),
+                    _ => {
+                        return Err(to_jsonrpsee_error_object(
+                            format!("Unsupported subscription: {}", kind),
+                            ETH_RPC_ERROR,
+                        )
+                        .into())
+                    }
+                };
+
+                let mut rx = ethereum.slot_subscriptions.resubscribe();
+                let mut next_block = ethereum.head_block_number()? + 1;
+
+                // Accept the subscription. This message is sent immediately
+                let subscription = pending_subscription.accept().await?;
+                let closed = subscription.closed();
+                futures::pin_mut!(closed);
+
+                // This loop continues running until the subscription ends.
+                loop {
+                    let next_msg = rx.recv();
+                    futures::pin_mut!(next_msg);
+                    match futures::future::select(closed, next_msg).await {
+                        // If the subscription closed, we're done
+                        Either::Left(_) => break Ok(()),
+                        // Missing some slots is fine, the new blocks are read from the state
+                        Either::Right((Err(broadcast::error::RecvError::Closed), _)) => {
+                            break Ok(())
+                        }
+                        Either::Right((_, channel_closing_future)) => {
+                            closed = channel_closing_future;
+
+                            let head_number = ethereum.head_block_number()?;
+                            if next_block > head_number {
+                                continue;
+                            }
+                            let messages = ethereum.subscription_messages(
+                                &subscription_kind,
+                                next_block,
+                                head_number,
+                            )?;
+                            next_block = head_number + 1;
+
+                            for msg in messages {
+                                // Sending only fails if the subscriber has canceled, so we can stop sending messages
+                                if subscription.send(msg).await.is_err() {
+                                    return Ok(());
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+        )?;
+
         #[cfg(feature = "local")]
         rpc.register_async_method("eth_accounts", |_parameters, ethereum| async move {
             Ok::<_, ErrorObjectOwned>(ethereum.eth_signer.signers())
@@ -5,11 +5,11 @@
 
 use jsonrpsee::proc_macros::rpc;
 use sov_rollup_interface::rpc::{
-    BatchIdentifier, EventIdentifier, QueryMode, SlotIdentifier, TxIdentifier,
+    BatchIdentifier, EventCursor, EventFilter, EventIdentifier, EventPage, QueryMode,
+    SlotIdentifier, TxIdentifier,
 };
-use sov_rollup_interface::stf::Event;
 
-use crate::HexHash;
+use crate::{EventResponse, HexHash, NumberedEventResponse};
 
 /// A [`jsonrpsee`] trait for interacting with the ledger JSON-RPC API.
 ///
@@ -55,7 +55,10 @@ where
 
     /// Gets a list of events by ID. The IDs need not be ordered.
     #[method(name = "getEvents")]
-    async fn get_events(&self, event_ids: Vec<EventIdentifier>) -> RpcResult<Vec<Option<Event>>>;
+    async fn get_events(
+        &self,
+        event_ids: Vec<EventIdentifier>,
+    ) -> RpcResult<Vec<Option<EventResponse>>>;
 
     /// Gets a single slot by hash.
     #[method(name = "getSlotByHash")]
@@ -99,7 +102,7 @@ where
 
     /// Gets a single event by number.
     #[method(name = "getEventByNumber")]
-    async fn get_event_by_number(&self, number: u64) -> RpcResult<Option<Event>>;
+    async fn get_event_by_number(&self, number: u64) -> RpcResult<Option<EventResponse>>;
 
     /// Gets a single tx by number.
     #[method(name = "getTransactionByNumber")]
@@ -138,8 +141,23 @@ where
         query_mode: QueryMode,
     ) -> RpcResult<Vec<Option<Tx>>>;
 
+    /// Gets a page of the events matching the filter, ordered by key and then by number.
+    /// The next page is fetched by passing the returned cursor, if any.
+    #[method(name = "getEventsByFilter")]
+    async fn get_events_by_filter(
+        &self,
+        filter: EventFilter,
+        cursor: Option<EventCursor>,
+        limit: u64,
+    ) -> RpcResult<EventPage<NumberedEventResponse>>;
+
     /// Subscription method to receive a notification each time a slot is
     /// processed.
     #[subscription(name = "subscribeSlots", item = u64)]
     async fn subscribe_slots(&self) -> SubscriptionResult;
+
+    /// Subscription method to receive the events matching the filter as they are
+    /// committed to the ledger.
+    #[subscription(name = "subscribeEvents", item = NumberedEventResponse)]
+    async fn subscribe_events(&self, filter: EventFilter) -> SubscriptionResult;
 }
@@ -1,5 +1,7 @@
 #![forbid(unsafe_code)]
 
+use sov_rollup_interface::stf::Event;
+
 #[cfg(feature = "server")]
 pub mod server;
 
@@ -10,3 +12,27 @@ pub mod client;
 /// `0x`. See [`sov_rollup_interface::rpc::utils::rpc_hex`].
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct HexHash(#[serde(with = "sov_rollup_interface::rpc::utils::rpc_hex")] pub [u8; 32]);
+
+/// An event returned by the ledger JSON-RPC API.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EventResponse {
+    /// The raw key and value of the event.
+    #[serde(flatten)]
+    pub event: Event,
+    /// The event decoded by the runtime, if it was emitted by a module with typed events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decoded: Option<serde_json::Value>,
+}
+
+/// An event returned by the event queries and the event subscription of the ledger JSON-RPC API,
+/// along with its position in the ledger.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NumberedEventResponse {
+    /// The number of the event.
+    pub number: u64,
+    /// The number of the transaction which emitted the event.
+    pub tx_number: u64,
+    /// The event.
+    #[serde(flatten)]
+    pub event: EventResponse,
+}
@@ -5,17 +5,26 @@ use jsonrpsee::types::ErrorObjectOwned;
 use jsonrpsee::{RpcModule, SubscriptionMessage};
 use serde::de::DeserializeOwned;
 use sov_modules_api::utils::to_jsonrpsee_error_object;
+use sov_modules_api::DecodeEvent;
 use sov_rollup_interface::rpc::{
-    BatchIdentifier, EventIdentifier, LedgerRpcProvider, QueryMode, SlotIdentifier, TxIdentifier,
+    BatchIdentifier, EventCursor, EventFilter, EventIdentifier, EventPage, LedgerRpcProvider,
+    NumberedEvent, QueryMode, SlotIdentifier, TxIdentifier,
 };
+use sov_rollup_interface::stf::Event;
 
-use crate::HexHash;
+use crate::{EventResponse, HexHash, NumberedEventResponse};
 
 const LEDGER_RPC_ERROR: &str = "LEDGER_RPC_ERROR";
 
+/// The number of events returned by `ledger_getEventsByFilter` when no limit is given.
+const DEFAULT_EVENTS_PAGE_SIZE: u64 = 100;
+
 /// Creates a new [`jsonrpsee::RpcModule`] that exposes all JSON-RPC methods
 /// necessary to interface with the [`LedgerRpcProvider`].
 ///
+/// The events are decoded with `E`, the event type of the runtime, and returned as JSON
+/// alongside their raw key and value.
+///
 /// # Example
 /// ```
 /// use sov_ledger_rpc::server::rpc_module;
@@ -26,7 +35,7 @@ const LEDGER_RPC_ERROR: &str = "LEDGER_RPC_ERROR";
 /// async fn rpc_server() -> jsonrpsee::server::ServerHandle {
 ///     let dir = tempdir().unwrap();
 ///     let db = LedgerDB::with_path(dir).unwrap();
-///     let rpc_module = rpc_module::<LedgerDB, u32, u32>(db).unwrap();
+///     let rpc_module = rpc_module::<LedgerDB, u32, u32, ()>(db).unwrap();
 ///
 ///     let server = jsonrpsee::server::ServerBuilder::default()
 ///         .build("127.0.0.1:0")
@@ -35,11 +44,12 @@ const LEDGER_RPC_ERROR: &str = "LEDGER_RPC_ERROR";
 ///     server.start(rpc_module)
 /// }
 /// ```
-pub fn rpc_module<T, B, Tx>(ledger: T) -> anyhow::Result<RpcModule<T>>
+pub fn rpc_module<T, B, Tx, E>(ledger: T) -> anyhow::Result<RpcModule<T>>
 where
     T: LedgerRpcProvider + Send + Sync + 'static,
     B: serde::Serialize + DeserializeOwned + Clone + 'static,
     Tx: serde::Serialize + DeserializeOwned + Clone + 'static,
+    E: DecodeEvent + serde::Serialize + 'static,
 {
     let mut rpc = RpcModule::new(ledger);
 
@@ -72,8 +82,15 @@ where
     })?;
     rpc.register_method("ledger_getEvents", move |params, db| {
         let ids: Vec<EventIdentifier> = params.parse().or_else(|_| params.one())?;
-        db.get_events(&ids)
-            .map_err(|e| to_jsonrpsee_error_object(e, LEDGER_RPC_ERROR))
+        let events = db
+            .get_events(&ids)
+            .map_err(|e| to_jsonrpsee_error_object(e, LEDGER_RPC_ERROR))?;
+        Ok::<_, ErrorObjectOwned>(
+            events
+                .into_iter()
+                .map(|event| event.map(event_response::<E>))
+                .collect::<Vec<_>>(),
+        )
     })?;
 
     // By-hash getters.
@@ -117,9 +134,10 @@ where
     })?;
     rpc.register_method("ledger_getEventByNumber", move |params, ledger| {
         let args: u64 = params.one()?;
-        ledger
+        let event = ledger
             .get_event_by_number(args)
-            .map_err(|e| to_jsonrpsee_error_object(e, LEDGER_RPC_ERROR))
+            .map_err(|e| to_jsonrpsee_error_object(e, LEDGER_RPC_ERROR))?;
+        Ok::<_, ErrorObjectOwned>(event.map(event_response::<E>))
     })?;
 
     // Range getters.
@@ -142,6 +160,33 @@ where
             .map_err(|e| to_jsonrpsee_error_object(e, LEDGER_RPC_ERROR))
     })?;
 
+    // Transaction status.
+    rpc.register_method("tx_getStatus", move |params, ledger| {
+        let tx_hash: [u8; 32] = params.one()?;
+        ledger
+            .get_tx_status::<Tx>(&tx_hash)
+            .map_err(|e| to_jsonrpsee_error_object(e, LEDGER_RPC_ERROR))
+    })?;
+
+    // Event queries.
+    rpc.register_method("ledger_getEventsByFilter", move |params, ledger| {
+        let mut params = params.sequence();
+        let filter: EventFilter = params.next()?;
+        let cursor: Option<EventCursor> = params.optional_next()?;
+        let limit = params.optional_next()?.unwrap_or(DEFAULT_EVENTS_PAGE_SIZE);
+        let page = ledger
+            .get_events_by_filter(&filter, cursor.as_ref(), limit)
+            .map_err(|e| to_jsonrpsee_error_object(e, LEDGER_RPC_ERROR))?;
+        Ok::<_, ErrorObjectOwned>(EventPage {
+            events: page
+                .events
+                .into_iter()
+                .map(numbered_event_response::<E>)
+                .collect(),
+            next_cursor: page.next_cursor,
+        })
+    })?;
+
     rpc.register_subscription(
         "ledger_subscribeSlots",
         "ledger_slotProcessed",
@@ -178,9 +223,64 @@ where
         },
     )?;
 
+    rpc.register_subscription(
+        "ledger_subscribeEvents",
+        "ledger_eventEmitted",
+        "ledger_unsubscribeEvents",
+        |params, pending_subscription, db| async move {
+            let filter: EventFilter = params.sequence().optional_next()?.unwrap_or_default();
+            let mut rx = db
+                .subscribe_events()
+                .map_err(|e| to_jsonrpsee_error_object(e, LEDGER_RPC_ERROR))?;
+
+            let subscription = pending_subscription.accept().await?;
+            let closed = subscription.closed();
+            futures::pin_mut!(closed);
+
+            // The events of each slot are received together, and sent one by one if they match the filter.
+            loop {
+                let next_msg = rx.recv();
+                futures::pin_mut!(next_msg);
+                match futures::future::select(closed, next_msg).await {
+                    Either::Left(_) => break Ok(()),
+                    Either::Right((outcome, channel_closing_future)) => {
+                        for event in outcome?.iter() {
+                            if !filter.matches(event.event.key(), event.tx_number) {
+                                continue;
+                            }
+                            let msg = SubscriptionMessage::from_json(
+                                &numbered_event_response::<E>(event.clone()),
+                            )?;
+                            if subscription.send(msg).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                        closed = channel_closing_future;
+                    }
+                }
+            }
+        },
+    )?;
+
     Ok(rpc)
 }
 
+/// Decodes the event with the event type of the runtime, if it was emitted by a module with typed events.
+fn event_response<E: DecodeEvent + serde::Serialize>(event: Event) -> EventResponse {
+    let decoded = E::decode_event(&event).and_then(|decoded| serde_json::to_value(decoded).ok());
+    EventResponse { event, decoded }
+}
+
+fn numbered_event_response<E: DecodeEvent + serde::Serialize>(
+    event: NumberedEvent,
+) -> NumberedEventResponse {
+    NumberedEventResponse {
+        number: event.number,
+        tx_number: event.tx_number,
+        event: event_response::<E>(event.event),
+    }
+}
+
 #[derive(serde::Deserialize)]
 struct RangeArgs(u64, u64, #[serde(default)] QueryMode);
 
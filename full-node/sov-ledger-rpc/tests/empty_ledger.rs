@@ -9,15 +9,15 @@ use sov_ledger_rpc::server::rpc_module;
 use sov_ledger_rpc::HexHash;
 use sov_modules_api::Event;
 use sov_rollup_interface::rpc::{
-    BatchResponse, EventIdentifier, QueryMode, SlotResponse, TxIdAndOffset, TxIdentifier,
-    TxResponse,
+    BatchResponse, EventFilter, EventIdentifier, LedgerTxStatus, QueryMode, SlotResponse,
+    TxIdAndOffset, TxIdentifier, TxResponse,
 };
 use tempfile::tempdir;
 
 async fn rpc_server() -> (jsonrpsee::server::ServerHandle, SocketAddr) {
     let dir = tempdir().unwrap();
     let db = LedgerDB::with_path(dir).unwrap();
-    let rpc_module = rpc_module::<LedgerDB, u32, u32>(db).unwrap();
+    let rpc_module = rpc_module::<LedgerDB, u32, u32, ()>(db).unwrap();
 
     let server = jsonrpsee::server::ServerBuilder::default()
         .build("127.0.0.1:0")
@@ -103,6 +103,21 @@ async fn getters_succeed() {
         .get_txs_range(0, 1, QueryMode::Compact)
         .await
         .unwrap();
+
+    let page = rpc_client
+        .get_events_by_filter(EventFilter::default(), None, 10)
+        .await
+        .unwrap();
+    assert!(page.events.is_empty());
+    assert!(page.next_cursor.is_none());
+
+    let mut params = ArrayParams::new();
+    params.insert([0u8; 32]).unwrap();
+    let status = rpc_client
+        .request::<Option<LedgerTxStatus<u32>>, _>("tx_getStatus", params)
+        .await
+        .unwrap();
+    assert!(status.is_none());
 }
 
 #[tokio::test]
@@ -113,6 +128,33 @@ async fn subscribe_slots_succeeds() {
     rpc_client.subscribe_slots().await.unwrap();
 }
 
+#[tokio::test]
+async fn subscribe_events_succeeds() {
+    let (_server_handle, addr) = rpc_server().await;
+    let rpc_client = rpc_client(addr).await;
+
+    rpc_client
+        .subscribe_events(EventFilter {
+            key_prefix: b"bank".to_vec(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn get_events_by_filter_with_optional_params() {
+    let (_server_handle, addr) = rpc_server().await;
+    let rpc_client = rpc_client(addr).await;
+
+    let mut params = ArrayParams::new();
+    params.insert(EventFilter::default()).unwrap();
+    rpc_client
+        .request::<serde_json::Value, _>("ledger_getEventsByFilter", params)
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 async fn get_head_with_optional_query_mode() {
     let (_server_handle, addr) = rpc_server().await;
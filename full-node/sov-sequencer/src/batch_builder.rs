@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::io::Cursor;
 
 use anyhow::{bail, Context as ErrorContext};
@@ -6,15 +6,21 @@ use borsh::BorshDeserialize;
 use sov_modules_api::digest::Digest;
 use sov_modules_api::transaction::Transaction;
 use sov_modules_api::{Context, DispatchCall, PublicKey, Spec, WorkingSet};
-use sov_rollup_interface::services::batch_builder::BatchBuilder;
+use sov_rollup_interface::services::batch_builder::{BatchBuilder, TxStatus};
 use tracing::{info, warn};
 
+use crate::nonces::{PendingNonces, TxStatuses};
+
+/// Reads the chain id of the rollup from its state.
+/// Transactions signed for another chain are rejected by the batch builders.
+pub type ChainIdReader<C> = fn(&mut WorkingSet<C>) -> u64;
+
 /// Transaction stored in the mempool.
 pub struct PooledTransaction<C: Context, R: DispatchCall<Context = C>> {
     /// Raw transaction bytes.
-    raw: Vec<u8>,
+    pub(crate) raw: Vec<u8>,
     /// Deserialized transaction.
-    tx: Transaction<C>,
+    pub(crate) tx: Transaction<C>,
     /// The decoded runtime message, cached during initial verification.
     msg: Option<R::Decodable>,
 }
@@ -37,20 +43,70 @@ where
     C: Context,
     R: DispatchCall<Context = C>,
 {
-    fn calculate_hash(&self) -> [u8; 32] {
+    /// Deserializes and verifies the transaction, checks that it was signed for `chain_id`,
+    /// and decodes its runtime message.
+    pub(crate) fn try_from_raw(raw: Vec<u8>, chain_id: u64) -> anyhow::Result<Self> {
+        // Deserialize
+        let mut data = Cursor::new(&raw);
+        let tx = Transaction::<C>::deserialize_reader(&mut data)
+            .context("Failed to deserialize transaction")?;
+
+        // Verify
+        tx.verify().context("Failed to verify transaction")?;
+        if tx.chain_id() != chain_id {
+            bail!(
+                "Transaction chain id {} doesn't match the rollup chain id {}",
+                tx.chain_id(),
+                chain_id
+            )
+        }
+
+        // Decode
+        let msg = R::decode_call(tx.runtime_msg())
+            .map_err(anyhow::Error::new)
+            .context("Failed to decode message in transaction")?;
+
+        Ok(Self {
+            raw,
+            tx,
+            msg: Some(msg),
+        })
+    }
+
+    /// Takes the decoded runtime message cached upon accepting transaction
+    /// into the pool or attempts to decode the message again if
+    /// the transaction was previously executed,
+    /// but discarded from the batch due to the batch size.
+    pub(crate) fn take_msg(&mut self) -> R::Decodable {
+        self.msg.take().unwrap_or_else(||
+            // SAFETY: The transaction was accepted into the pool,
+            // so we know that the runtime message is valid.
+            R::decode_call(self.tx.runtime_msg()).expect("noop; qed"))
+    }
+
+    pub(crate) fn calculate_hash(&self) -> [u8; 32] {
         <C as Spec>::Hasher::digest(&self.raw[..]).into()
     }
 }
 
 /// BatchBuilder that creates batches of transactions in the order they were submitted
 /// Only transactions that were successfully dispatched are included.
+///
+/// Transactions are admitted in nonce order for every sender: transactions with nonces already
+/// used are rejected, while transactions with future nonces are queued until the missing ones arrive.
 pub struct FiFoStrictBatchBuilder<C: Context, R: DispatchCall<Context = C>> {
     mempool: VecDeque<PooledTransaction<C, R>>,
+    /// Transactions waiting for transactions with lower nonces of the same sender, by nonce.
+    queued: HashMap<C::PublicKey, BTreeMap<u64, PooledTransaction<C, R>>>,
+    queued_txs_count: usize,
     mempool_max_txs_count: usize,
     runtime: R,
     max_batch_size_bytes: usize,
     current_storage: C::Storage,
     sequencer: C::Address,
+    chain_id: ChainIdReader<C>,
+    nonces: PendingNonces<C>,
+    statuses: TxStatuses,
 }
 
 impl<C, R> FiFoStrictBatchBuilder<C, R>
@@ -65,15 +121,84 @@ where
         runtime: R,
         current_storage: C::Storage,
         sequencer: C::Address,
+        chain_id: ChainIdReader<C>,
     ) -> Self {
         Self {
             mempool: VecDeque::new(),
+            queued: HashMap::new(),
+            queued_txs_count: 0,
             mempool_max_txs_count,
             max_batch_size_bytes,
             runtime,
+            nonces: PendingNonces::new(current_storage.clone()),
+            statuses: TxStatuses::default(),
             current_storage,
             sequencer,
+            chain_id,
+        }
+    }
+
+    /// Adds a transaction with the pending nonce of its sender to the mempool,
+    /// followed by the queued transactions of the sender that become ready.
+    fn push_ready(&mut self, pooled: PooledTransaction<C, R>) {
+        let pub_key = pooled.tx.pub_key().clone();
+        let mut next_nonce = pooled.tx.nonce() + 1;
+        self.statuses
+            .set(pooled.calculate_hash(), TxStatus::Pending);
+        self.mempool.push_back(pooled);
+
+        if let Some(queued) = self.queued.get_mut(&pub_key) {
+            while let Some(pooled) = queued.remove(&next_nonce) {
+                self.queued_txs_count -= 1;
+                self.statuses
+                    .set(pooled.calculate_hash(), TxStatus::Pending);
+                self.mempool.push_back(pooled);
+                next_nonce += 1;
+            }
+            if queued.is_empty() {
+                self.queued.remove(&pub_key);
+            }
+        }
+
+        self.nonces.set_pending_nonce(pub_key, next_nonce);
+    }
+
+    fn queue(&mut self, pooled: PooledTransaction<C, R>) -> anyhow::Result<()> {
+        let nonce = pooled.tx.nonce();
+        let queued = self.queued.entry(pooled.tx.pub_key().clone()).or_default();
+        if queued.contains_key(&nonce) {
+            bail!("Transaction with nonce {} is already queued", nonce)
+        }
+
+        self.statuses.set(pooled.calculate_hash(), TxStatus::Queued);
+        queued.insert(nonce, pooled);
+        self.queued_txs_count += 1;
+        Ok(())
+    }
+
+    /// Drops a transaction that failed to be dispatched. The following transactions of its sender
+    /// are queued again, until a transaction with the same nonce is submitted.
+    fn drop_tx(&mut self, pooled: PooledTransaction<C, R>, reason: String) {
+        let pub_key = pooled.tx.pub_key().clone();
+        let nonce = pooled.tx.nonce();
+        self.statuses
+            .set(pooled.calculate_hash(), TxStatus::Dropped { reason });
+
+        let mempool = std::mem::take(&mut self.mempool);
+        for pooled in mempool {
+            if pooled.tx.pub_key() == &pub_key && pooled.tx.nonce() > nonce {
+                self.statuses.set(pooled.calculate_hash(), TxStatus::Queued);
+                self.queued
+                    .entry(pub_key.clone())
+                    .or_default()
+                    .insert(pooled.tx.nonce(), pooled);
+                self.queued_txs_count += 1;
+            } else {
+                self.mempool.push_back(pooled);
+            }
         }
+
+        self.nonces.set_pending_nonce(pub_key, nonce);
     }
 }
 
@@ -87,8 +212,12 @@ where
     /// The transaction is discarded if:
     /// - mempool is full
     /// - transaction is invalid (deserialization, verification or decoding of the runtime message failed)
+    /// - it was signed for another chain
+    /// - its nonce was already used by a transaction of the same sender
+    ///
+    /// Transactions with future nonces are queued, and count toward the mempool size.
     fn accept_tx(&mut self, raw: Vec<u8>) -> anyhow::Result<()> {
-        if self.mempool.len() >= self.mempool_max_txs_count {
+        if self.mempool.len() + self.queued_txs_count >= self.mempool_max_txs_count {
             bail!("Mempool is full")
         }
 
@@ -99,25 +228,25 @@ where
             )
         }
 
-        // Deserialize
-        let mut data = Cursor::new(&raw);
-        let tx = Transaction::<C>::deserialize_reader(&mut data)
-            .context("Failed to deserialize transaction")?;
-
-        // Verify
-        tx.verify().context("Failed to verify transaction")?;
+        let chain_id = (self.chain_id)(&mut WorkingSet::new(self.current_storage.clone()));
+        let pooled = PooledTransaction::try_from_raw(raw, chain_id)?;
 
-        // Decode
-        let msg = R::decode_call(tx.runtime_msg())
-            .map_err(anyhow::Error::new)
-            .context("Failed to decode message in transaction")?;
+        let nonce = pooled.tx.nonce();
+        let pending_nonce = self.nonces.pending_nonce(pooled.tx.pub_key());
+        if nonce < pending_nonce {
+            bail!(
+                "Transaction nonce too low. Expected at least {}, but found {}",
+                pending_nonce,
+                nonce
+            )
+        }
 
-        self.mempool.push_back(PooledTransaction {
-            raw,
-            tx,
-            msg: Some(msg),
-        });
-        Ok(())
+        if nonce == pending_nonce {
+            self.push_ready(pooled);
+            Ok(())
+        } else {
+            self.queue(pooled)
+        }
     }
 
     /// Builds a new batch of valid transactions in order they were added to mempool
@@ -128,14 +257,7 @@ where
         let mut current_batch_size = 0;
 
         while let Some(mut pooled) = self.mempool.pop_front() {
-            // Take the decoded runtime message cached upon accepting transaction
-            // into the pool or attempt to decode the message again if
-            // the transaction was previously executed,
-            // but discarded from the batch due to the batch size.
-            let msg = pooled.msg.take().unwrap_or_else(||
-                    // SAFETY: The transaction was accepted into the pool,
-                    // so we know that the runtime message is valid. 
-                    R::decode_call(pooled.tx.runtime_msg()).expect("noop; qed"));
+            let msg = pooled.take_msg();
 
             // Execute
             {
@@ -146,6 +268,7 @@ where
 
                 if let Err(error) = self.runtime.dispatch_call(msg, &mut working_set, &ctx) {
                     warn!(%error, tx = hex::encode(&pooled.raw), "Error during transaction dispatch");
+                    self.drop_tx(pooled, error.to_string());
                     continue;
                 }
             }
@@ -165,15 +288,41 @@ where
                 hash = hex::encode(tx_hash),
                 "Transaction has been included in the batch",
             );
+            self.statuses.set(tx_hash, TxStatus::Included);
             txs.push(pooled.raw);
         }
 
+        self.nonces.prune();
+
         if txs.is_empty() {
             bail!("No valid transactions are available");
         }
 
         Ok(txs)
     }
+
+    /// The transactions go back to the front of the mempool, in their order in the batch.
+    /// The pending nonces of their senders already follow them.
+    fn return_batch(&mut self, txs: Vec<Vec<u8>>) -> anyhow::Result<()> {
+        let chain_id = (self.chain_id)(&mut WorkingSet::new(self.current_storage.clone()));
+        for raw in txs.into_iter().rev() {
+            let pooled = PooledTransaction::try_from_raw(raw, chain_id)?;
+            self.statuses
+                .set(pooled.calculate_hash(), TxStatus::Pending);
+            self.mempool.push_front(pooled);
+        }
+        Ok(())
+    }
+
+    fn get_pending_nonce(&self, pub_key: &[u8]) -> anyhow::Result<u64> {
+        let pub_key =
+            C::PublicKey::try_from(pub_key).context("Failed to deserialize public key")?;
+        Ok(self.nonces.pending_nonce(&pub_key))
+    }
+
+    fn get_tx_status(&self, tx_hash: &[u8; 32]) -> Option<TxStatus> {
+        self.statuses.get(tx_hash)
+    }
 }
 
 #[cfg(test)]
@@ -197,6 +346,7 @@ mod tests {
     use super::*;
 
     const MAX_TX_POOL_SIZE: usize = 20;
+    const CHAIN_ID: u64 = 0;
     type C = DefaultContext;
 
     #[derive(Genesis, DispatchCall, MessageCodec, DefaultRuntime)]
@@ -209,16 +359,23 @@ mod tests {
         let private_key = DefaultPrivateKey::generate();
         let mut rng = rand::thread_rng();
         let value: u32 = rng.gen();
-        generate_valid_tx(&private_key, value)
+        generate_valid_tx(&private_key, value, 0)
     }
 
-    fn generate_valid_tx(private_key: &DefaultPrivateKey, value: u32) -> Vec<u8> {
+    fn generate_valid_tx(private_key: &DefaultPrivateKey, value: u32, nonce: u64) -> Vec<u8> {
+        generate_tx_for_chain(private_key, value, nonce, CHAIN_ID)
+    }
+
+    fn generate_tx_for_chain(
+        private_key: &DefaultPrivateKey,
+        value: u32,
+        nonce: u64,
+        chain_id: u64,
+    ) -> Vec<u8> {
         let msg = CallMessage::SetValue(value);
         let msg = <TestRuntime<C> as EncodeCall<ValueSetter<DefaultContext>>>::encode_call(msg);
-        let chain_id = 0;
         let gas_tip = 0;
         let gas_limit = 0;
-        let nonce = 1;
 
         Transaction::<DefaultContext>::new_signed_tx(
             private_key,
@@ -242,7 +399,7 @@ mod tests {
 
     fn generate_signed_tx_with_invalid_payload(private_key: &DefaultPrivateKey) -> Vec<u8> {
         let msg = generate_random_bytes();
-        let chain_id = 0;
+        let chain_id = CHAIN_ID;
         let gas_tip = 0;
         let gas_limit = 0;
         let nonce = 1;
@@ -259,6 +416,10 @@ mod tests {
         .unwrap()
     }
 
+    fn tx_hash(raw: &[u8]) -> [u8; 32] {
+        <C as Spec>::Hasher::digest(raw).into()
+    }
+
     fn create_batch_builder(
         batch_size_bytes: usize,
         tmpdir: &TempDir,
@@ -275,6 +436,7 @@ mod tests {
             TestRuntime::<C>::default(),
             storage.clone(),
             sequencer,
+            |_| CHAIN_ID,
         );
         (batch_builder, storage)
     }
@@ -376,6 +538,86 @@ mod tests {
                 .starts_with("Failed to decode message"))
         }
 
+        #[test]
+        fn reject_tx_for_another_chain() {
+            let private_key = DefaultPrivateKey::generate();
+            let tx = generate_tx_for_chain(&private_key, 1, 0, CHAIN_ID + 1);
+
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (mut batch_builder, _) = create_batch_builder(tx.len(), &tmpdir);
+
+            let accept_result = batch_builder.accept_tx(tx);
+            assert_eq!(
+                format!(
+                    "Transaction chain id {} doesn't match the rollup chain id {}",
+                    CHAIN_ID + 1,
+                    CHAIN_ID
+                ),
+                accept_result.unwrap_err().to_string()
+            );
+        }
+
+        #[test]
+        fn reject_used_nonce() {
+            let private_key = DefaultPrivateKey::generate();
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (mut batch_builder, _) = create_batch_builder(usize::MAX, &tmpdir);
+
+            batch_builder
+                .accept_tx(generate_valid_tx(&private_key, 1, 0))
+                .unwrap();
+
+            let accept_result = batch_builder.accept_tx(generate_valid_tx(&private_key, 2, 0));
+            assert_eq!(
+                "Transaction nonce too low. Expected at least 1, but found 0",
+                accept_result.unwrap_err().to_string()
+            );
+            assert_eq!(1, batch_builder.mempool.len());
+        }
+
+        #[test]
+        fn queue_future_nonces() {
+            let private_key = DefaultPrivateKey::generate();
+            let pub_key = private_key.pub_key().try_to_vec().unwrap();
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (mut batch_builder, _) = create_batch_builder(usize::MAX, &tmpdir);
+
+            let tx_2 = generate_valid_tx(&private_key, 3, 2);
+            let tx_1 = generate_valid_tx(&private_key, 2, 1);
+            batch_builder.accept_tx(tx_2.clone()).unwrap();
+            batch_builder.accept_tx(tx_1.clone()).unwrap();
+
+            let accept_result = batch_builder.accept_tx(generate_valid_tx(&private_key, 4, 2));
+            assert_eq!(
+                "Transaction with nonce 2 is already queued",
+                accept_result.unwrap_err().to_string()
+            );
+
+            assert_eq!(0, batch_builder.mempool.len());
+            assert_eq!(0, batch_builder.get_pending_nonce(&pub_key).unwrap());
+            assert_eq!(
+                Some(TxStatus::Queued),
+                batch_builder.get_tx_status(&tx_hash(&tx_2))
+            );
+
+            // The missing nonce makes the queued transactions ready
+            batch_builder
+                .accept_tx(generate_valid_tx(&private_key, 1, 0))
+                .unwrap();
+
+            assert_eq!(3, batch_builder.mempool.len());
+            assert_eq!(0, batch_builder.queued_txs_count);
+            assert_eq!(3, batch_builder.get_pending_nonce(&pub_key).unwrap());
+            assert_eq!(
+                Some(TxStatus::Pending),
+                batch_builder.get_tx_status(&tx_hash(&tx_1))
+            );
+            assert_eq!(
+                Some(TxStatus::Pending),
+                batch_builder.get_tx_status(&tx_hash(&tx_2))
+            );
+        }
+
         #[test]
         fn zero_sized_mempool_cant_accept_tx() {
             let tx = generate_random_valid_tx();
@@ -412,8 +654,8 @@ mod tests {
             let value_setter_admin = DefaultPrivateKey::generate();
             let txs = [
                 // Should be included: 113 bytes
-                generate_valid_tx(&value_setter_admin, 1),
-                generate_valid_tx(&value_setter_admin, 2),
+                generate_valid_tx(&value_setter_admin, 1, 0),
+                generate_valid_tx(&value_setter_admin, 2, 1),
             ];
 
             let tmpdir = tempfile::tempdir().unwrap();
@@ -440,13 +682,13 @@ mod tests {
             let value_setter_admin = DefaultPrivateKey::generate();
             let txs = [
                 // Should be included: 113 bytes
-                generate_valid_tx(&value_setter_admin, 1),
+                generate_valid_tx(&value_setter_admin, 1, 0),
                 // Should be rejected, not admin
                 generate_random_valid_tx(),
                 // Should be included: 113 bytes
-                generate_valid_tx(&value_setter_admin, 2),
+                generate_valid_tx(&value_setter_admin, 2, 1),
                 // Should be skipped, more than batch size
-                generate_valid_tx(&value_setter_admin, 3),
+                generate_valid_tx(&value_setter_admin, 3, 2),
             ];
 
             let tmpdir = tempfile::tempdir().unwrap();
@@ -468,6 +710,83 @@ mod tests {
             assert!(blob.contains(&txs[2]));
             assert!(!blob.contains(&txs[3]));
             assert_eq!(1, batch_builder.mempool.len());
+
+            assert_eq!(
+                Some(TxStatus::Included),
+                batch_builder.get_tx_status(&tx_hash(&txs[0]))
+            );
+            assert!(matches!(
+                batch_builder.get_tx_status(&tx_hash(&txs[1])),
+                Some(TxStatus::Dropped { .. })
+            ));
+            assert_eq!(
+                Some(TxStatus::Pending),
+                batch_builder.get_tx_status(&tx_hash(&txs[3]))
+            );
+        }
+
+        #[test]
+        fn dropped_tx_queues_following_txs_of_sender() {
+            let value_setter_admin = DefaultPrivateKey::generate();
+            let not_admin = DefaultPrivateKey::generate();
+            let txs = [
+                // Should be rejected, not admin
+                generate_valid_tx(&not_admin, 1, 0),
+                generate_valid_tx(&not_admin, 2, 1),
+                generate_valid_tx(&value_setter_admin, 3, 0),
+            ];
+
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (mut batch_builder, storage) = create_batch_builder(usize::MAX, &tmpdir);
+            setup_runtime(storage, Some(value_setter_admin.pub_key()));
+
+            for tx in &txs {
+                batch_builder.accept_tx(tx.clone()).unwrap();
+            }
+
+            let blob = batch_builder.get_next_blob().unwrap();
+            assert_eq!(vec![txs[2].clone()], blob);
+
+            // The nonce of the dropped transaction can be used again
+            let not_admin_pub_key = not_admin.pub_key().try_to_vec().unwrap();
+            assert_eq!(
+                0,
+                batch_builder.get_pending_nonce(&not_admin_pub_key).unwrap()
+            );
+            assert_eq!(
+                Some(TxStatus::Queued),
+                batch_builder.get_tx_status(&tx_hash(&txs[1]))
+            );
+            assert_eq!(0, batch_builder.mempool.len());
+            assert_eq!(1, batch_builder.queued_txs_count);
+        }
+
+        #[test]
+        fn returned_batch_is_built_again() {
+            let value_setter_admin = DefaultPrivateKey::generate();
+            let pub_key = value_setter_admin.pub_key().try_to_vec().unwrap();
+            let txs = [
+                generate_valid_tx(&value_setter_admin, 1, 0),
+                generate_valid_tx(&value_setter_admin, 2, 1),
+            ];
+
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (mut batch_builder, storage) = create_batch_builder(usize::MAX, &tmpdir);
+            setup_runtime(storage, Some(value_setter_admin.pub_key()));
+
+            batch_builder.accept_tx(txs[0].clone()).unwrap();
+            let blob = batch_builder.get_next_blob().unwrap();
+            batch_builder.accept_tx(txs[1].clone()).unwrap();
+
+            batch_builder.return_batch(blob).unwrap();
+            assert_eq!(
+                Some(TxStatus::Pending),
+                batch_builder.get_tx_status(&tx_hash(&txs[0]))
+            );
+            assert_eq!(2, batch_builder.get_pending_nonce(&pub_key).unwrap());
+
+            let blob = batch_builder.get_next_blob().unwrap();
+            assert_eq!(txs.to_vec(), blob);
         }
     }
 }
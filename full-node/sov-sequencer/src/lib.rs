@@ -1,33 +1,72 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
-use std::sync::Mutex;
+use std::sync::{Mutex, MutexGuard};
 
 /// Concrete implementations of `[BatchBuilder]`
 pub mod batch_builder;
+mod nonces;
+/// `[BatchBuilder]` ordering transactions by `gas_tip`
+pub mod priority_batch_builder;
 /// Utilities for the sequencer rpc
 pub mod utils;
 
 use anyhow::anyhow;
 use jsonrpsee::types::ErrorObjectOwned;
-use jsonrpsee::RpcModule;
+use jsonrpsee::{RpcModule, SubscriptionMessage};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 use sov_modules_api::utils::to_jsonrpsee_error_object;
-use sov_rollup_interface::services::batch_builder::BatchBuilder;
+use sov_rollup_interface::chunks::split_batch;
+use sov_rollup_interface::rpc::{LedgerRpcProvider, LedgerTxStatus};
+use sov_rollup_interface::services::batch_builder::{BatchBuilder, TxStatus};
 use sov_rollup_interface::services::da::DaService;
+use tokio::sync::broadcast;
 
 const SEQUENCER_RPC_ERROR: &str = "SEQUENCER_RPC_ERROR";
 
+/// How many times the submission of a chunk is attempted before the batch is given up.
+const MAX_CHUNK_SUBMISSION_ATTEMPTS: usize = 3;
+
+/// The chunks of a batch which were not all sent to the DA layer.
+struct PendingChunks {
+    blobs: Vec<Vec<u8>>,
+    /// Index of the first chunk which wasn't sent.
+    next: usize,
+}
+
 /// Single data structure that manages mempool and batch producing.
-pub struct Sequencer<B: BatchBuilder, T: DaService> {
+/// The statuses of the transactions are tracked from the mempool to the ledger `L`.
+pub struct Sequencer<B: BatchBuilder, T: DaService, L> {
     batch_builder: Mutex<B>,
     da_service: T,
+    ledger: L,
+    max_blob_size_bytes: Option<usize>,
+    pending_chunks: Mutex<Option<PendingChunks>>,
+    mempool_updates: broadcast::Sender<()>,
 }
 
-impl<B: BatchBuilder + Send + Sync, T: DaService + Send + Sync> Sequencer<B, T> {
-    /// Creates new Sequencer from BatchBuilder and DaService
-    pub fn new(batch_builder: B, da_service: T) -> Self {
+impl<B, T, L> Sequencer<B, T, L>
+where
+    B: BatchBuilder + Send + Sync,
+    T: DaService + Send + Sync,
+    L: LedgerRpcProvider,
+{
+    /// Creates new Sequencer from BatchBuilder, DaService and the ledger of the rollup.
+    /// Batches larger than `max_blob_size_bytes` are split into several chunk blobs.
+    pub fn new(
+        batch_builder: B,
+        da_service: T,
+        ledger: L,
+        max_blob_size_bytes: Option<usize>,
+    ) -> Self {
         Self {
             batch_builder: Mutex::new(batch_builder),
             da_service,
+            ledger,
+            max_blob_size_bytes,
+            pending_chunks: Mutex::new(None),
+            mempool_updates: broadcast::channel(10).0,
         }
     }
 
@@ -37,39 +76,149 @@ impl<B: BatchBuilder + Send + Sync, T: DaService + Send + Sync> Sequencer<B, T>
         // It can be improved with atomics,
         // so a new batch is only created after previous was submitted.
         tracing::info!("Submit batch request has been received!");
-        let blob = {
-            let mut batch_builder = self
-                .batch_builder
-                .lock()
-                .map_err(|e| anyhow!("failed to lock mempool: {}", e.to_string()))?;
-            batch_builder.get_next_blob()?
+
+        // The rollup slashes the sequencer if the chunks of a batch are not all received in time,
+        // so the chunks left over by a failed submission are sent before any new batch.
+        let pending_chunks = self.lock_pending_chunks()?.take();
+        if let Some(PendingChunks { blobs, next }) = pending_chunks {
+            tracing::info!(
+                "Resuming the submission of a batch from chunk {} of {}",
+                next + 1,
+                blobs.len()
+            );
+            if let Err((next, e)) = self.send_blobs(&blobs, next).await {
+                *self.lock_pending_chunks()? = Some(PendingChunks { blobs, next });
+                return Err(e);
+            }
+        }
+
+        let txs = {
+            let mut batch_builder = self.lock_batch_builder()?;
+            let txs = batch_builder.get_next_blob();
+            let _ = self.mempool_updates.send(());
+            txs?
+        };
+        let num_txs = txs.len();
+        let blob: Vec<u8> = borsh::to_vec(&txs)?;
+        let blobs = match self.max_blob_size_bytes {
+            Some(max_blob_size_bytes) => {
+                let batch_id: [u8; 32] = Sha256::digest(&blob).into();
+                split_batch(&blob, batch_id, max_blob_size_bytes)
+            }
+            None => vec![blob],
         };
-        let num_txs = blob.len();
-        let blob: Vec<u8> = borsh::to_vec(&blob)?;
 
-        match self.da_service.send_transaction(&blob).await {
-            Ok(_) => Ok(num_txs),
-            Err(e) => Err(anyhow!("failed to submit batch: {:?}", e)),
+        match self.send_blobs(&blobs, 0).await {
+            Ok(()) => Ok(num_txs),
+            // Nothing reached the DA layer: the transactions go in a later batch.
+            Err((0, e)) => {
+                self.lock_batch_builder()?.return_batch(txs)?;
+                let _ = self.mempool_updates.send(());
+                Err(e)
+            }
+            // The chunks which were sent can't be taken back: the others are resent by the next
+            // submission, under the same batch id.
+            Err((next, e)) => {
+                *self.lock_pending_chunks()? = Some(PendingChunks { blobs, next });
+                Err(e)
+            }
         }
     }
 
+    /// Sends the blobs from index `next` on, in order, each of them possibly landing in a
+    /// different DA block. Returns the index of the first blob which wasn't sent on failure.
+    ///
+    /// A failed chunk is resent as it is, rather than resending the whole batch under a new id:
+    /// the rollup ignores the copies of a chunk which landed despite the failure.
+    async fn send_blobs(
+        &self,
+        blobs: &[Vec<u8>],
+        next: usize,
+    ) -> Result<(), (usize, anyhow::Error)> {
+        let attempts = if blobs.len() > 1 {
+            MAX_CHUNK_SUBMISSION_ATTEMPTS
+        } else {
+            1
+        };
+        let num_blobs = blobs.len();
+        for (index, blob) in blobs.iter().enumerate().skip(next) {
+            let mut attempt = 1;
+            while let Err(e) = self.da_service.send_transaction(blob).await {
+                if attempt == attempts {
+                    return Err((
+                        index,
+                        anyhow!(
+                            "failed to submit batch: blob {} of {} was not sent: {:?}",
+                            index + 1,
+                            num_blobs,
+                            e
+                        ),
+                    ));
+                }
+                tracing::warn!(
+                    "Failed to send chunk {} of {}, retrying: {:?}",
+                    index + 1,
+                    num_blobs,
+                    e
+                );
+                attempt += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn lock_batch_builder(&self) -> anyhow::Result<MutexGuard<'_, B>> {
+        self.batch_builder
+            .lock()
+            .map_err(|e| anyhow!("failed to lock mempool: {}", e.to_string()))
+    }
+
+    fn lock_pending_chunks(&self) -> anyhow::Result<MutexGuard<'_, Option<PendingChunks>>> {
+        self.pending_chunks
+            .lock()
+            .map_err(|e| anyhow!("failed to lock pending chunks: {}", e.to_string()))
+    }
+
     fn accept_tx(&self, tx: Vec<u8>) -> anyhow::Result<()> {
         tracing::info!("Accepting tx: 0x{}", hex::encode(&tx));
-        let mut batch_builder = self
-            .batch_builder
-            .lock()
-            .map_err(|e| anyhow!("failed to lock mempool: {}", e.to_string()))?;
+        let mut batch_builder = self.lock_batch_builder()?;
         batch_builder.accept_tx(tx)?;
+        let _ = self.mempool_updates.send(());
         Ok(())
     }
+
+    fn get_pending_nonce(&self, pub_key: &[u8]) -> anyhow::Result<u64> {
+        let batch_builder = self.lock_batch_builder()?;
+        batch_builder.get_pending_nonce(pub_key)
+    }
+
+    fn get_tx_status(&self, tx_hash: &[u8; 32]) -> anyhow::Result<Option<TxStatus>> {
+        let batch_builder = self.lock_batch_builder()?;
+        Ok(batch_builder.get_tx_status(tx_hash))
+    }
+
+    /// The status of the transaction, from the ledger once it has been executed, or from the mempool.
+    /// A transaction whose slot was dropped by a reorganization is reported as dropped by the ledger.
+    fn get_transaction_status<R: DeserializeOwned>(
+        &self,
+        tx_hash: &[u8; 32],
+    ) -> anyhow::Result<Option<TransactionStatus<R>>> {
+        // The mempool keeps reporting the executed transactions as included.
+        if let Some(status) = self.ledger.get_tx_status(tx_hash)? {
+            return Ok(Some(status.into()));
+        }
+        Ok(self.get_tx_status(tx_hash)?.map(Into::into))
+    }
 }
 
-fn register_txs_rpc_methods<B, D>(
-    rpc: &mut RpcModule<Sequencer<B, D>>,
+fn register_txs_rpc_methods<B, D, L, R>(
+    rpc: &mut RpcModule<Sequencer<B, D, L>>,
 ) -> Result<(), jsonrpsee::core::Error>
 where
     B: BatchBuilder + Send + Sync + 'static,
     D: DaService,
+    L: LedgerRpcProvider + Send + Sync + 'static,
+    R: Serialize + DeserializeOwned + PartialEq + Send + 'static,
 {
     rpc.register_async_method(
         "sequencer_publishBatch",
@@ -96,19 +245,96 @@ where
         };
         Ok::<_, ErrorObjectOwned>(response)
     })?;
+    rpc.register_method("sequencer_getPendingNonce", move |params, sequencer| {
+        let pub_key: Vec<u8> = params.one()?;
+        sequencer
+            .get_pending_nonce(&pub_key)
+            .map_err(|e| to_jsonrpsee_error_object(e, SEQUENCER_RPC_ERROR))
+    })?;
+    rpc.register_method("sequencer_txStatus", move |params, sequencer| {
+        let tx_hash: [u8; 32] = params.one()?;
+        sequencer
+            .get_tx_status(&tx_hash)
+            .map_err(|e| to_jsonrpsee_error_object(e, SEQUENCER_RPC_ERROR))
+    })?;
+    rpc.register_method("tx_getStatus", move |params, sequencer| {
+        let tx_hash: [u8; 32] = params.one()?;
+        sequencer
+            .get_transaction_status::<R>(&tx_hash)
+            .map_err(|e| to_jsonrpsee_error_object(e, SEQUENCER_RPC_ERROR))
+    })?;
+    rpc.register_subscription(
+        "tx_subscribeStatus",
+        "tx_statusChanged",
+        "tx_unsubscribeStatus",
+        |params, pending_subscription, sequencer| async move {
+            let tx_hash: [u8; 32] = params.one()?;
+            // Register for the updates before reading the current status, so that no change is missed
+            let mut ledger_updates = sequencer
+                .ledger
+                .subscribe_tx_statuses()
+                .map_err(|e| to_jsonrpsee_error_object(e, SEQUENCER_RPC_ERROR))?;
+            let mut mempool_updates = sequencer.mempool_updates.subscribe();
+
+            let subscription = pending_subscription.accept().await?;
+
+            // The status is checked again after each update of the ledger or the mempool,
+            // and sent if it changed. The subscription ends once the transaction is finalized or dropped.
+            let mut status = None;
+            loop {
+                let new_status = sequencer
+                    .get_transaction_status::<R>(&tx_hash)
+                    .map_err(|e| to_jsonrpsee_error_object(e, SEQUENCER_RPC_ERROR))?;
+                if new_status.is_some() && new_status != status {
+                    let msg = SubscriptionMessage::from_json(&new_status)?;
+                    // Sending only fails if the subscriber has canceled, so we can stop sending messages
+                    if subscription.send(msg).await.is_err() {
+                        break Ok(());
+                    }
+                    status = new_status;
+                }
+                if status.as_ref().is_some_and(TransactionStatus::is_terminal) {
+                    break Ok(());
+                }
+
+                tokio::select! {
+                    _ = subscription.closed() => break Ok(()),
+                    update = ledger_updates.recv() => match update {
+                        Ok(tx_hashes) if !tx_hashes.contains(&tx_hash) => continue,
+                        Err(broadcast::error::RecvError::Closed) => break Ok(()),
+                        _ => {}
+                    },
+                    update = mempool_updates.recv() => {
+                        if let Err(broadcast::error::RecvError::Closed) = update {
+                            break Ok(());
+                        }
+                    }
+                }
+            }
+        },
+    )?;
 
     Ok(())
 }
 
-/// Creates an RPC module with the sequencer's methods
-pub fn get_sequencer_rpc<B, D>(batch_builder: B, da_service: D) -> RpcModule<Sequencer<B, D>>
+/// Creates an RPC module with the sequencer's methods.
+/// The receipts of the executed transactions are read from `ledger` as `R`.
+pub fn get_sequencer_rpc<B, D, L, R>(
+    batch_builder: B,
+    da_service: D,
+    ledger: L,
+    max_blob_size_bytes: Option<usize>,
+) -> RpcModule<Sequencer<B, D, L>>
 where
     B: BatchBuilder + Send + Sync + 'static,
     D: DaService,
+    L: LedgerRpcProvider + Send + Sync + 'static,
+    R: Serialize + DeserializeOwned + PartialEq + Send + 'static,
 {
-    let sequencer = Sequencer::new(batch_builder, da_service);
+    let sequencer = Sequencer::new(batch_builder, da_service, ledger, max_blob_size_bytes);
     let mut rpc = RpcModule::new(sequencer);
-    register_txs_rpc_methods::<B, D>(&mut rpc).expect("Failed to register sequencer RPC methods");
+    register_txs_rpc_methods::<B, D, L, R>(&mut rpc)
+        .expect("Failed to register sequencer RPC methods");
     rpc
 }
 
@@ -134,14 +360,122 @@ pub enum SubmitTransactionResponse {
     Failed(String),
 }
 
+/// The status of a transaction, from its submission to the sequencer to the finalization
+/// of the slot it was executed in. The receipt `R` of an executed transaction tells whether it was reverted.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum TransactionStatus<R> {
+    /// The transaction is in the mempool and can be included in the next batch.
+    Pending,
+    /// The transaction is in the mempool, waiting for the transactions of its sender with lower nonces.
+    Queued,
+    /// The transaction was included in a batch sent to the DA layer, and hasn't been executed yet.
+    Included,
+    /// The transaction was dropped from the mempool, or the slot it was executed in was dropped
+    /// by a reorganization of the DA layer.
+    Dropped {
+        /// Why the transaction was dropped.
+        reason: String,
+    },
+    /// The transaction was executed in a slot which isn't finalized yet.
+    Executed {
+        /// The hash of the slot the transaction was executed in.
+        #[serde(with = "sov_rollup_interface::rpc::utils::rpc_hex")]
+        slot_hash: [u8; 32],
+        /// The receipt of the transaction.
+        receipt: R,
+    },
+    /// The transaction was executed in a finalized slot.
+    Finalized {
+        /// The number of the transaction in the ledger.
+        tx_number: u64,
+        /// The receipt of the transaction.
+        receipt: R,
+    },
+}
+
+impl<R> TransactionStatus<R> {
+    /// Whether the status is final: a finalized or dropped transaction doesn't change status anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            TransactionStatus::Finalized { .. } | TransactionStatus::Dropped { .. }
+        )
+    }
+}
+
+impl<R> From<TxStatus> for TransactionStatus<R> {
+    fn from(status: TxStatus) -> Self {
+        match status {
+            TxStatus::Pending => TransactionStatus::Pending,
+            TxStatus::Queued => TransactionStatus::Queued,
+            TxStatus::Included => TransactionStatus::Included,
+            TxStatus::Dropped { reason } => TransactionStatus::Dropped { reason },
+        }
+    }
+}
+
+impl<R> From<LedgerTxStatus<R>> for TransactionStatus<R> {
+    fn from(status: LedgerTxStatus<R>) -> Self {
+        match status {
+            LedgerTxStatus::Executed { slot_hash, receipt } => {
+                TransactionStatus::Executed { slot_hash, receipt }
+            }
+            LedgerTxStatus::Finalized { tx_number, receipt } => {
+                TransactionStatus::Finalized { tx_number, receipt }
+            }
+            LedgerTxStatus::Dropped { reason } => TransactionStatus::Dropped { reason },
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use sov_mock_da::{MockAddress, MockDaService};
+    use sov_db::ledger_db::{LedgerDB, SlotCommit};
+    use sov_mock_da::{MockAddress, MockBlock, MockDaService};
+    use sov_rollup_interface::chunks::BlobChunk;
     use sov_rollup_interface::da::BlobReaderTrait;
+    use sov_rollup_interface::services::da::SlotData;
+    use sov_rollup_interface::stf::{BatchReceipt, TransactionReceipt};
 
     use super::*;
 
+    type TestSequencer = Sequencer<MockBatchBuilder, MockDaService, LedgerDB>;
+
+    /// Creates the sequencer RPC on top of an empty ledger, stored in the returned directory.
+    fn get_test_rpc(
+        batch_builder: MockBatchBuilder,
+        da_service: MockDaService,
+        max_blob_size_bytes: Option<usize>,
+    ) -> (RpcModule<TestSequencer>, LedgerDB, tempfile::TempDir) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let ledger_db = LedgerDB::with_path(temp_dir.path()).unwrap();
+        let rpc = get_sequencer_rpc::<_, _, _, u32>(
+            batch_builder,
+            da_service,
+            ledger_db.clone(),
+            max_blob_size_bytes,
+        );
+        (rpc, ledger_db, temp_dir)
+    }
+
+    /// A slot with a single transaction, whose hash is `[1; 32]` and receipt is `7`.
+    fn slot_with_tx() -> SlotCommit<MockBlock, (), u32> {
+        let mut slot = SlotCommit::new(MockBlock::default());
+        slot.add_batch(BatchReceipt {
+            batch_hash: [0; 32],
+            tx_receipts: vec![TransactionReceipt {
+                tx_hash: [1; 32],
+                body_to_save: None,
+                events: vec![],
+                receipt: 7,
+                gas_used: vec![],
+            }],
+            inner: (),
+        });
+        slot
+    }
+
     /// BatchBuilder used in tests.
     pub struct MockBatchBuilder {
         /// Mempool with transactions.
@@ -178,7 +512,7 @@ mod tests {
     async fn test_submit_on_empty_mempool() {
         let batch_builder = MockBatchBuilder { mempool: vec![] };
         let da_service = MockDaService::new(MockAddress::default());
-        let rpc = get_sequencer_rpc(batch_builder, da_service.clone());
+        let (rpc, _ledger_db, _temp_dir) = get_test_rpc(batch_builder, da_service.clone(), None);
 
         let arg: &[u8] = &[];
         let result: Result<String, jsonrpsee::core::Error> =
@@ -200,7 +534,7 @@ mod tests {
             mempool: vec![tx1.clone(), tx2.clone()],
         };
         let da_service = MockDaService::new(MockAddress::default());
-        let rpc = get_sequencer_rpc(batch_builder, da_service.clone());
+        let (rpc, _ledger_db, _temp_dir) = get_test_rpc(batch_builder, da_service.clone(), None);
 
         let arg: &[u8] = &[];
         let _: String = rpc.call("sequencer_publishBatch", arg).await.unwrap();
@@ -214,12 +548,143 @@ mod tests {
         assert_eq!(expected, block_data);
     }
 
+    #[tokio::test]
+    async fn test_submit_batch_split_into_chunks() {
+        let txs: Vec<Vec<u8>> = (0..100).map(|i| vec![i]).collect();
+        let batch_builder = MockBatchBuilder {
+            mempool: txs.clone(),
+        };
+        let da_service = MockDaService::new(MockAddress::default());
+        let (rpc, _ledger_db, _temp_dir) =
+            get_test_rpc(batch_builder, da_service.clone(), Some(128));
+
+        let arg: &[u8] = &[];
+        let _: String = rpc.call("sequencer_publishBatch", arg).await.unwrap();
+
+        // The batch is 4 bytes of length and 100 txs of 5 bytes: it is sent in 7 chunks
+        // carrying 76 bytes at most.
+        let expected: Vec<u8> = borsh::to_vec(&txs).unwrap();
+        let mut reassembled = Vec::new();
+        for (index, height) in (1..=7).enumerate() {
+            let mut submitted_block = da_service.get_block_at(height).await.unwrap();
+            let chunk = BlobChunk::decode(submitted_block.blobs[0].full_data()).unwrap();
+            assert_eq!(index as u32, chunk.index);
+            assert_eq!(7, chunk.total);
+            reassembled.extend(chunk.data);
+        }
+        assert_eq!(expected, reassembled);
+    }
+
+    #[tokio::test]
+    async fn test_failed_chunk_is_resent() {
+        let txs: Vec<Vec<u8>> = (0..100).map(|i| vec![i]).collect();
+        let batch_builder = MockBatchBuilder {
+            mempool: txs.clone(),
+        };
+        let da_service = MockDaService::new(MockAddress::default());
+        let (rpc, _ledger_db, _temp_dir) =
+            get_test_rpc(batch_builder, da_service.clone(), Some(128));
+
+        da_service.fail_next_transactions(MAX_CHUNK_SUBMISSION_ATTEMPTS - 1);
+        let arg: &[u8] = &[];
+        let _: String = rpc.call("sequencer_publishBatch", arg).await.unwrap();
+
+        // All the chunks are sent once, under the same batch id.
+        let mut batch_ids = Vec::new();
+        for (index, height) in (1..=7).enumerate() {
+            let mut submitted_block = da_service.get_block_at(height).await.unwrap();
+            let chunk = BlobChunk::decode(submitted_block.blobs[0].full_data()).unwrap();
+            assert_eq!(index as u32, chunk.index);
+            batch_ids.push(chunk.batch_id);
+        }
+        assert!(batch_ids.iter().all(|id| id == &batch_ids[0]));
+    }
+
+    #[tokio::test]
+    async fn test_submit_batch_gives_up_on_failed_chunk() {
+        let txs: Vec<Vec<u8>> = (0..100).map(|i| vec![i]).collect();
+        let batch_builder = MockBatchBuilder {
+            mempool: txs.clone(),
+        };
+        let da_service = MockDaService::new(MockAddress::default());
+        let (rpc, _ledger_db, _temp_dir) =
+            get_test_rpc(batch_builder, da_service.clone(), Some(128));
+
+        da_service.fail_next_transactions(MAX_CHUNK_SUBMISSION_ATTEMPTS);
+        let arg: &[u8] = &[];
+        let error = rpc
+            .call::<_, String>("sequencer_publishBatch", arg)
+            .await
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("failed to submit batch: blob 1 of 7 was not sent"));
+
+        // No chunk was sent, so the transactions are back in the mempool for the next batch.
+        let result: String = rpc.call("sequencer_publishBatch", arg).await.unwrap();
+        assert_eq!("Submitted 100 transactions", result);
+        let mut reassembled = Vec::new();
+        for height in 1..=7 {
+            let mut submitted_block = da_service.get_block_at(height).await.unwrap();
+            let chunk = BlobChunk::decode(submitted_block.blobs[0].full_data()).unwrap();
+            reassembled.extend(chunk.data);
+        }
+        assert_eq!(borsh::to_vec(&txs).unwrap(), reassembled);
+    }
+
+    #[tokio::test]
+    async fn test_partially_sent_batch_is_resumed() {
+        let txs: Vec<Vec<u8>> = (0..100).map(|i| vec![i]).collect();
+        let batch_builder = MockBatchBuilder {
+            mempool: txs.clone(),
+        };
+        let da_service = MockDaService::new(MockAddress::default());
+        let (rpc, _ledger_db, _temp_dir) =
+            get_test_rpc(batch_builder, da_service.clone(), Some(128));
+
+        da_service.fail_transactions_after(2, MAX_CHUNK_SUBMISSION_ATTEMPTS);
+        let arg: &[u8] = &[];
+        let error = rpc
+            .call::<_, String>("sequencer_publishBatch", arg)
+            .await
+            .unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("failed to submit batch: blob 3 of 7 was not sent"));
+
+        // The remaining chunks are sent before the next batch.
+        let new_tx = vec![200];
+        let result: String = rpc
+            .call("sequencer_publishBatch", [new_tx.clone()])
+            .await
+            .unwrap();
+        assert_eq!("Submitted 1 transactions", result);
+
+        let mut batch_ids = Vec::new();
+        let mut reassembled = Vec::new();
+        for (index, height) in (1..=7).enumerate() {
+            let mut submitted_block = da_service.get_block_at(height).await.unwrap();
+            let chunk = BlobChunk::decode(submitted_block.blobs[0].full_data()).unwrap();
+            assert_eq!(index as u32, chunk.index);
+            batch_ids.push(chunk.batch_id);
+            reassembled.extend(chunk.data);
+        }
+        assert!(batch_ids.iter().all(|id| id == &batch_ids[0]));
+        assert_eq!(borsh::to_vec(&txs).unwrap(), reassembled);
+
+        let mut submitted_block = da_service.get_block_at(8).await.unwrap();
+        assert_eq!(
+            borsh::to_vec(&vec![new_tx]).unwrap(),
+            submitted_block.blobs[0].full_data()
+        );
+    }
+
     #[tokio::test]
     async fn test_accept_tx() {
         let batch_builder = MockBatchBuilder { mempool: vec![] };
         let da_service = MockDaService::new(MockAddress::default());
 
-        let rpc = get_sequencer_rpc(batch_builder, da_service.clone());
+        let (rpc, _ledger_db, _temp_dir) = get_test_rpc(batch_builder, da_service.clone(), None);
 
         let tx: Vec<u8> = vec![1, 2, 3, 4, 5];
         let request = SubmitTransaction { body: tx.clone() };
@@ -239,7 +704,151 @@ mod tests {
         assert_eq!(expected, block_data);
     }
 
+    #[tokio::test]
+    async fn test_pending_nonce_unsupported() {
+        let batch_builder = MockBatchBuilder { mempool: vec![] };
+        let da_service = MockDaService::new(MockAddress::default());
+        let (rpc, _ledger_db, _temp_dir) = get_test_rpc(batch_builder, da_service, None);
+
+        let pub_key: Vec<u8> = vec![1; 32];
+        let result: Result<u64, jsonrpsee::core::Error> =
+            rpc.call("sequencer_getPendingNonce", [pub_key]).await;
+        assert!(result.is_err());
+
+        let status: Option<TxStatus> = rpc.call("sequencer_txStatus", [[0u8; 32]]).await.unwrap();
+        assert_eq!(None, status);
+    }
+
     #[tokio::test]
     #[ignore = "TBD"]
     async fn test_full_flow() {}
+
+    #[tokio::test]
+    async fn test_tx_status_from_ledger() {
+        let batch_builder = MockBatchBuilder { mempool: vec![] };
+        let da_service = MockDaService::new(MockAddress::default());
+        let (rpc, ledger_db, _temp_dir) = get_test_rpc(batch_builder, da_service, None);
+
+        let status: Option<TransactionStatus<u32>> =
+            rpc.call("tx_getStatus", [[1u8; 32]]).await.unwrap();
+        assert_eq!(None, status);
+
+        let slot = slot_with_tx();
+        let slot_hash = slot.slot_data().hash();
+        ledger_db.add_unfinalized_slot(&slot).unwrap();
+        let status: Option<TransactionStatus<u32>> =
+            rpc.call("tx_getStatus", [[1u8; 32]]).await.unwrap();
+        assert_eq!(
+            Some(TransactionStatus::Executed {
+                slot_hash,
+                receipt: 7
+            }),
+            status
+        );
+
+        ledger_db.commit_slot(slot).unwrap();
+        let status: Option<TransactionStatus<u32>> =
+            rpc.call("tx_getStatus", [[1u8; 32]]).await.unwrap();
+        assert_eq!(
+            Some(TransactionStatus::Finalized {
+                tx_number: 1,
+                receipt: 7
+            }),
+            status
+        );
+    }
+
+    #[tokio::test]
+    async fn test_tx_status_subscription() {
+        let batch_builder = MockBatchBuilder { mempool: vec![] };
+        let da_service = MockDaService::new(MockAddress::default());
+        let (rpc, ledger_db, _temp_dir) = get_test_rpc(batch_builder, da_service, None);
+
+        let mut subscription = rpc
+            .subscribe_unbounded("tx_subscribeStatus", [[1u8; 32]])
+            .await
+            .unwrap();
+
+        let slot = slot_with_tx();
+        let slot_hash = slot.slot_data().hash();
+        ledger_db.add_unfinalized_slot(&slot).unwrap();
+        let (status, _) = subscription
+            .next::<TransactionStatus<u32>>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            TransactionStatus::Executed {
+                slot_hash,
+                receipt: 7
+            },
+            status
+        );
+
+        ledger_db.commit_slot(slot).unwrap();
+        let (status, _) = subscription
+            .next::<TransactionStatus<u32>>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            TransactionStatus::Finalized {
+                tx_number: 1,
+                receipt: 7
+            },
+            status
+        );
+
+        // The subscription ends with the final status
+        let next = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            subscription.next::<TransactionStatus<u32>>(),
+        )
+        .await
+        .expect("The subscription should end once the transaction is finalized");
+        assert!(next.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tx_status_after_reorg() {
+        let batch_builder = MockBatchBuilder { mempool: vec![] };
+        let da_service = MockDaService::new(MockAddress::default());
+        let (rpc, ledger_db, _temp_dir) = get_test_rpc(batch_builder, da_service, None);
+
+        let mut subscription = rpc
+            .subscribe_unbounded("tx_subscribeStatus", [[1u8; 32]])
+            .await
+            .unwrap();
+
+        let slot = slot_with_tx();
+        ledger_db.add_unfinalized_slot(&slot).unwrap();
+        let (status, _) = subscription
+            .next::<TransactionStatus<u32>>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(status, TransactionStatus::Executed { .. }));
+
+        ledger_db.remove_unfinalized_slot(&slot.slot_data().hash());
+        let dropped = TransactionStatus::Dropped {
+            reason: "reorg".to_string(),
+        };
+        let (status, _) = subscription
+            .next::<TransactionStatus<u32>>()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(dropped, status);
+        let status: Option<TransactionStatus<u32>> =
+            rpc.call("tx_getStatus", [[1u8; 32]]).await.unwrap();
+        assert_eq!(Some(dropped), status);
+
+        let next = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            subscription.next::<TransactionStatus<u32>>(),
+        )
+        .await
+        .expect("The subscription should end once the transaction is dropped");
+        assert!(next.is_none());
+    }
 }
@@ -0,0 +1,86 @@
+use std::collections::{HashMap, VecDeque};
+
+use sov_accounts::{Accounts, Response};
+use sov_modules_api::{Context, WorkingSet};
+use sov_rollup_interface::services::batch_builder::TxStatus;
+
+/// Maximum number of included or dropped transactions whose status is remembered.
+const MAX_FINALIZED_STATUSES: usize = 10_000;
+
+/// Tracks the next nonce of every account with transactions in the mempool or in built batches,
+/// on top of the nonces stored by `sov-accounts`.
+pub(crate) struct PendingNonces<C: Context> {
+    accounts: Accounts<C>,
+    current_storage: C::Storage,
+    next_nonces: HashMap<C::PublicKey, u64>,
+}
+
+impl<C: Context> PendingNonces<C> {
+    pub(crate) fn new(current_storage: C::Storage) -> Self {
+        Self {
+            accounts: Accounts::default(),
+            current_storage,
+            next_nonces: HashMap::new(),
+        }
+    }
+
+    /// The nonce of the account in the current state, `0` for accounts which don't exist yet.
+    fn state_nonce(&self, pub_key: &C::PublicKey) -> u64 {
+        let mut working_set = WorkingSet::new(self.current_storage.clone());
+        match self.accounts.get_account(pub_key.clone(), &mut working_set) {
+            Ok(Response::AccountExists { nonce, .. }) => nonce,
+            _ => 0,
+        }
+    }
+
+    /// The nonce expected for the next transaction of the account to be included.
+    pub(crate) fn pending_nonce(&self, pub_key: &C::PublicKey) -> u64 {
+        let state_nonce = self.state_nonce(pub_key);
+        self.next_nonces
+            .get(pub_key)
+            .map_or(state_nonce, |next_nonce| (*next_nonce).max(state_nonce))
+    }
+
+    pub(crate) fn set_pending_nonce(&mut self, pub_key: C::PublicKey, nonce: u64) {
+        self.next_nonces.insert(pub_key, nonce);
+    }
+
+    /// Forgets the accounts whose transactions were all applied to the current state.
+    pub(crate) fn prune(&mut self) {
+        let next_nonces = std::mem::take(&mut self.next_nonces);
+        self.next_nonces = next_nonces
+            .into_iter()
+            .filter(|(pub_key, next_nonce)| *next_nonce > self.state_nonce(pub_key))
+            .collect();
+    }
+}
+
+/// Statuses of the transactions submitted to the mempool.
+///
+/// The statuses of included and dropped transactions are only kept for
+/// the [`MAX_FINALIZED_STATUSES`] most recent ones.
+#[derive(Default)]
+pub(crate) struct TxStatuses {
+    statuses: HashMap<[u8; 32], TxStatus>,
+    finalized: VecDeque<[u8; 32]>,
+}
+
+impl TxStatuses {
+    pub(crate) fn get(&self, tx_hash: &[u8; 32]) -> Option<TxStatus> {
+        self.statuses.get(tx_hash).cloned()
+    }
+
+    pub(crate) fn set(&mut self, tx_hash: [u8; 32], status: TxStatus) {
+        let is_final = matches!(status, TxStatus::Included | TxStatus::Dropped { .. });
+        self.statuses.insert(tx_hash, status);
+
+        if is_final {
+            self.finalized.push_back(tx_hash);
+            if self.finalized.len() > MAX_FINALIZED_STATUSES {
+                if let Some(expired) = self.finalized.pop_front() {
+                    self.statuses.remove(&expired);
+                }
+            }
+        }
+    }
+}
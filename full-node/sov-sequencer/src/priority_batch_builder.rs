@@ -0,0 +1,717 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{bail, Context as ErrorContext};
+use sov_modules_api::{Context, DispatchCall, PublicKey, WorkingSet};
+use sov_rollup_interface::services::batch_builder::{BatchBuilder, TxStatus};
+use tracing::{info, warn};
+
+use crate::batch_builder::{ChainIdReader, PooledTransaction};
+use crate::nonces::{PendingNonces, TxStatuses};
+
+/// Transaction in the [`PriorityMempool`], with its arrival order.
+struct PrioritizedTransaction<C: Context, R: DispatchCall<Context = C>> {
+    pooled: PooledTransaction<C, R>,
+    /// Transactions paying the same tip are ordered by arrival.
+    arrival: u64,
+    hash: [u8; 32],
+}
+
+impl<C, R> PrioritizedTransaction<C, R>
+where
+    C: Context,
+    R: DispatchCall<Context = C>,
+{
+    /// Higher priorities are included first and evicted last.
+    fn priority(&self) -> (u64, Reverse<u64>) {
+        (self.pooled.tx.gas_tip(), Reverse(self.arrival))
+    }
+}
+
+/// Mempool ordering transactions by `gas_tip`, while keeping the transactions of every sender
+/// in nonce order.
+struct PriorityMempool<C: Context, R: DispatchCall<Context = C>> {
+    /// Pending transactions of every sender, by nonce.
+    senders: HashMap<C::Address, BTreeMap<u64, PrioritizedTransaction<C, R>>>,
+    max_txs_count: usize,
+    txs_count: usize,
+    next_arrival: u64,
+    statuses: TxStatuses,
+}
+
+impl<C, R> PriorityMempool<C, R>
+where
+    C: Context,
+    R: DispatchCall<Context = C>,
+{
+    fn new(max_txs_count: usize) -> Self {
+        Self {
+            senders: HashMap::new(),
+            max_txs_count,
+            txs_count: 0,
+            next_arrival: 0,
+            statuses: TxStatuses::default(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.txs_count
+    }
+
+    /// Adds a transaction to the mempool.
+    ///
+    /// A transaction with the same sender and nonce as a pending one replaces it if it pays a
+    /// higher tip. When the mempool is full, the lowest-paying transaction which is the last of
+    /// its sender is evicted, if the new transaction pays more.
+    fn insert(&mut self, pooled: PooledTransaction<C, R>) -> anyhow::Result<()> {
+        let sender: C::Address = pooled.tx.pub_key().to_address();
+        let nonce = pooled.tx.nonce();
+        let gas_tip = pooled.tx.gas_tip();
+
+        let replaced = self
+            .senders
+            .get(&sender)
+            .and_then(|txs| txs.get(&nonce))
+            .map(|tx| (tx.pooled.tx.gas_tip(), tx.hash));
+
+        match replaced {
+            Some((replaced_gas_tip, replaced_hash)) => {
+                if gas_tip <= replaced_gas_tip {
+                    bail!(
+                        "Replacement transaction underpriced. Gas tip must be higher than {}",
+                        replaced_gas_tip
+                    )
+                }
+                self.statuses.set(
+                    replaced_hash,
+                    TxStatus::Dropped {
+                        reason: "Replaced by a transaction with a higher gas tip".to_string(),
+                    },
+                );
+            }
+            None => {
+                if self.txs_count >= self.max_txs_count {
+                    self.evict_for(gas_tip)?;
+                }
+                self.txs_count += 1;
+            }
+        }
+
+        self.push(sender, pooled);
+        Ok(())
+    }
+
+    /// Evicts the lowest-paying transaction among the last transactions of every sender,
+    /// so that the nonce sequences of the remaining transactions have no gaps.
+    fn evict_for(&mut self, gas_tip: u64) -> anyhow::Result<()> {
+        let lowest = self
+            .senders
+            .iter()
+            .filter_map(|(sender, txs)| {
+                txs.iter()
+                    .next_back()
+                    .map(|(nonce, tx)| (tx.priority(), sender.clone(), *nonce))
+            })
+            .min_by_key(|(priority, _, _)| *priority);
+
+        match lowest {
+            Some(((lowest_gas_tip, _), sender, nonce)) if lowest_gas_tip < gas_tip => {
+                let evicted = self.remove(&sender, nonce);
+                if let Some(evicted) = evicted {
+                    let hash = evicted.calculate_hash();
+                    info!(
+                        hash = hex::encode(hash),
+                        "Transaction has been evicted from the mempool",
+                    );
+                    self.statuses.set(
+                        hash,
+                        TxStatus::Dropped {
+                            reason: "Evicted from the full mempool".to_string(),
+                        },
+                    );
+                }
+                Ok(())
+            }
+            _ => bail!("Mempool is full"),
+        }
+    }
+
+    /// Removes and returns the highest-paying transaction among the first pending transactions
+    /// of every sender, whose nonce is the next expected one.
+    ///
+    /// The next nonce of the sender of the returned transaction is incremented.
+    /// Senders missing from `next_nonces` are expected to send nonce `0`.
+    fn pop_best(
+        &mut self,
+        next_nonces: &mut HashMap<C::Address, u64>,
+    ) -> Option<PooledTransaction<C, R>> {
+        let (sender, nonce) = self
+            .senders
+            .iter()
+            .filter_map(|(sender, txs)| {
+                let next_nonce = next_nonces.get(sender).copied().unwrap_or_default();
+                txs.get(&next_nonce)
+                    .map(|tx| (tx.priority(), sender.clone(), next_nonce))
+            })
+            .max_by_key(|(priority, _, _)| *priority)
+            .map(|(_, sender, nonce)| (sender, nonce))?;
+
+        next_nonces.insert(sender.clone(), nonce + 1);
+        self.remove(&sender, nonce)
+    }
+
+    /// Puts back a transaction that was popped but could not be included in the batch.
+    fn push_back(&mut self, pooled: PooledTransaction<C, R>) {
+        let sender: C::Address = pooled.tx.pub_key().to_address();
+        self.txs_count += 1;
+        self.push(sender, pooled);
+    }
+
+    fn push(&mut self, sender: C::Address, pooled: PooledTransaction<C, R>) {
+        let nonce = pooled.tx.nonce();
+        let arrival = self.next_arrival;
+        self.next_arrival += 1;
+        self.senders.entry(sender).or_default().insert(
+            nonce,
+            PrioritizedTransaction {
+                hash: pooled.calculate_hash(),
+                pooled,
+                arrival,
+            },
+        );
+    }
+
+    fn remove(&mut self, sender: &C::Address, nonce: u64) -> Option<PooledTransaction<C, R>> {
+        let txs = self.senders.get_mut(sender)?;
+        let removed = txs.remove(&nonce)?;
+        if txs.is_empty() {
+            self.senders.remove(sender);
+        }
+        self.txs_count -= 1;
+        Some(removed.pooled)
+    }
+
+    /// Returns the nonce following the consecutive transactions of the sender, starting at `nonce`.
+    fn next_nonce_after(&self, sender: &C::Address, mut nonce: u64) -> u64 {
+        if let Some(txs) = self.senders.get(sender) {
+            while txs.contains_key(&nonce) {
+                nonce += 1;
+            }
+        }
+        nonce
+    }
+
+    fn find(&self, tx_hash: &[u8; 32]) -> Option<&PrioritizedTransaction<C, R>> {
+        self.senders
+            .values()
+            .flat_map(|txs| txs.values())
+            .find(|tx| &tx.hash == tx_hash)
+    }
+}
+
+/// BatchBuilder that creates batches of the transactions paying the highest `gas_tip` first,
+/// while keeping the transactions of every sender in nonce order.
+///
+/// Same-nonce transactions of a sender can be replaced by paying a higher tip, and the
+/// lowest-paying transactions are evicted when the mempool is full.
+/// Transactions with nonces already used are rejected, and transactions with future nonces
+/// wait for the missing ones.
+/// Only transactions that were successfully dispatched are included.
+pub struct PriorityBatchBuilder<C: Context, R: DispatchCall<Context = C>> {
+    mempool: PriorityMempool<C, R>,
+    runtime: R,
+    max_batch_size_bytes: usize,
+    current_storage: C::Storage,
+    sequencer: C::Address,
+    chain_id: ChainIdReader<C>,
+    /// Next nonces of the senders, following their transactions included in batches.
+    nonces: PendingNonces<C>,
+}
+
+impl<C, R> PriorityBatchBuilder<C, R>
+where
+    C: Context,
+    R: DispatchCall<Context = C>,
+{
+    /// BatchBuilder constructor.
+    pub fn new(
+        max_batch_size_bytes: usize,
+        mempool_max_txs_count: usize,
+        runtime: R,
+        current_storage: C::Storage,
+        sequencer: C::Address,
+        chain_id: ChainIdReader<C>,
+    ) -> Self {
+        Self {
+            mempool: PriorityMempool::new(mempool_max_txs_count),
+            max_batch_size_bytes,
+            runtime,
+            nonces: PendingNonces::new(current_storage.clone()),
+            current_storage,
+            sequencer,
+            chain_id,
+        }
+    }
+}
+
+impl<C, R> BatchBuilder for PriorityBatchBuilder<C, R>
+where
+    C: Context,
+    R: DispatchCall<Context = C>,
+{
+    /// Attempt to add transaction to the mempool.
+    ///
+    /// The transaction is discarded if:
+    /// - mempool is full and the transaction doesn't pay more than the transaction to evict
+    /// - it replaces a pending transaction without paying a higher tip
+    /// - transaction is invalid (deserialization, verification or decoding of the runtime message failed)
+    /// - it was signed for another chain
+    /// - its nonce was already used by an included transaction of the same sender
+    fn accept_tx(&mut self, raw: Vec<u8>) -> anyhow::Result<()> {
+        if raw.len() > self.max_batch_size_bytes {
+            bail!(
+                "Transaction too big. Max allowed size: {}",
+                self.max_batch_size_bytes
+            )
+        }
+
+        let chain_id = (self.chain_id)(&mut WorkingSet::new(self.current_storage.clone()));
+        let pooled = PooledTransaction::try_from_raw(raw, chain_id)?;
+
+        let nonce = pooled.tx.nonce();
+        let included_nonce = self.nonces.pending_nonce(pooled.tx.pub_key());
+        if nonce < included_nonce {
+            bail!(
+                "Transaction nonce too low. Expected at least {}, but found {}",
+                included_nonce,
+                nonce
+            )
+        }
+
+        self.mempool.insert(pooled)
+    }
+
+    /// Builds a new batch of valid transactions, highest `gas_tip` first.
+    /// Only transactions, which are dispatched successfully are included in the batch
+    fn get_next_blob(&mut self) -> anyhow::Result<Vec<Vec<u8>>> {
+        let mut working_set = WorkingSet::new(self.current_storage.clone());
+        let mut txs = Vec::new();
+        let mut current_batch_size = 0;
+
+        let mut next_nonces: HashMap<C::Address, u64> = self
+            .mempool
+            .senders
+            .iter()
+            .filter_map(|(sender, txs)| {
+                let pub_key = txs.values().next()?.pooled.tx.pub_key();
+                Some((sender.clone(), self.nonces.pending_nonce(pub_key)))
+            })
+            .collect();
+
+        while let Some(mut pooled) = self.mempool.pop_best(&mut next_nonces) {
+            let msg = pooled.take_msg();
+
+            // Execute
+            {
+                let sender_address: C::Address = pooled.tx.pub_key().to_address();
+                // FIXME! This should use the correct height
+                let ctx = C::new(sender_address.clone(), self.sequencer.clone(), 0);
+
+                if let Err(error) = self.runtime.dispatch_call(msg, &mut working_set, &ctx) {
+                    warn!(%error, tx = hex::encode(&pooled.raw), "Error during transaction dispatch");
+                    // The following transactions of the sender wait for a new transaction with this nonce.
+                    next_nonces.insert(sender_address, pooled.tx.nonce());
+                    self.mempool.statuses.set(
+                        pooled.calculate_hash(),
+                        TxStatus::Dropped {
+                            reason: error.to_string(),
+                        },
+                    );
+                    continue;
+                }
+            }
+
+            // In order to fill batch as big as possible, we only check if valid tx can fit in the batch.
+            let tx_len = pooled.raw.len();
+            if current_batch_size + tx_len > self.max_batch_size_bytes {
+                self.mempool.push_back(pooled);
+                break;
+            }
+
+            // Update size of current batch
+            current_batch_size += tx_len;
+
+            let tx_hash: [u8; 32] = pooled.calculate_hash();
+            info!(
+                hash = hex::encode(tx_hash),
+                "Transaction has been included in the batch",
+            );
+            self.mempool.statuses.set(tx_hash, TxStatus::Included);
+            self.nonces
+                .set_pending_nonce(pooled.tx.pub_key().clone(), pooled.tx.nonce() + 1);
+            txs.push(pooled.raw);
+        }
+
+        self.nonces.prune();
+
+        if txs.is_empty() {
+            bail!("No valid transactions are available");
+        }
+
+        Ok(txs)
+    }
+
+    /// The transactions go back to the mempool, and the next included nonce of every sender
+    /// goes back to its first returned transaction.
+    fn return_batch(&mut self, txs: Vec<Vec<u8>>) -> anyhow::Result<()> {
+        let chain_id = (self.chain_id)(&mut WorkingSet::new(self.current_storage.clone()));
+        for raw in txs {
+            let pooled = PooledTransaction::try_from_raw(raw, chain_id)?;
+            let pub_key = pooled.tx.pub_key().clone();
+            let nonce = pooled.tx.nonce();
+            if nonce < self.nonces.pending_nonce(&pub_key) {
+                self.nonces.set_pending_nonce(pub_key, nonce);
+            }
+            self.mempool
+                .statuses
+                .set(pooled.calculate_hash(), TxStatus::Pending);
+            self.mempool.push_back(pooled);
+        }
+        Ok(())
+    }
+
+    fn get_pending_nonce(&self, pub_key: &[u8]) -> anyhow::Result<u64> {
+        let pub_key =
+            C::PublicKey::try_from(pub_key).context("Failed to deserialize public key")?;
+        let included_nonce = self.nonces.pending_nonce(&pub_key);
+        Ok(self
+            .mempool
+            .next_nonce_after(&pub_key.to_address(), included_nonce))
+    }
+
+    fn get_tx_status(&self, tx_hash: &[u8; 32]) -> Option<TxStatus> {
+        let Some(tx) = self.mempool.find(tx_hash) else {
+            return self.mempool.statuses.get(tx_hash);
+        };
+
+        let pub_key = tx.pooled.tx.pub_key();
+        let included_nonce = self.nonces.pending_nonce(pub_key);
+        let pending_nonce = self
+            .mempool
+            .next_nonce_after(&pub_key.to_address(), included_nonce);
+        if tx.pooled.tx.nonce() < pending_nonce {
+            Some(TxStatus::Pending)
+        } else {
+            Some(TxStatus::Queued)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::BorshSerialize;
+    use sov_modules_api::default_context::DefaultContext;
+    use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
+    use sov_modules_api::macros::DefaultRuntime;
+    use sov_modules_api::transaction::Transaction;
+    use sov_modules_api::{
+        Address, Context, DispatchCall, EncodeCall, Genesis, MessageCodec, PrivateKey,
+    };
+    use sov_prover_storage_manager::{new_orphan_storage, SnapshotManager};
+    use sov_rollup_interface::services::batch_builder::BatchBuilder;
+    use sov_state::{DefaultStorageSpec, ProverStorage, Storage};
+    use sov_value_setter::{CallMessage, ValueSetter, ValueSetterConfig};
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const MAX_TX_POOL_SIZE: usize = 3;
+    const CHAIN_ID: u64 = 0;
+    type C = DefaultContext;
+
+    #[derive(Genesis, DispatchCall, MessageCodec, DefaultRuntime)]
+    #[serialization(borsh::BorshDeserialize, borsh::BorshSerialize)]
+    struct TestRuntime<T: Context> {
+        value_setter: sov_value_setter::ValueSetter<T>,
+    }
+
+    fn generate_tx(
+        private_key: &DefaultPrivateKey,
+        value: u32,
+        gas_tip: u64,
+        nonce: u64,
+    ) -> Vec<u8> {
+        generate_tx_for_chain(private_key, value, gas_tip, nonce, CHAIN_ID)
+    }
+
+    fn generate_tx_for_chain(
+        private_key: &DefaultPrivateKey,
+        value: u32,
+        gas_tip: u64,
+        nonce: u64,
+        chain_id: u64,
+    ) -> Vec<u8> {
+        let msg = CallMessage::SetValue(value);
+        let msg = <TestRuntime<C> as EncodeCall<ValueSetter<DefaultContext>>>::encode_call(msg);
+        let gas_limit = 0;
+
+        Transaction::<DefaultContext>::new_signed_tx(
+            private_key,
+            msg,
+            chain_id,
+            gas_tip,
+            gas_limit,
+            nonce,
+        )
+        .try_to_vec()
+        .unwrap()
+    }
+
+    fn create_batch_builder(
+        batch_size_bytes: usize,
+        tmpdir: &TempDir,
+    ) -> (
+        PriorityBatchBuilder<C, TestRuntime<C>>,
+        ProverStorage<DefaultStorageSpec, SnapshotManager>,
+    ) {
+        let storage = new_orphan_storage(tmpdir.path()).unwrap();
+
+        let sequencer = Address::from([0; 32]);
+        let batch_builder = PriorityBatchBuilder::new(
+            batch_size_bytes,
+            MAX_TX_POOL_SIZE,
+            TestRuntime::<C>::default(),
+            storage.clone(),
+            sequencer,
+            |_| CHAIN_ID,
+        );
+        (batch_builder, storage)
+    }
+
+    fn setup_runtime(
+        storage: ProverStorage<DefaultStorageSpec, SnapshotManager>,
+        admin: &DefaultPrivateKey,
+    ) {
+        let runtime = TestRuntime::<C>::default();
+        let mut working_set = WorkingSet::new(storage.clone());
+
+        let value_setter_config = ValueSetterConfig {
+            admin: admin.pub_key().to_address(),
+        };
+        let config = GenesisConfig::<C>::new(value_setter_config);
+        runtime.genesis(&config, &mut working_set).unwrap();
+        let (log, witness) = working_set.checkpoint().freeze();
+        storage.validate_and_commit(log, &witness).unwrap();
+    }
+
+    fn pooled(raw: Vec<u8>) -> PooledTransaction<C, TestRuntime<C>> {
+        PooledTransaction::try_from_raw(raw, CHAIN_ID).unwrap()
+    }
+
+    #[test]
+    fn pops_highest_tip_first_in_nonce_order() {
+        let alice = DefaultPrivateKey::generate();
+        let bob = DefaultPrivateKey::generate();
+        let mut mempool = PriorityMempool::<C, TestRuntime<C>>::new(10);
+
+        let alice_0 = generate_tx(&alice, 1, 1, 0);
+        let alice_1 = generate_tx(&alice, 2, 100, 1);
+        let bob_0 = generate_tx(&bob, 3, 10, 0);
+        let bob_1 = generate_tx(&bob, 4, 10, 1);
+
+        // Inserted out of nonce order
+        for tx in [&alice_1, &bob_0, &alice_0, &bob_1] {
+            mempool.insert(pooled(tx.clone())).unwrap();
+        }
+        assert_eq!(4, mempool.len());
+
+        let mut next_nonces = HashMap::new();
+        let popped: Vec<_> = std::iter::from_fn(|| mempool.pop_best(&mut next_nonces))
+            .map(|pooled| pooled.raw)
+            .collect();
+        // Alice's high tip transaction can only be included after her first transaction
+        assert_eq!(vec![bob_0, bob_1, alice_0, alice_1], popped);
+        assert_eq!(0, mempool.len());
+    }
+
+    #[test]
+    fn replace_by_fee() {
+        let alice = DefaultPrivateKey::generate();
+        let mut mempool = PriorityMempool::<C, TestRuntime<C>>::new(10);
+
+        mempool
+            .insert(pooled(generate_tx(&alice, 1, 5, 0)))
+            .unwrap();
+
+        let result = mempool.insert(pooled(generate_tx(&alice, 2, 5, 0)));
+        assert_eq!(
+            "Replacement transaction underpriced. Gas tip must be higher than 5",
+            result.unwrap_err().to_string()
+        );
+
+        let replacement = generate_tx(&alice, 3, 6, 0);
+        mempool.insert(pooled(replacement.clone())).unwrap();
+        assert_eq!(1, mempool.len());
+        assert_eq!(
+            replacement,
+            mempool.pop_best(&mut HashMap::new()).unwrap().raw
+        );
+    }
+
+    #[test]
+    fn evicts_lowest_tip_when_full() {
+        let alice = DefaultPrivateKey::generate();
+        let bob = DefaultPrivateKey::generate();
+        let carol = DefaultPrivateKey::generate();
+        let mut mempool = PriorityMempool::<C, TestRuntime<C>>::new(MAX_TX_POOL_SIZE);
+
+        let alice_0 = generate_tx(&alice, 1, 1, 0);
+        let alice_1 = generate_tx(&alice, 2, 20, 1);
+        let bob_0 = generate_tx(&bob, 3, 10, 0);
+        for tx in [&alice_0, &alice_1, &bob_0] {
+            mempool.insert(pooled(tx.clone())).unwrap();
+        }
+
+        // Doesn't pay more than the cheapest evictable transaction: bob's
+        let result = mempool.insert(pooled(generate_tx(&carol, 4, 10, 0)));
+        assert_eq!("Mempool is full", result.unwrap_err().to_string());
+
+        // Alice's first transaction pays less, but can't be evicted before her second one
+        let carol_0 = generate_tx(&carol, 5, 11, 0);
+        mempool.insert(pooled(carol_0.clone())).unwrap();
+        assert_eq!(MAX_TX_POOL_SIZE, mempool.len());
+
+        let mut next_nonces = HashMap::new();
+        let popped: Vec<_> = std::iter::from_fn(|| mempool.pop_best(&mut next_nonces))
+            .map(|pooled| pooled.raw)
+            .collect();
+        assert_eq!(vec![carol_0, alice_0, alice_1], popped);
+    }
+
+    #[test]
+    fn builds_batch_by_nonce_and_size() {
+        let value_setter_admin = DefaultPrivateKey::generate();
+        let txs = [
+            generate_tx(&value_setter_admin, 1, 1, 0),
+            generate_tx(&value_setter_admin, 2, 100, 1),
+            // Should be rejected, not admin
+            generate_tx(&DefaultPrivateKey::generate(), 3, 1000, 0),
+        ];
+
+        let tmpdir = tempfile::tempdir().unwrap();
+        let batch_size = txs[0].len() + 1;
+        let (mut batch_builder, storage) = create_batch_builder(batch_size, &tmpdir);
+        setup_runtime(storage, &value_setter_admin);
+
+        for tx in &txs {
+            batch_builder.accept_tx(tx.clone()).unwrap();
+        }
+
+        let blob = batch_builder.get_next_blob().unwrap();
+        assert_eq!(vec![txs[0].clone()], blob);
+        // The second transaction didn't fit in the batch
+        assert_eq!(1, batch_builder.mempool.len());
+
+        let blob = batch_builder.get_next_blob().unwrap();
+        assert_eq!(vec![txs[1].clone()], blob);
+    }
+
+    #[test]
+    fn rejects_tx_for_another_chain() {
+        let alice = DefaultPrivateKey::generate();
+        let tx = generate_tx_for_chain(&alice, 1, 1, 0, CHAIN_ID + 1);
+
+        let tmpdir = tempfile::tempdir().unwrap();
+        let (mut batch_builder, _) = create_batch_builder(usize::MAX, &tmpdir);
+
+        let result = batch_builder.accept_tx(tx);
+        assert_eq!(
+            format!(
+                "Transaction chain id {} doesn't match the rollup chain id {}",
+                CHAIN_ID + 1,
+                CHAIN_ID
+            ),
+            result.unwrap_err().to_string()
+        );
+        assert_eq!(0, batch_builder.mempool.len());
+    }
+
+    #[test]
+    fn tracks_nonces_and_statuses() {
+        let value_setter_admin = DefaultPrivateKey::generate();
+        let pub_key = value_setter_admin.pub_key().try_to_vec().unwrap();
+        let tx_0 = generate_tx(&value_setter_admin, 1, 1, 0);
+        let tx_1 = generate_tx(&value_setter_admin, 2, 1, 1);
+        let tx_2 = generate_tx(&value_setter_admin, 3, 1, 2);
+        let tx_hash = |raw: &Vec<u8>| pooled(raw.clone()).calculate_hash();
+
+        let tmpdir = tempfile::tempdir().unwrap();
+        let (mut batch_builder, storage) = create_batch_builder(usize::MAX, &tmpdir);
+        setup_runtime(storage, &value_setter_admin);
+
+        batch_builder.accept_tx(tx_0.clone()).unwrap();
+        batch_builder.accept_tx(tx_2.clone()).unwrap();
+        assert_eq!(1, batch_builder.get_pending_nonce(&pub_key).unwrap());
+        assert_eq!(
+            Some(TxStatus::Pending),
+            batch_builder.get_tx_status(&tx_hash(&tx_0))
+        );
+        assert_eq!(
+            Some(TxStatus::Queued),
+            batch_builder.get_tx_status(&tx_hash(&tx_2))
+        );
+
+        // The transaction after the nonce gap waits in the mempool
+        let blob = batch_builder.get_next_blob().unwrap();
+        assert_eq!(vec![tx_0.clone()], blob);
+        assert_eq!(1, batch_builder.mempool.len());
+        assert_eq!(
+            Some(TxStatus::Included),
+            batch_builder.get_tx_status(&tx_hash(&tx_0))
+        );
+
+        let result = batch_builder.accept_tx(generate_tx(&value_setter_admin, 4, 10, 0));
+        assert_eq!(
+            "Transaction nonce too low. Expected at least 1, but found 0",
+            result.unwrap_err().to_string()
+        );
+
+        batch_builder.accept_tx(tx_1.clone()).unwrap();
+        assert_eq!(3, batch_builder.get_pending_nonce(&pub_key).unwrap());
+        assert_eq!(
+            Some(TxStatus::Pending),
+            batch_builder.get_tx_status(&tx_hash(&tx_2))
+        );
+
+        let blob = batch_builder.get_next_blob().unwrap();
+        assert_eq!(vec![tx_1, tx_2], blob);
+    }
+    #[test]
+    fn returned_batch_is_built_again() {
+        let value_setter_admin = DefaultPrivateKey::generate();
+        let pub_key = value_setter_admin.pub_key().try_to_vec().unwrap();
+        let tx_0 = generate_tx(&value_setter_admin, 1, 1, 0);
+        let tx_1 = generate_tx(&value_setter_admin, 2, 1, 1);
+        let tx_hash = |raw: &Vec<u8>| pooled(raw.clone()).calculate_hash();
+
+        let tmpdir = tempfile::tempdir().unwrap();
+        let (mut batch_builder, storage) = create_batch_builder(usize::MAX, &tmpdir);
+        setup_runtime(storage, &value_setter_admin);
+
+        batch_builder.accept_tx(tx_0.clone()).unwrap();
+        batch_builder.accept_tx(tx_1.clone()).unwrap();
+        let blob = batch_builder.get_next_blob().unwrap();
+        assert_eq!(2, batch_builder.get_pending_nonce(&pub_key).unwrap());
+
+        batch_builder.return_batch(blob).unwrap();
+        assert_eq!(2, batch_builder.mempool.len());
+        assert_eq!(
+            Some(TxStatus::Pending),
+            batch_builder.get_tx_status(&tx_hash(&tx_0))
+        );
+        assert_eq!(2, batch_builder.get_pending_nonce(&pub_key).unwrap());
+
+        let blob = batch_builder.get_next_blob().unwrap();
+        assert_eq!(vec![tx_0, tx_1], blob);
+    }
+}
@@ -0,0 +1,53 @@
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::zk::{AggregatedStateTransition, Zkvm, ZkvmGuest};
+
+/// Aggregates the proofs of consecutive blocks into a single proof.
+/// This is the program of the zkVM generating the aggregated proofs: it verifies the proofs of the
+/// blocks recursively, and commits their chained [`AggregatedStateTransition`].
+pub struct StateTransitionAggregator<Da, StateRoot, Zk>
+where
+    Da: DaSpec,
+    Zk: Zkvm,
+{
+    block_code_commitment: Zk::CodeCommitment,
+    phantom: PhantomData<(Da, StateRoot)>,
+}
+
+impl<Da, StateRoot, Zk> StateTransitionAggregator<Da, StateRoot, Zk>
+where
+    Da: DaSpec,
+    StateRoot: Serialize + DeserializeOwned + PartialEq,
+    Zk: ZkvmGuest,
+{
+    /// Create a [`StateTransitionAggregator`] of the proofs generated by the program of `block_code_commitment`.
+    pub fn new(block_code_commitment: Zk::CodeCommitment) -> Self {
+        Self {
+            block_code_commitment,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Aggregate the serialized proofs of consecutive blocks, read from the host in order.
+    pub fn run_aggregation(&self, zkvm: Zk) -> Result<(), anyhow::Error> {
+        let block_proofs: Vec<Vec<u8>> = zkvm.read_from_host();
+
+        let state_transitions = block_proofs
+            .iter()
+            .map(|proof| {
+                Zk::verify_and_extract_output::<Da, StateRoot>(proof, &self.block_code_commitment)
+                    .map_err(|e| anyhow::anyhow!("Failed to verify a block proof: {:?}", e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let out = AggregatedStateTransition::<Da, StateRoot>::aggregate::<sha2::Sha256>(
+            state_transitions,
+        )?;
+
+        zkvm.commit(&out);
+        Ok(())
+    }
+}
@@ -34,7 +34,136 @@ pub struct StorageConfig {
 #[derive(Debug, Clone, PartialEq, Deserialize, Copy)]
 pub struct ProverServiceConfig {
     /// The "distance"  measured in the number of blocks between two consecutive aggregated proofs.
+    /// `0` disables the proof aggregation. The proofs are only aggregated by a prover generating
+    /// SNARKs with an aggregator zkVM, see `ParallelProverService::new`.
     pub aggregated_proof_block_jump: u64,
+    /// Submission of the aggregated proofs to the DA layer.
+    #[serde(default)]
+    pub proof_submission: ProofSubmissionConfig,
+}
+
+/// Submission policy of the aggregated proofs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProofSubmissionPolicy {
+    /// Aggregated proofs are not posted to the DA layer.
+    Disabled,
+    /// Aggregated proofs are posted to the DA layer as soon as they are built.
+    #[default]
+    Aggregated,
+}
+
+/// Configuration of the submission of the aggregated proofs to the DA layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct ProofSubmissionConfig {
+    /// Which proofs are posted to the DA layer.
+    #[serde(default)]
+    pub policy: ProofSubmissionPolicy,
+    /// Number of times a failed submission is retried.
+    #[serde(default = "default_max_submission_retries")]
+    pub max_retries: u32,
+    /// Delay before the first retry of a failed submission, doubled after every retry.
+    #[serde(default = "default_submission_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+}
+
+impl Default for ProofSubmissionConfig {
+    fn default() -> Self {
+        Self {
+            policy: ProofSubmissionPolicy::default(),
+            max_retries: default_max_submission_retries(),
+            retry_backoff_ms: default_submission_retry_backoff_ms(),
+        }
+    }
+}
+
+const fn default_max_submission_retries() -> u32 {
+    5
+}
+
+const fn default_submission_retry_backoff_ms() -> u64 {
+    500
+}
+
+/// Transaction ordering policy of the sequencer mempool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MempoolPolicy {
+    /// Transactions are included in the order they were submitted.
+    #[default]
+    #[serde(rename = "fifo")]
+    FiFo,
+    /// Transactions paying the highest `gas_tip` are included first,
+    /// in nonce order for every sender.
+    Priority,
+}
+
+/// Sequencer configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SequencerConfig {
+    /// Transaction ordering policy of the mempool.
+    #[serde(default)]
+    pub mempool_policy: MempoolPolicy,
+    /// Maximum number of transactions in the mempool.
+    #[serde(default = "default_mempool_max_txs_count")]
+    pub mempool_max_txs_count: usize,
+    /// Maximum size of a batch in bytes.
+    #[serde(default = "default_max_batch_size_bytes")]
+    pub max_batch_size_bytes: usize,
+    /// Maximum size of a DA blob in bytes. Larger batches are split into several chunk blobs,
+    /// which are reassembled by the rollup. Batches are never split if not set.
+    #[serde(default)]
+    pub max_blob_size_bytes: Option<usize>,
+}
+
+impl Default for SequencerConfig {
+    fn default() -> Self {
+        Self {
+            mempool_policy: MempoolPolicy::default(),
+            mempool_max_txs_count: default_mempool_max_txs_count(),
+            max_batch_size_bytes: default_max_batch_size_bytes(),
+            max_blob_size_bytes: None,
+        }
+    }
+}
+
+const fn default_mempool_max_txs_count() -> usize {
+    u32::MAX as usize
+}
+
+const fn default_max_batch_size_bytes() -> usize {
+    1024 * 100
+}
+
+/// Configuration of a full node which delegates the proof generation to a prover server.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RemoteProverConfig {
+    /// URL of the prover server.
+    pub url: String,
+    /// Token sent with every request, which must match the one of the [`ProverServerConfig`].
+    pub auth_token: String,
+    /// Maximum size in bytes of the requests and of the responses, which carry the witnesses and the proofs.
+    #[serde(default = "default_max_prover_payload_size")]
+    pub max_payload_size: u32,
+}
+
+/// Configuration of a prover server, which generates the proofs of the full nodes configured
+/// with a [`RemoteProverConfig`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ProverServerConfig {
+    /// Address the server listens at.
+    pub rpc_config: RpcConfig,
+    /// Storage of the proving jobs, which must not be shared with a full node.
+    pub storage: StorageConfig,
+    /// Token the clients must send with every request.
+    pub auth_token: String,
+    /// Maximum size in bytes of the requests and of the responses, which carry the witnesses and the proofs.
+    #[serde(default = "default_max_prover_payload_size")]
+    pub max_payload_size: u32,
+}
+
+const fn default_max_prover_payload_size() -> u32 {
+    100 * 1024 * 1024
 }
 
 /// Rollup Configuration
@@ -48,6 +177,15 @@ pub struct RollupConfig<DaServiceConfig> {
     pub da: DaServiceConfig,
     /// Prover service configuration.
     pub prover_service: ProverServiceConfig,
+    /// Sequencer configuration.
+    #[serde(default)]
+    pub sequencer: SequencerConfig,
+    /// The proofs are generated by a prover server if set, instead of the node itself.
+    #[serde(default)]
+    pub remote_prover: Option<RemoteProverConfig>,
+    /// Configuration of the prover server of the rollup, if it's run.
+    #[serde(default)]
+    pub prover_server: Option<ProverServerConfig>,
 }
 
 /// Reads toml file as a specific type.
@@ -116,14 +254,150 @@ mod tests {
                 celestia_rpc_address: "http://localhost:11111/".into(),
                 max_celestia_response_body_size: 980,
                 celestia_rpc_timeout_seconds: 60,
+                min_gas_price: 1.0,
+                max_gas_price: 10.0,
+                gas_price_bump_multiplier: 1.5,
+                blocks_before_resubmission: 5,
             },
             storage: StorageConfig {
                 path: PathBuf::from("/tmp"),
             },
             prover_service: ProverServiceConfig {
                 aggregated_proof_block_jump: 22,
+                proof_submission: ProofSubmissionConfig::default(),
             },
+            sequencer: SequencerConfig::default(),
+            remote_prover: None,
+            prover_server: None,
         };
         assert_eq!(config, expected);
     }
+
+    #[test]
+    fn test_sequencer_config() {
+        let config = r#"
+            [da]
+            celestia_rpc_auth_token = "SECRET_RPC_TOKEN"
+            celestia_rpc_address = "http://localhost:11111/"
+            max_celestia_response_body_size = 980
+            [storage]
+            path = "/tmp"
+            [runner]
+            start_height = 31337
+            [runner.rpc_config]
+            bind_host = "127.0.0.1"
+            bind_port = 12345
+            [prover_service]
+            aggregated_proof_block_jump = 22
+            [sequencer]
+            mempool_policy = "priority"
+            mempool_max_txs_count = 1000
+        "#;
+
+        let config_file = create_config_from(config);
+
+        let config: RollupConfig<sov_celestia_adapter::CelestiaConfig> =
+            from_toml_path(config_file.path()).unwrap();
+        assert_eq!(
+            config.sequencer,
+            SequencerConfig {
+                mempool_policy: MempoolPolicy::Priority,
+                mempool_max_txs_count: 1000,
+                max_batch_size_bytes: 1024 * 100,
+                max_blob_size_bytes: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_proof_submission_config() {
+        let config = r#"
+            [da]
+            celestia_rpc_auth_token = "SECRET_RPC_TOKEN"
+            celestia_rpc_address = "http://localhost:11111/"
+            max_celestia_response_body_size = 980
+            [storage]
+            path = "/tmp"
+            [runner]
+            start_height = 31337
+            [runner.rpc_config]
+            bind_host = "127.0.0.1"
+            bind_port = 12345
+            [prover_service]
+            aggregated_proof_block_jump = 22
+            [prover_service.proof_submission]
+            policy = "disabled"
+            max_retries = 2
+        "#;
+
+        let config_file = create_config_from(config);
+
+        let config: RollupConfig<sov_celestia_adapter::CelestiaConfig> =
+            from_toml_path(config_file.path()).unwrap();
+        assert_eq!(
+            config.prover_service.proof_submission,
+            ProofSubmissionConfig {
+                policy: ProofSubmissionPolicy::Disabled,
+                max_retries: 2,
+                retry_backoff_ms: 500,
+            }
+        );
+    }
+
+    #[test]
+    fn test_remote_prover_config() {
+        let config = r#"
+            [da]
+            celestia_rpc_auth_token = "SECRET_RPC_TOKEN"
+            celestia_rpc_address = "http://localhost:11111/"
+            max_celestia_response_body_size = 980
+            [storage]
+            path = "/tmp"
+            [runner]
+            start_height = 31337
+            [runner.rpc_config]
+            bind_host = "127.0.0.1"
+            bind_port = 12345
+            [prover_service]
+            aggregated_proof_block_jump = 22
+            [remote_prover]
+            url = "http://localhost:12346"
+            auth_token = "SECRET_PROVER_TOKEN"
+            [prover_server]
+            auth_token = "SECRET_PROVER_TOKEN"
+            max_payload_size = 1024
+            [prover_server.rpc_config]
+            bind_host = "0.0.0.0"
+            bind_port = 12346
+            [prover_server.storage]
+            path = "/tmp/prover"
+        "#;
+
+        let config_file = create_config_from(config);
+
+        let config: RollupConfig<sov_celestia_adapter::CelestiaConfig> =
+            from_toml_path(config_file.path()).unwrap();
+        assert_eq!(
+            config.remote_prover,
+            Some(RemoteProverConfig {
+                url: "http://localhost:12346".to_string(),
+                auth_token: "SECRET_PROVER_TOKEN".to_string(),
+                max_payload_size: 100 * 1024 * 1024,
+            })
+        );
+        assert_eq!(
+            config.prover_server,
+            Some(ProverServerConfig {
+                rpc_config: RpcConfig {
+                    bind_host: "0.0.0.0".to_string(),
+                    bind_port: 12346,
+                },
+                storage: StorageConfig {
+                    path: PathBuf::from("/tmp/prover"),
+                },
+                auth_token: "SECRET_PROVER_TOKEN".to_string(),
+                max_payload_size: 1024,
+            })
+        );
+    }
 }
@@ -20,12 +20,20 @@ pub use config::RpcConfig;
 #[cfg(feature = "native")]
 pub use prover_service::*;
 #[cfg(feature = "native")]
+mod proving_queue;
+#[cfg(feature = "native")]
 mod runner;
 #[cfg(feature = "native")]
-pub use config::{from_toml_path, ProverServiceConfig, RollupConfig, RunnerConfig, StorageConfig};
+pub use config::{
+    from_toml_path, MempoolPolicy, ProofSubmissionConfig, ProofSubmissionPolicy,
+    ProverServerConfig, ProverServiceConfig, RemoteProverConfig, RollupConfig, RunnerConfig,
+    SequencerConfig, StorageConfig,
+};
 #[cfg(feature = "native")]
 pub use runner::*;
 
+/// Implements the `StateTransitionAggregator` type for aggregating the proofs of consecutive blocks
+pub mod aggregator;
 /// Implements the `StateTransitionVerifier` type for checking the validity of a state transition
 pub mod verifier;
 
@@ -1,7 +1,14 @@
 mod parallel;
+mod remote;
+mod rpc;
 use async_trait::async_trait;
 pub use parallel::ParallelProverService;
-use serde::Serialize;
+pub use remote::{
+    get_remote_prover_rpc, start_remote_prover_server, LocalOrRemoteProverService,
+    RemoteProverService,
+};
+pub use rpc::{get_prover_rpc, ProverJobStatus, ProverStatusResponse};
+use serde::{Deserialize, Serialize};
 use sov_rollup_interface::da::DaSpec;
 use sov_rollup_interface::services::da::DaService;
 use sov_rollup_interface::zk::StateTransitionData;
@@ -20,7 +27,7 @@ pub enum RollupProverConfig {
 }
 
 /// Represents the status of a witness submission.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum WitnessSubmissionStatus {
     /// The witness has been submitted to the prover.
     SubmittedForProving,
@@ -29,7 +36,7 @@ pub enum WitnessSubmissionStatus {
 }
 
 /// Represents the status of a DA proof submission.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ProofSubmissionStatus {
     /// Indicates successful submission of the proof to the DA.
     Success,
@@ -38,7 +45,7 @@ pub enum ProofSubmissionStatus {
 }
 
 /// Represents the current status of proof generation.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ProofProcessingStatus {
     /// Indicates that proof generation is currently in progress.
     ProvingInProgress,
@@ -60,7 +67,8 @@ pub enum ProverServiceError {
 /// This service is responsible for ZK proof generation.
 /// The proof generation process involves the following stages:
 ///     1. Submitting a witness using the `submit_witness` method to a prover service.
-///     2. Initiating proof generation with the `prove` method.
+///     2. Initiating proof generation with the `prove` method. If the prover is busy, the witness
+///        is kept, and the proof generation can be initiated again later.
 /// Once the proof is ready, it can be sent to the DA with `send_proof_to_da` method.
 /// Currently, the cancellation of proving jobs for submitted witnesses is not supported,
 /// but this functionality will be added in the future (#1185).
@@ -81,7 +89,7 @@ pub trait ProverService {
             Self::Witness,
             <Self::DaService as DaService>::Spec,
         >,
-    ) -> WitnessSubmissionStatus;
+    ) -> Result<WitnessSubmissionStatus, anyhow::Error>;
 
     /// Creates ZKP prove for a block corresponding to `block_header_hash`.
     async fn prove(
@@ -90,9 +98,18 @@ pub trait ProverService {
     ) -> Result<ProofProcessingStatus, ProverServiceError>;
 
     /// Sends the ZK proof to the DA.
-    /// This method is noy yet fully implemented: see #1185
+    /// Once the proofs of `aggregated_proof_block_jump` consecutive blocks have been sent, they are
+    /// aggregated by a zkVM running a [`StateTransitionAggregator`](crate::aggregator::StateTransitionAggregator),
+    /// and the aggregated proof is posted to the DA.
     async fn send_proof_to_da(
         &self,
         block_header_hash: <<Self::DaService as DaService>::Spec as DaSpec>::SlotHash,
     ) -> Result<ProofSubmissionStatus, anyhow::Error>;
+
+    /// Takes the headers of the DA blocks whose proving jobs have been recovered after a restart,
+    /// by increasing height. Their proofs are generated without calling [`ProverService::prove`],
+    /// and must be sent to the DA with [`ProverService::send_proof_to_da`].
+    async fn take_recovered_jobs(
+        &self,
+    ) -> Result<Vec<<<Self::DaService as DaService>::Spec as DaSpec>::BlockHeader>, anyhow::Error>;
 }
@@ -1,17 +1,20 @@
 mod prover;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
-use prover::Prover;
+use prover::{ProofAggregation, Prover};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use sov_db::ledger_db::LedgerDB;
+use sov_db::schema::types::StoredProofSubmission;
 use sov_rollup_interface::da::DaSpec;
 use sov_rollup_interface::services::da::DaService;
 use sov_rollup_interface::stf::StateTransitionFunction;
-use sov_rollup_interface::zk::{StateTransitionData, ZkvmHost};
+use sov_rollup_interface::zk::{Proof, StateTransitionData, ZkvmHost};
 
 use super::{ProverService, ProverServiceError};
-use crate::config::ProverServiceConfig;
+use crate::config::{ProofSubmissionConfig, ProofSubmissionPolicy, ProverServiceConfig};
 use crate::verifier::StateTransitionVerifier;
 use crate::{
     ProofGenConfig, ProofProcessingStatus, ProofSubmissionStatus, RollupProverConfig,
@@ -28,26 +31,39 @@ where
     V: StateTransitionFunction<Vm::Guest, Da::Spec> + Send + Sync,
 {
     vm: Vm,
+    aggregator_vm: Option<Vm>,
     prover_config: Arc<ProofGenConfig<V, Da, Vm>>,
 
     zk_storage: V::PreState,
     prover_state: Prover<StateRoot, Witness, Da>,
+
+    da_service: Da,
+    ledger_db: LedgerDB,
+    proof_submission: ProofSubmissionConfig,
 }
 
 impl<StateRoot, Witness, Da, Vm, V> ParallelProverService<StateRoot, Witness, Da, Vm, V>
 where
-    StateRoot: Serialize + DeserializeOwned + Clone + AsRef<[u8]> + Send + Sync + 'static,
+    StateRoot:
+        Serialize + DeserializeOwned + Clone + AsRef<[u8]> + PartialEq + Send + Sync + 'static,
     Witness: Serialize + DeserializeOwned + Send + Sync + 'static,
     Da: DaService,
-    Vm: ZkvmHost,
-    V: StateTransitionFunction<Vm::Guest, Da::Spec> + Send + Sync,
-    V::PreState: Clone + Send + Sync,
+    Vm: ZkvmHost + 'static,
+    V: StateTransitionFunction<Vm::Guest, Da::Spec> + Send + Sync + 'static,
+    V::PreState: Clone + Send + Sync + 'static,
 {
-    /// Creates a new prover.
+    /// Creates a new prover, and recovers the proving jobs persisted in `ledger_db` before a restart.
+    /// The proofs of the blocks are aggregated by `aggregator_vm`, which runs a
+    /// [`StateTransitionAggregator`](crate::aggregator::StateTransitionAggregator) of the proofs generated by `vm`.
+    /// Aggregated proofs are posted with `da_service`, and their DA heights are recorded in `ledger_db`.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         vm: Vm,
+        aggregator_vm: Option<Vm>,
         zk_stf: V,
         da_verifier: Da::Verifier,
+        da_service: Da,
+        ledger_db: LedgerDB,
         config: RollupProverConfig,
         zk_storage: V::PreState,
         num_threads: usize,
@@ -63,24 +79,42 @@ where
             RollupProverConfig::Prove => ProofGenConfig::Prover,
         };
 
+        // Only SNARKs can be verified by the aggregator.
+        let aggregated_proof_block_jump = match (&config, &aggregator_vm) {
+            (ProofGenConfig::Prover, Some(_)) => prover_service_config.aggregated_proof_block_jump,
+            _ => 0,
+        };
+
         let prover_config = Arc::new(config);
 
+        let prover_state = Prover::new(num_threads, aggregated_proof_block_jump, ledger_db.clone());
+        match prover_state.recover_jobs(prover_config.clone(), vm.clone(), zk_storage.clone()) {
+            Ok(0) => {}
+            Ok(recovered_jobs) => tracing::info!("Recovered {} proving jobs", recovered_jobs),
+            Err(e) => tracing::error!("Failed to recover the proving jobs: {:?}", e),
+        }
+
         Self {
             vm,
+            aggregator_vm,
             prover_config,
-            prover_state: Prover::new(
-                num_threads,
-                prover_service_config.aggregated_proof_block_jump,
-            ),
+            prover_state,
             zk_storage,
+            da_service,
+            ledger_db,
+            proof_submission: prover_service_config.proof_submission,
         }
     }
 
     /// Creates a new prover.
+    #[allow(clippy::too_many_arguments)]
     pub fn new_with_default_workers(
         vm: Vm,
+        aggregator_vm: Option<Vm>,
         zk_stf: V,
         da_verifier: Da::Verifier,
+        da_service: Da,
+        ledger_db: LedgerDB,
         config: RollupProverConfig,
         zk_storage: V::PreState,
         prover_service_config: ProverServiceConfig,
@@ -90,26 +124,155 @@ where
 
         Self::new(
             vm,
+            aggregator_vm,
             zk_stf,
             da_verifier,
+            da_service,
+            ledger_db,
             config,
             zk_storage,
             num_cpus - 1,
             prover_service_config,
         )
     }
+
+    /// Generates and posts the aggregated proofs to the DA layer, in order.
+    /// The aggregations which could not be posted are kept for the next submission, and persisted
+    /// with the proofs which are not aggregated yet.
+    async fn submit_aggregated_proofs(&self) -> Result<(), anyhow::Error> {
+        let aggregations = self.prover_state.take_aggregations();
+
+        if self.proof_submission.policy == ProofSubmissionPolicy::Disabled {
+            for aggregation in aggregations {
+                let state_transition = aggregation.state_transition;
+                tracing::info!(
+                    "Proofs of slots {:?} to {:?} are not aggregated: proof submission is disabled",
+                    state_transition.initial_slot_hash,
+                    state_transition.final_slot_hash
+                );
+            }
+            return self.prover_state.persist_aggregation_window();
+        }
+
+        let mut aggregations = aggregations.into_iter();
+        while let Some(mut aggregation) = aggregations.next() {
+            if let Err(e) = self.submit_aggregated_proof(&mut aggregation).await {
+                self.prover_state.restore_aggregations(
+                    std::iter::once(aggregation).chain(aggregations).collect(),
+                );
+                self.prover_state.persist_aggregation_window()?;
+                return Err(e);
+            }
+        }
+
+        self.prover_state.persist_aggregation_window()
+    }
+
+    /// Runs the aggregator over the proofs of the blocks, unless the aggregated proof was generated
+    /// before a failed submission.
+    async fn prove_aggregation(
+        &self,
+        aggregation: &mut ProofAggregation<StateRoot, Da::Spec>,
+    ) -> Result<Vec<u8>, anyhow::Error> {
+        if let Some(aggregated_proof) = &aggregation.aggregated_proof {
+            return Ok(aggregated_proof.clone());
+        }
+
+        let mut aggregator_vm = self
+            .aggregator_vm
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("The prover has no aggregator"))?;
+        aggregator_vm.add_hint(&aggregation.block_proofs);
+
+        let proof = tokio::task::spawn_blocking(move || aggregator_vm.run(true)).await??;
+        let aggregated_proof = match proof {
+            Proof::Full(aggregated_proof) => aggregated_proof,
+            Proof::PublicInput(_) => {
+                anyhow::bail!("The aggregator must generate a proof, not only its public input")
+            }
+        };
+        aggregation.aggregated_proof = Some(aggregated_proof.clone());
+        Ok(aggregated_proof)
+    }
+
+    /// Posts an aggregated proof to the DA layer, retrying with an exponential backoff,
+    /// and records the DA height it was posted at in the ledger.
+    async fn submit_aggregated_proof(
+        &self,
+        aggregation: &mut ProofAggregation<StateRoot, Da::Spec>,
+    ) -> Result<(), anyhow::Error> {
+        let aggregated_proof = self.prove_aggregation(aggregation).await.map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to aggregate the proofs of slots {:?} to {:?}: {}",
+                aggregation.state_transition.initial_slot_hash,
+                aggregation.state_transition.final_slot_hash,
+                e
+            )
+        })?;
+        let state_transition = &aggregation.state_transition;
+
+        let mut backoff = Duration::from_millis(self.proof_submission.retry_backoff_ms);
+        let mut retries = 0;
+        let da_height = loop {
+            match self
+                .da_service
+                .send_aggregated_zk_proof(&aggregated_proof)
+                .await
+            {
+                Ok(da_height) => break da_height,
+                Err(e) if retries < self.proof_submission.max_retries => {
+                    retries += 1;
+                    tracing::warn!(
+                        "Failed to post the aggregated proof of slots {:?} to {:?}: {}. Retrying in {:?} ({}/{})",
+                        state_transition.initial_slot_hash,
+                        state_transition.final_slot_hash,
+                        e,
+                        backoff,
+                        retries,
+                        self.proof_submission.max_retries
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                }
+                Err(e) => anyhow::bail!(
+                    "Failed to post the aggregated proof of slots {:?} to {:?} after {} retries: {}",
+                    state_transition.initial_slot_hash,
+                    state_transition.final_slot_hash,
+                    retries,
+                    e
+                ),
+            }
+        };
+
+        self.ledger_db.put_proof_submission(
+            &state_transition.final_slot_hash.clone().into(),
+            &StoredProofSubmission {
+                initial_slot_hash: state_transition.initial_slot_hash.clone().into(),
+                da_height,
+            },
+        )?;
+
+        tracing::info!(
+            "Aggregated proof of slots {:?} to {:?} has been posted at DA height {}",
+            state_transition.initial_slot_hash,
+            state_transition.final_slot_hash,
+            da_height
+        );
+        Ok(())
+    }
 }
 
 #[async_trait]
 impl<StateRoot, Witness, Da, Vm, V> ProverService
     for ParallelProverService<StateRoot, Witness, Da, Vm, V>
 where
-    StateRoot: Serialize + DeserializeOwned + Clone + AsRef<[u8]> + Send + Sync + 'static,
+    StateRoot:
+        Serialize + DeserializeOwned + Clone + AsRef<[u8]> + PartialEq + Send + Sync + 'static,
     Witness: Serialize + DeserializeOwned + Send + Sync + 'static,
     Da: DaService,
     Vm: ZkvmHost + 'static,
     V: StateTransitionFunction<Vm::Guest, Da::Spec> + Send + Sync + 'static,
-    V::PreState: Clone + Send + Sync,
+    V::PreState: Clone + Send + Sync + 'static,
 {
     type StateRoot = StateRoot;
 
@@ -124,7 +287,7 @@ where
             Self::Witness,
             <Self::DaService as DaService>::Spec,
         >,
-    ) -> WitnessSubmissionStatus {
+    ) -> Result<WitnessSubmissionStatus, anyhow::Error> {
         self.prover_state.submit_witness(state_transition_data)
     }
 
@@ -147,7 +310,20 @@ where
         &self,
         block_header_hash: <Da::Spec as DaSpec>::SlotHash,
     ) -> Result<ProofSubmissionStatus, anyhow::Error> {
-        self.prover_state
-            .get_proof_submission_status_and_remove_on_success(block_header_hash)
+        let status = self
+            .prover_state
+            .get_proof_submission_status_and_remove_on_success::<Vm>(block_header_hash)?;
+
+        if status == ProofSubmissionStatus::Success {
+            self.submit_aggregated_proofs().await?;
+        }
+
+        Ok(status)
+    }
+
+    async fn take_recovered_jobs(
+        &self,
+    ) -> Result<Vec<<Da::Spec as DaSpec>::BlockHeader>, anyhow::Error> {
+        Ok(self.prover_state.take_recovered_jobs())
     }
 }
@@ -4,11 +4,15 @@ use std::ops::Deref;
 use std::sync::{Arc, RwLock};
 
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use sov_db::ledger_db::LedgerDB;
+use sov_db::schema::types::{DbBytes, DbHash, StoredProverStatus};
 use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec};
 use sov_rollup_interface::services::da::DaService;
 use sov_rollup_interface::stf::StateTransitionFunction;
-use sov_rollup_interface::zk::{Proof, StateTransitionData, ZkvmHost};
+use sov_rollup_interface::zk::{
+    AggregatedStateTransition, Proof, StateTransition, StateTransitionData, ZkvmHost,
+};
 
 use super::ProverServiceError;
 use crate::{
@@ -22,9 +26,82 @@ enum ProverStatus<StateRoot, Witness, Da: DaSpec> {
     Err(anyhow::Error),
 }
 
+/// The proofs of consecutive blocks, aggregated into a single proof which is posted to the DA layer.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound = "StateRoot: Serialize + DeserializeOwned")]
+pub(crate) struct ProofAggregation<StateRoot, Da: DaSpec> {
+    /// The output of the aggregated proof: the state transitions of the blocks, chained into one.
+    pub(crate) state_transition: AggregatedStateTransition<Da, StateRoot>,
+    /// The serialized proofs of the blocks, in order.
+    pub(crate) block_proofs: Vec<Vec<u8>>,
+    /// The serialized aggregated proof, once it's generated.
+    pub(crate) aggregated_proof: Option<Vec<u8>>,
+}
+
+/// Groups the proofs of consecutive blocks into [`ProofAggregation`]s.
+#[derive(Clone)]
+struct ProofAggregator<StateRoot, Da: DaSpec> {
+    /// Number of blocks covered by an aggregated proof, `0` disables the aggregation.
+    block_jump: u64,
+    /// The proofs which are not aggregated yet, with their outputs.
+    block_proofs: Vec<(StateTransition<Da, StateRoot>, Vec<u8>)>,
+    aggregations: Vec<ProofAggregation<StateRoot, Da>>,
+}
+
+impl<StateRoot, Da> ProofAggregator<StateRoot, Da>
+where
+    StateRoot: Serialize + DeserializeOwned + PartialEq,
+    Da: DaSpec,
+{
+    fn new(block_jump: u64) -> Self {
+        Self {
+            block_jump,
+            block_proofs: Default::default(),
+            aggregations: Default::default(),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.block_jump > 0
+    }
+
+    /// Adds the proof of the next block, and groups the pending proofs into an aggregation
+    /// once they cover `block_jump` blocks.
+    fn push(
+        &mut self,
+        state_transition: StateTransition<Da, StateRoot>,
+        block_proof: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        self.block_proofs.push((state_transition, block_proof));
+        if (self.block_proofs.len() as u64) < self.block_jump {
+            return Ok(());
+        }
+
+        let (state_transitions, block_proofs): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.block_proofs).into_iter().unzip();
+        // The aggregator checks that the outputs chain as well, but proving takes a while.
+        let state_transition =
+            AggregatedStateTransition::aggregate::<sha2::Sha256>(state_transitions)?;
+        self.aggregations.push(ProofAggregation {
+            state_transition,
+            block_proofs,
+            aggregated_proof: None,
+        });
+        Ok(())
+    }
+
+    /// Serializes the proofs which are not aggregated yet and the aggregations which are not posted yet.
+    fn serialize_window(&self) -> anyhow::Result<DbBytes> {
+        let window = (&self.block_proofs, &self.aggregations);
+        Ok(bincode::serialize(&window)?.into())
+    }
+}
+
 struct ProverState<StateRoot, Witness, Da: DaSpec> {
     prover_status: HashMap<Da::SlotHash, ProverStatus<StateRoot, Witness, Da>>,
     pending_tasks_count: usize,
+    aggregator: ProofAggregator<StateRoot, Da>,
+    recovered_jobs: Vec<Da::BlockHeader>,
 }
 
 impl<StateRoot, Witness, Da: DaSpec> ProverState<StateRoot, Witness, Da> {
@@ -75,20 +152,27 @@ impl<StateRoot, Witness, Da: DaSpec> ProverState<StateRoot, Witness, Da> {
 
 // A prover that generates proofs in parallel using a thread pool. If the pool is saturated,
 // the prover will reject new jobs.
+// The jobs are persisted in the ledger until their proofs are sent to the DA, so that they can be
+// recovered after a restart.
 pub(crate) struct Prover<StateRoot, Witness, Da: DaService> {
     prover_state: Arc<RwLock<ProverState<StateRoot, Witness, Da::Spec>>>,
     num_threads: usize,
     pool: rayon::ThreadPool,
-    _aggregated_proof_block_jump: u64,
+    ledger_db: LedgerDB,
 }
 
 impl<StateRoot, Witness, Da> Prover<StateRoot, Witness, Da>
 where
     Da: DaService,
-    StateRoot: Serialize + DeserializeOwned + Clone + AsRef<[u8]> + Send + Sync + 'static,
+    StateRoot:
+        Serialize + DeserializeOwned + Clone + AsRef<[u8]> + PartialEq + Send + Sync + 'static,
     Witness: Serialize + DeserializeOwned + Send + Sync + 'static,
 {
-    pub(crate) fn new(num_threads: usize, _aggregated_proof_block_jump: u64) -> Self {
+    pub(crate) fn new(
+        num_threads: usize,
+        aggregated_proof_block_jump: u64,
+        ledger_db: LedgerDB,
+    ) -> Self {
         Self {
             num_threads,
             pool: rayon::ThreadPoolBuilder::new()
@@ -99,26 +183,130 @@ where
             prover_state: Arc::new(RwLock::new(ProverState {
                 prover_status: Default::default(),
                 pending_tasks_count: Default::default(),
+                aggregator: ProofAggregator::new(aggregated_proof_block_jump),
+                recovered_jobs: Default::default(),
             })),
-            _aggregated_proof_block_jump,
+            ledger_db,
         }
     }
 
+    /// Recovers the proving jobs persisted before a restart, and returns their number.
+    /// The interrupted and failed jobs are started over, even if the prover is busy.
+    pub(crate) fn recover_jobs<Vm, V>(
+        &self,
+        config: Arc<ProofGenConfig<V, Da, Vm>>,
+        vm: Vm,
+        zk_storage: V::PreState,
+    ) -> Result<usize, anyhow::Error>
+    where
+        Vm: ZkvmHost + 'static,
+        V: StateTransitionFunction<Vm::Guest, Da::Spec> + Send + Sync + 'static,
+        V::PreState: Clone + Send + Sync + 'static,
+    {
+        let jobs = self.ledger_db.get_prover_jobs()?;
+        let mut prover_state = self.prover_state.write().expect("Lock was poisoned");
+
+        if let Some(window) = self.ledger_db.get_prover_aggregation_window()? {
+            let (block_proofs, aggregations): (
+                Vec<(StateTransition<Da::Spec, StateRoot>, Vec<u8>)>,
+                Vec<ProofAggregation<StateRoot, Da::Spec>>,
+            ) = bincode::deserialize(window.as_ref())?;
+            prover_state.aggregator.block_proofs = block_proofs;
+            // The aggregated proofs posted right before the restart are already recorded in the ledger.
+            for aggregation in aggregations {
+                let final_slot_hash: DbHash =
+                    aggregation.state_transition.final_slot_hash.clone().into();
+                if self
+                    .ledger_db
+                    .get_proof_submission(&final_slot_hash)?
+                    .is_none()
+                {
+                    prover_state.aggregator.aggregations.push(aggregation);
+                }
+            }
+        }
+
+        for (slot_hash, status) in &jobs {
+            let witness = self
+                .ledger_db
+                .get_prover_witness(slot_hash)?
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Missing witness of the proving job of 0x{}",
+                        hex::encode(slot_hash)
+                    )
+                })?;
+            let state_transition_data: StateTransitionData<StateRoot, Witness, Da::Spec> =
+                bincode::deserialize(witness.as_ref())?;
+            let block_header_hash = state_transition_data.da_block_header.hash();
+            prover_state
+                .recovered_jobs
+                .push(state_transition_data.da_block_header.clone());
+
+            match status {
+                StoredProverStatus::WitnessSubmitted
+                | StoredProverStatus::ProvingInProgress
+                | StoredProverStatus::Failed(_) => {
+                    persist_status(
+                        &self.ledger_db,
+                        slot_hash,
+                        &StoredProverStatus::ProvingInProgress,
+                    )?;
+                    prover_state.pending_tasks_count += 1;
+                    self.spawn_proving_job(
+                        &mut prover_state,
+                        block_header_hash,
+                        state_transition_data,
+                        config.clone(),
+                        vm.clone(),
+                        zk_storage.clone(),
+                    );
+                }
+                StoredProverStatus::Proved(proof) => {
+                    let proof = bincode::deserialize(proof.as_ref())?;
+                    prover_state
+                        .prover_status
+                        .insert(block_header_hash, ProverStatus::Proved(proof));
+                }
+            }
+        }
+        prover_state
+            .recovered_jobs
+            .sort_by_key(|header| header.height());
+
+        Ok(jobs.len())
+    }
+
+    pub(crate) fn take_recovered_jobs(&self) -> Vec<<Da::Spec as DaSpec>::BlockHeader> {
+        let mut prover_state = self.prover_state.write().expect("Lock was poisoned");
+        std::mem::take(&mut prover_state.recovered_jobs)
+    }
+
     pub(crate) fn submit_witness(
         &self,
         state_transition_data: StateTransitionData<StateRoot, Witness, Da::Spec>,
-    ) -> WitnessSubmissionStatus {
+    ) -> Result<WitnessSubmissionStatus, anyhow::Error> {
         let header_hash = state_transition_data.da_block_header.hash();
-        let data = ProverStatus::WitnessSubmitted(state_transition_data);
 
         let mut prover_state = self.prover_state.write().expect("Lock was poisoned");
-        let entry = prover_state.prover_status.entry(header_hash);
+        let entry = prover_state.prover_status.entry(header_hash.clone());
 
         match entry {
-            Entry::Occupied(_) => WitnessSubmissionStatus::WitnessExist,
+            Entry::Occupied(_) => Ok(WitnessSubmissionStatus::WitnessExist),
             Entry::Vacant(v) => {
-                v.insert(data);
-                WitnessSubmissionStatus::SubmittedForProving
+                let witness = bincode::serialize(&state_transition_data)?;
+                self.ledger_db
+                    .put_prover_witness(&header_hash.clone().into(), &witness.into())
+                    .map_err(|e| {
+                        anyhow::anyhow!(
+                            "Failed to persist the witness of {:?}: {:?}",
+                            header_hash,
+                            e
+                        )
+                    })?;
+
+                v.insert(ProverStatus::WitnessSubmitted(state_transition_data));
+                Ok(WitnessSubmissionStatus::SubmittedForProving)
             }
         }
     }
@@ -127,7 +315,7 @@ where
         &self,
         block_header_hash: <Da::Spec as DaSpec>::SlotHash,
         config: Arc<ProofGenConfig<V, Da, Vm>>,
-        mut vm: Vm,
+        vm: Vm,
         zk_storage: V::PreState,
     ) -> Result<ProofProcessingStatus, ProverServiceError>
     where
@@ -135,7 +323,6 @@ where
         V: StateTransitionFunction<Vm::Guest, Da::Spec> + Send + Sync + 'static,
         V::PreState: Send + Sync + 'static,
     {
-        let prover_state_clone = self.prover_state.clone();
         let mut prover_state = self.prover_state.write().expect("Lock was poisoned");
 
         let prover_status = prover_state
@@ -148,23 +335,35 @@ where
 
                 // Initiate a new proving job only if the prover is not busy.
                 if start_prover {
-                    prover_state.set_to_proving(block_header_hash.clone());
-                    vm.add_hint(state_transition_data);
-
-                    self.pool.spawn(move || {
-                        tracing::info_span!("guest_execution").in_scope(|| {
-                            let proof = make_proof(vm, config, zk_storage);
+                    if let Err(e) = persist_status(
+                        &self.ledger_db,
+                        &block_header_hash.clone().into(),
+                        &StoredProverStatus::ProvingInProgress,
+                    ) {
+                        prover_state.dec_task_count();
+                        prover_state.prover_status.insert(
+                            block_header_hash,
+                            ProverStatus::WitnessSubmitted(state_transition_data),
+                        );
+                        return Err(e.into());
+                    }
 
-                            let mut prover_state =
-                                prover_state_clone.write().expect("Lock was poisoned");
-
-                            prover_state.set_to_proved(block_header_hash, proof);
-                            prover_state.dec_task_count();
-                        })
-                    });
+                    self.spawn_proving_job(
+                        &mut prover_state,
+                        block_header_hash,
+                        state_transition_data,
+                        config,
+                        vm,
+                        zk_storage,
+                    );
 
                     Ok(ProofProcessingStatus::ProvingInProgress)
                 } else {
+                    // The witness is kept, so that the proof generation can be started later.
+                    prover_state.prover_status.insert(
+                        block_header_hash,
+                        ProverStatus::WitnessSubmitted(state_transition_data),
+                    );
                     Ok(ProofProcessingStatus::Busy)
                 }
             }
@@ -182,7 +381,51 @@ where
         }
     }
 
-    pub(crate) fn get_proof_submission_status_and_remove_on_success(
+    fn spawn_proving_job<Vm, V>(
+        &self,
+        prover_state: &mut ProverState<StateRoot, Witness, Da::Spec>,
+        block_header_hash: <Da::Spec as DaSpec>::SlotHash,
+        state_transition_data: StateTransitionData<StateRoot, Witness, Da::Spec>,
+        config: Arc<ProofGenConfig<V, Da, Vm>>,
+        mut vm: Vm,
+        zk_storage: V::PreState,
+    ) where
+        Vm: ZkvmHost + 'static,
+        V: StateTransitionFunction<Vm::Guest, Da::Spec> + Send + Sync + 'static,
+        V::PreState: Send + Sync + 'static,
+    {
+        let prover_state_clone = self.prover_state.clone();
+        let ledger_db = self.ledger_db.clone();
+        let slot_hash: DbHash = block_header_hash.clone().into();
+
+        prover_state.set_to_proving(block_header_hash.clone());
+        vm.add_hint(state_transition_data);
+
+        self.pool.spawn(move || {
+            tracing::info_span!("guest_execution").in_scope(|| {
+                let proof = make_proof(vm, config, zk_storage);
+
+                let status = match &proof {
+                    Ok(proof) => StoredProverStatus::Proved(
+                        bincode::serialize(proof)
+                            .expect("serialization to vec is infallible")
+                            .into(),
+                    ),
+                    Err(e) => StoredProverStatus::Failed(e.to_string()),
+                };
+                // A proof which is not persisted would be lost on a restart after it's sent,
+                // so the job is failed: it's started over after a restart.
+                let proof = persist_status(&ledger_db, &slot_hash, &status).and(proof);
+
+                let mut prover_state = prover_state_clone.write().expect("Lock was poisoned");
+
+                prover_state.set_to_proved(block_header_hash, proof);
+                prover_state.dec_task_count();
+            })
+        });
+    }
+
+    pub(crate) fn get_proof_submission_status_and_remove_on_success<Vm: ZkvmHost>(
         &self,
         block_header_hash: <Da::Spec as DaSpec>::SlotHash,
     ) -> Result<ProofSubmissionStatus, anyhow::Error> {
@@ -193,7 +436,34 @@ where
             Some(ProverStatus::ProvingInProgress) => {
                 Ok(ProofSubmissionStatus::ProofGenerationInProgress)
             }
-            Some(ProverStatus::Proved(_)) => {
+            Some(ProverStatus::Proved(proof)) => {
+                let slot_hash: DbHash = block_header_hash.clone().into();
+                if prover_state.aggregator.is_enabled() {
+                    let state_transition = Vm::extract_output::<Da::Spec, StateRoot>(proof)
+                        .map_err(|e| {
+                            anyhow::anyhow!(
+                                "Failed to extract the output of the proof for {:?}: {:?}",
+                                block_header_hash,
+                                e
+                            )
+                        })?;
+                    let block_proof = match proof {
+                        Proof::Full(block_proof) => block_proof.clone(),
+                        Proof::PublicInput(_) => anyhow::bail!(
+                            "The proof for {:?} can't be aggregated: it only contains its public input",
+                            block_header_hash
+                        ),
+                    };
+                    // The proof is added to a copy of the window, which replaces the window once
+                    // it's persisted: on a failure, the job and the window are left as they were.
+                    let mut aggregator = prover_state.aggregator.clone();
+                    aggregator.push(state_transition, block_proof)?;
+                    let window = aggregator.serialize_window()?;
+                    self.ledger_db.complete_prover_job(&slot_hash, &window)?;
+                    prover_state.aggregator = aggregator;
+                } else {
+                    self.ledger_db.delete_prover_job(&slot_hash)?;
+                }
                 prover_state.remove(&block_header_hash);
                 Ok(ProofSubmissionStatus::Success)
             }
@@ -208,6 +478,44 @@ where
             )),
         }
     }
+
+    pub(crate) fn take_aggregations(&self) -> Vec<ProofAggregation<StateRoot, Da::Spec>> {
+        let mut prover_state = self.prover_state.write().expect("Lock was poisoned");
+        std::mem::take(&mut prover_state.aggregator.aggregations)
+    }
+
+    /// Puts back aggregations which were taken but could not be posted,
+    /// before the ones built in the meantime.
+    pub(crate) fn restore_aggregations(
+        &self,
+        mut aggregations: Vec<ProofAggregation<StateRoot, Da::Spec>>,
+    ) {
+        let mut prover_state = self.prover_state.write().expect("Lock was poisoned");
+        aggregations.append(&mut prover_state.aggregator.aggregations);
+        prover_state.aggregator.aggregations = aggregations;
+    }
+
+    /// Persists the proofs which are not aggregated yet and the aggregations which are not posted yet,
+    /// so that they are recovered after a restart.
+    pub(crate) fn persist_aggregation_window(&self) -> anyhow::Result<()> {
+        let prover_state = self.prover_state.read().expect("Lock was poisoned");
+        let window = prover_state.aggregator.serialize_window()?;
+        self.ledger_db.put_prover_aggregation_window(&window)
+    }
+}
+
+fn persist_status(
+    ledger_db: &LedgerDB,
+    slot_hash: &DbHash,
+    status: &StoredProverStatus,
+) -> Result<(), anyhow::Error> {
+    ledger_db.put_prover_status(slot_hash, status).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to persist the status of the proving job of 0x{}: {:?}",
+            hex::encode(slot_hash),
+            e
+        )
+    })
 }
 
 fn make_proof<V, Vm, Da>(
@@ -0,0 +1,371 @@
+use std::marker::PhantomData;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
+use jsonrpsee::rpc_params;
+use jsonrpsee::server::ServerHandle;
+use jsonrpsee::types::ErrorObjectOwned;
+use jsonrpsee::RpcModule;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sov_db::ledger_db::LedgerDB;
+use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::services::da::DaService;
+use sov_rollup_interface::zk::{Proof, StateTransitionData};
+
+use super::rpc::to_prover_rpc_error;
+use super::{
+    get_prover_rpc, ProofProcessingStatus, ProofSubmissionStatus, ProverService,
+    ProverServiceError, ProverStatusResponse, WitnessSubmissionStatus,
+};
+use crate::{ProverServerConfig, RemoteProverConfig};
+
+type SlotHash<Ps> = <<<Ps as ProverService>::DaService as DaService>::Spec as DaSpec>::SlotHash;
+
+type TransitionData<Ps> = StateTransitionData<
+    <Ps as ProverService>::StateRoot,
+    <Ps as ProverService>::Witness,
+    <<Ps as ProverService>::DaService as DaService>::Spec,
+>;
+
+/// A [`ProverService`] which delegates the proof generation to a prover server,
+/// started with [`start_remote_prover_server`].
+/// The witnesses are sent to the server serialized in the same way as they are persisted in the ledger.
+pub struct RemoteProverService<StateRoot, Witness, Da> {
+    client: HttpClient,
+    auth_token: String,
+    phantom: PhantomData<fn() -> (StateRoot, Witness, Da)>,
+}
+
+impl<StateRoot, Witness, Da: DaService> RemoteProverService<StateRoot, Witness, Da> {
+    /// Creates a client of the prover server configured in `config`.
+    pub fn new(config: &RemoteProverConfig) -> Result<Self, anyhow::Error> {
+        let client = HttpClientBuilder::default()
+            .max_request_size(config.max_payload_size)
+            .max_response_size(config.max_payload_size)
+            .build(&config.url)?;
+
+        Ok(Self {
+            client,
+            auth_token: config.auth_token.clone(),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Gets the status of the proving job of the slot `block_header_hash` from the prover server.
+    pub async fn get_status(
+        &self,
+        block_header_hash: <Da::Spec as DaSpec>::SlotHash,
+    ) -> Result<ProverStatusResponse, anyhow::Error> {
+        let slot_hash: [u8; 32] = block_header_hash.into();
+        self.request("prover_getStatus", rpc_params![slot_hash])
+            .await
+    }
+
+    /// Fetches the proof of the slot `block_header_hash` from the prover server.
+    /// The proof is available once it is generated, until it is sent to the DA layer.
+    pub async fn get_proof(
+        &self,
+        block_header_hash: <Da::Spec as DaSpec>::SlotHash,
+    ) -> Result<Option<Proof>, anyhow::Error> {
+        let slot_hash: [u8; 32] = block_header_hash.into();
+        self.request("prover_getProof", rpc_params![slot_hash])
+            .await
+    }
+
+    async fn request<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: jsonrpsee::core::params::ArrayParams,
+    ) -> Result<R, anyhow::Error> {
+        self.client
+            .request(method, params)
+            .await
+            .map_err(from_prover_rpc_error)
+    }
+}
+
+/// Extracts the reason of the failures reported by the prover server, so that the errors
+/// are the same as the ones of a local prover service.
+fn from_prover_rpc_error(e: jsonrpsee::core::Error) -> anyhow::Error {
+    if let jsonrpsee::core::Error::Call(error) = &e {
+        let reason = error
+            .data()
+            .and_then(|data| serde_json::from_str::<String>(data.get()).ok());
+        if let Some(reason) = reason {
+            return anyhow::anyhow!(reason);
+        }
+    }
+    e.into()
+}
+
+#[async_trait]
+impl<StateRoot, Witness, Da> ProverService for RemoteProverService<StateRoot, Witness, Da>
+where
+    StateRoot: Serialize + DeserializeOwned + Clone + AsRef<[u8]> + Send + Sync + 'static,
+    Witness: Serialize + DeserializeOwned + Send + Sync + 'static,
+    Da: DaService,
+{
+    type StateRoot = StateRoot;
+
+    type Witness = Witness;
+
+    type DaService = Da;
+
+    async fn submit_witness(
+        &self,
+        state_transition_data: StateTransitionData<
+            Self::StateRoot,
+            Self::Witness,
+            <Self::DaService as DaService>::Spec,
+        >,
+    ) -> Result<WitnessSubmissionStatus, anyhow::Error> {
+        let witness = bincode::serialize(&state_transition_data)?;
+        self.request(
+            "prover_submitWitness",
+            rpc_params![&self.auth_token, witness],
+        )
+        .await
+    }
+
+    async fn prove(
+        &self,
+        block_header_hash: <Da::Spec as DaSpec>::SlotHash,
+    ) -> Result<ProofProcessingStatus, ProverServiceError> {
+        Ok(self
+            .request(
+                "prover_prove",
+                rpc_params![&self.auth_token, block_header_hash],
+            )
+            .await?)
+    }
+
+    async fn send_proof_to_da(
+        &self,
+        block_header_hash: <Da::Spec as DaSpec>::SlotHash,
+    ) -> Result<ProofSubmissionStatus, anyhow::Error> {
+        self.request(
+            "prover_sendProofToDa",
+            rpc_params![&self.auth_token, block_header_hash],
+        )
+        .await
+    }
+
+    async fn take_recovered_jobs(
+        &self,
+    ) -> Result<Vec<<Da::Spec as DaSpec>::BlockHeader>, anyhow::Error> {
+        self.request("prover_takeRecoveredJobs", rpc_params![&self.auth_token])
+            .await
+    }
+}
+
+/// Reads the auth token, which is the first parameter of every method of the remote prover RPC,
+/// and checks that it matches `auth_token`.
+/// The tokens are compared in constant time, so that the response time doesn't leak the expected one.
+fn check_auth_token(
+    params: &mut jsonrpsee::types::params::ParamsSequence<'_>,
+    auth_token: &str,
+) -> Result<(), ErrorObjectOwned> {
+    let token: String = params.next()?;
+    let matches = token.len() == auth_token.len()
+        && token
+            .bytes()
+            .zip(auth_token.bytes())
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0;
+
+    if matches {
+        Ok(())
+    } else {
+        Err(to_prover_rpc_error("Invalid prover server auth token"))
+    }
+}
+
+/// Creates an RPC module which exposes `prover_service` to the [`RemoteProverService`] clients.
+/// Every request must carry `auth_token`.
+pub fn get_remote_prover_rpc<Ps>(prover_service: Ps, auth_token: String) -> RpcModule<Ps>
+where
+    Ps: ProverService + Send + Sync + 'static,
+    Ps::StateRoot: DeserializeOwned + Send,
+    Ps::Witness: DeserializeOwned + Send,
+{
+    let auth_token = Arc::new(auth_token);
+    let mut rpc = RpcModule::new(prover_service);
+
+    let token = auth_token.clone();
+    rpc.register_async_method("prover_submitWitness", move |params, prover_service| {
+        let token = token.clone();
+        async move {
+            let mut params = params.sequence();
+            check_auth_token(&mut params, &token)?;
+            let witness: Vec<u8> = params.next()?;
+            let state_transition_data: TransitionData<Ps> =
+                bincode::deserialize(&witness).map_err(to_prover_rpc_error)?;
+            prover_service
+                .submit_witness(state_transition_data)
+                .await
+                .map_err(to_prover_rpc_error)
+        }
+    })
+    .expect("Failed to register remote prover RPC methods");
+
+    let token = auth_token.clone();
+    rpc.register_async_method("prover_prove", move |params, prover_service| {
+        let token = token.clone();
+        async move {
+            let mut params = params.sequence();
+            check_auth_token(&mut params, &token)?;
+            let block_header_hash: SlotHash<Ps> = params.next()?;
+            prover_service
+                .prove(block_header_hash)
+                .await
+                .map_err(to_prover_rpc_error)
+        }
+    })
+    .expect("Failed to register remote prover RPC methods");
+
+    let token = auth_token.clone();
+    rpc.register_async_method("prover_sendProofToDa", move |params, prover_service| {
+        let token = token.clone();
+        async move {
+            let mut params = params.sequence();
+            check_auth_token(&mut params, &token)?;
+            let block_header_hash: SlotHash<Ps> = params.next()?;
+            prover_service
+                .send_proof_to_da(block_header_hash)
+                .await
+                .map_err(to_prover_rpc_error)
+        }
+    })
+    .expect("Failed to register remote prover RPC methods");
+
+    let token = auth_token;
+    rpc.register_async_method("prover_takeRecoveredJobs", move |params, prover_service| {
+        let token = token.clone();
+        async move {
+            let mut params = params.sequence();
+            check_auth_token(&mut params, &token)?;
+            prover_service
+                .take_recovered_jobs()
+                .await
+                .map_err(to_prover_rpc_error)
+        }
+    })
+    .expect("Failed to register remote prover RPC methods");
+    rpc
+}
+
+/// Starts a prover server, which generates the proofs of its [`RemoteProverService`] clients with `prover_service`.
+/// The server also exposes the statuses and the proofs of the proving jobs persisted in `ledger_db`,
+/// which should be opened at the storage path of `config`. These read-only methods don't require the auth token.
+/// Returns the address the server is bound to, and the handle to stop it.
+pub async fn start_remote_prover_server<Ps>(
+    prover_service: Ps,
+    ledger_db: LedgerDB,
+    config: &ProverServerConfig,
+) -> Result<(SocketAddr, ServerHandle), anyhow::Error>
+where
+    Ps: ProverService + Send + Sync + 'static,
+    Ps::StateRoot: DeserializeOwned + Send,
+    Ps::Witness: DeserializeOwned + Send,
+{
+    let mut methods = get_remote_prover_rpc(prover_service, config.auth_token.clone());
+    methods.merge(get_prover_rpc(ledger_db))?;
+
+    let rpc_config = &config.rpc_config;
+    let listen_address = SocketAddr::new(rpc_config.bind_host.parse()?, rpc_config.bind_port);
+    let server = jsonrpsee::server::ServerBuilder::default()
+        .max_request_body_size(config.max_payload_size)
+        .max_response_body_size(config.max_payload_size)
+        .build([listen_address].as_ref())
+        .await?;
+
+    let bound_address = server.local_addr()?;
+    tracing::info!("Starting prover server at {}", bound_address);
+
+    Ok((bound_address, server.start(methods)))
+}
+
+/// The [`ProverService`] of a full node, which generates the proofs itself or delegates them
+/// to a prover server, depending on the [`RemoteProverConfig`] of the rollup.
+pub enum LocalOrRemoteProverService<Local: ProverService> {
+    /// The proofs are generated by the node.
+    Local(Local),
+    /// The proofs are generated by a prover server.
+    Remote(RemoteProverService<Local::StateRoot, Local::Witness, Local::DaService>),
+}
+
+impl<Local: ProverService> LocalOrRemoteProverService<Local> {
+    /// Connects to the prover server of `remote_prover` if it's set,
+    /// otherwise creates the local prover service with `create_local`.
+    pub fn new(
+        remote_prover: Option<&RemoteProverConfig>,
+        create_local: impl FnOnce() -> Local,
+    ) -> Result<Self, anyhow::Error> {
+        match remote_prover {
+            Some(config) => Ok(Self::Remote(RemoteProverService::new(config)?)),
+            None => Ok(Self::Local(create_local())),
+        }
+    }
+}
+
+#[async_trait]
+impl<Local> ProverService for LocalOrRemoteProverService<Local>
+where
+    Local: ProverService + Send + Sync,
+    Local::StateRoot: DeserializeOwned + Send + Sync + 'static,
+    Local::Witness: DeserializeOwned + Send + Sync + 'static,
+{
+    type StateRoot = Local::StateRoot;
+
+    type Witness = Local::Witness;
+
+    type DaService = Local::DaService;
+
+    async fn submit_witness(
+        &self,
+        state_transition_data: StateTransitionData<
+            Self::StateRoot,
+            Self::Witness,
+            <Self::DaService as DaService>::Spec,
+        >,
+    ) -> Result<WitnessSubmissionStatus, anyhow::Error> {
+        match self {
+            Self::Local(prover) => prover.submit_witness(state_transition_data).await,
+            Self::Remote(prover) => prover.submit_witness(state_transition_data).await,
+        }
+    }
+
+    async fn prove(
+        &self,
+        block_header_hash: <<Self::DaService as DaService>::Spec as DaSpec>::SlotHash,
+    ) -> Result<ProofProcessingStatus, ProverServiceError> {
+        match self {
+            Self::Local(prover) => prover.prove(block_header_hash).await,
+            Self::Remote(prover) => prover.prove(block_header_hash).await,
+        }
+    }
+
+    async fn send_proof_to_da(
+        &self,
+        block_header_hash: <<Self::DaService as DaService>::Spec as DaSpec>::SlotHash,
+    ) -> Result<ProofSubmissionStatus, anyhow::Error> {
+        match self {
+            Self::Local(prover) => prover.send_proof_to_da(block_header_hash).await,
+            Self::Remote(prover) => prover.send_proof_to_da(block_header_hash).await,
+        }
+    }
+
+    async fn take_recovered_jobs(
+        &self,
+    ) -> Result<Vec<<<Self::DaService as DaService>::Spec as DaSpec>::BlockHeader>, anyhow::Error>
+    {
+        match self {
+            Self::Local(prover) => prover.take_recovered_jobs().await,
+            Self::Remote(prover) => prover.take_recovered_jobs().await,
+        }
+    }
+}
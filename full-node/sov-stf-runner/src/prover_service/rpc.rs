@@ -0,0 +1,95 @@
+use jsonrpsee::types::ErrorObjectOwned;
+use jsonrpsee::RpcModule;
+use serde::{Deserialize, Serialize};
+use sov_db::ledger_db::LedgerDB;
+use sov_db::schema::types::{DbHash, StoredProverStatus};
+use sov_rollup_interface::zk::Proof;
+
+const PROVER_RPC_ERROR: &str = "PROVER_RPC_ERROR";
+
+pub(super) fn to_prover_rpc_error(e: impl ToString) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(
+        jsonrpsee::types::error::UNKNOWN_ERROR_CODE,
+        PROVER_RPC_ERROR,
+        Some(e.to_string()),
+    )
+}
+
+/// The status of a proving job.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProverJobStatus {
+    /// The witness has been submitted, but the proof generation has not started.
+    WitnessSubmitted,
+    /// The proof is being generated.
+    ProvingInProgress,
+    /// The proof has been generated, and waits to be sent to the DA layer.
+    Proved,
+    /// The proof generation failed.
+    Failed {
+        /// The reason of the failure.
+        error: String,
+    },
+}
+
+impl From<StoredProverStatus> for ProverJobStatus {
+    fn from(status: StoredProverStatus) -> Self {
+        match status {
+            StoredProverStatus::WitnessSubmitted => ProverJobStatus::WitnessSubmitted,
+            StoredProverStatus::ProvingInProgress => ProverJobStatus::ProvingInProgress,
+            StoredProverStatus::Proved(_) => ProverJobStatus::Proved,
+            StoredProverStatus::Failed(error) => ProverJobStatus::Failed { error },
+        }
+    }
+}
+
+/// The response of the `prover_getStatus` RPC method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProverStatusResponse {
+    /// The status of the proving job of the requested slot.
+    /// `None` if the slot has no job, or if its proof has already been sent to the DA layer.
+    pub job: Option<ProverJobStatus>,
+    /// The number of jobs waiting for, or in, proof generation.
+    pub backlog: u64,
+}
+
+fn get_status(ledger_db: &LedgerDB, slot_hash: &DbHash) -> anyhow::Result<ProverStatusResponse> {
+    let backlog = ledger_db
+        .get_prover_jobs()?
+        .into_iter()
+        .filter(|(_, status)| {
+            matches!(
+                status,
+                StoredProverStatus::WitnessSubmitted | StoredProverStatus::ProvingInProgress
+            )
+        })
+        .count() as u64;
+    let job = ledger_db.get_prover_status(slot_hash)?.map(Into::into);
+
+    Ok(ProverStatusResponse { job, backlog })
+}
+
+fn get_proof(ledger_db: &LedgerDB, slot_hash: &DbHash) -> anyhow::Result<Option<Proof>> {
+    match ledger_db.get_prover_status(slot_hash)? {
+        Some(StoredProverStatus::Proved(proof)) => Ok(Some(bincode::deserialize(proof.as_ref())?)),
+        _ => Ok(None),
+    }
+}
+
+/// Creates an RPC module with the prover's methods.
+/// The statuses and the proofs are read from the proving jobs persisted in the ledger.
+pub fn get_prover_rpc(ledger_db: LedgerDB) -> RpcModule<LedgerDB> {
+    let mut rpc = RpcModule::new(ledger_db);
+    rpc.register_method("prover_getStatus", move |params, ledger_db| {
+        let slot_hash: DbHash = params.one()?;
+        get_status(ledger_db, &slot_hash).map_err(to_prover_rpc_error)
+    })
+    .expect("Failed to register prover RPC methods");
+    // The proof of a slot is available until it is sent to the DA layer.
+    rpc.register_method("prover_getProof", move |params, ledger_db| {
+        let slot_hash: DbHash = params.one()?;
+        get_proof(ledger_db, &slot_hash).map_err(to_prover_rpc_error)
+    })
+    .expect("Failed to register prover RPC methods");
+    rpc
+}
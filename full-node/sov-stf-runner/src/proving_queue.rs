@@ -0,0 +1,242 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use anyhow::Context;
+use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec};
+use sov_rollup_interface::services::da::DaService;
+use sov_rollup_interface::zk::StateTransitionData;
+
+use crate::{ProofProcessingStatus, ProofSubmissionStatus, ProverService, WitnessSubmissionStatus};
+
+/// Interval at which the proofs being generated are polled.
+pub(crate) const PROOF_POLLING_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Maximum number of finalized slots waiting for their proof generation to start.
+/// Once it is reached, the execution of the next DA blocks waits for the prover.
+const MAX_PENDING_PROOFS: usize = 32;
+
+type SlotHash<Ps> = <<<Ps as ProverService>::DaService as DaService>::Spec as DaSpec>::SlotHash;
+
+type TransitionData<Ps> = StateTransitionData<
+    <Ps as ProverService>::StateRoot,
+    <Ps as ProverService>::Witness,
+    <<Ps as ProverService>::DaService as DaService>::Spec,
+>;
+
+/// Drives the proof generation of the finalized slots, in order, without blocking the execution
+/// of the next DA blocks.
+pub(crate) struct ProvingQueue<Ps: ProverService> {
+    prover_service: Ps,
+    /// The finalized slots whose proof generation has not started yet.
+    pending: VecDeque<SlotHash<Ps>>,
+    /// The slots whose proofs are being generated, or wait to be sent to the DA.
+    in_progress: VecDeque<SlotHash<Ps>>,
+    /// The height of the last DA block queued for proving.
+    last_queued_height: Option<u64>,
+}
+
+impl<Ps: ProverService> ProvingQueue<Ps> {
+    pub(crate) fn new(prover_service: Ps) -> Self {
+        Self {
+            prover_service,
+            pending: Default::default(),
+            in_progress: Default::default(),
+            last_queued_height: None,
+        }
+    }
+
+    /// Queues the proving jobs recovered by the prover service after a restart.
+    pub(crate) async fn recover(&mut self) -> Result<(), anyhow::Error> {
+        let recovered_jobs = self
+            .prover_service
+            .take_recovered_jobs()
+            .await
+            .context("Failed to recover the proving jobs")?;
+        for header in recovered_jobs {
+            self.last_queued_height = Some(header.height());
+            self.in_progress.push_back(header.hash());
+        }
+        Ok(())
+    }
+
+    /// Submits the witness of a finalized slot to the prover service.
+    pub(crate) async fn submit(
+        &mut self,
+        transition_data: TransitionData<Ps>,
+    ) -> Result<(), anyhow::Error> {
+        let header = &transition_data.da_block_header;
+        // The slots executed again after a restart may already be queued, if their jobs were recovered.
+        if matches!(self.last_queued_height, Some(last) if header.height() <= last) {
+            return Ok(());
+        }
+        self.last_queued_height = Some(header.height());
+
+        let header_hash = header.hash();
+        let status = self
+            .prover_service
+            .submit_witness(transition_data)
+            .await
+            .with_context(|| format!("Failed to submit the witness of slot {:?}", header_hash))?;
+        match status {
+            WitnessSubmissionStatus::SubmittedForProving => self.pending.push_back(header_hash),
+            WitnessSubmissionStatus::WitnessExist => {
+                tracing::warn!(
+                    "The witness of slot {:?} has already been submitted",
+                    header_hash
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn is_idle(&self) -> bool {
+        self.pending.is_empty() && self.in_progress.is_empty()
+    }
+
+    /// Sends the generated proofs to the DA, in order, and then starts the proof generation
+    /// of the pending slots until the prover is busy.
+    pub(crate) async fn process(&mut self) -> Result<(), anyhow::Error> {
+        while let Some(header_hash) = self.in_progress.front().cloned() {
+            let status = self
+                .prover_service
+                .send_proof_to_da(header_hash.clone())
+                .await
+                .with_context(|| format!("Failed to send the proof of slot {:?}", header_hash))?;
+
+            match status {
+                ProofSubmissionStatus::Success => {
+                    self.in_progress.pop_front();
+                }
+                ProofSubmissionStatus::ProofGenerationInProgress => break,
+            }
+        }
+
+        while let Some(header_hash) = self.pending.front().cloned() {
+            let status = self
+                .prover_service
+                .prove(header_hash.clone())
+                .await
+                .with_context(|| format!("Failed to prove slot {:?}", header_hash))?;
+
+            match status {
+                ProofProcessingStatus::ProvingInProgress => {
+                    self.pending.pop_front();
+                    self.in_progress.push_back(header_hash);
+                }
+                // The remaining slots wait for the prover to be available.
+                ProofProcessingStatus::Busy => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Waits for the prover while too many finalized slots wait for their proof generation to start.
+    pub(crate) async fn wait_for_capacity(&mut self) -> Result<(), anyhow::Error> {
+        while self.pending.len() >= MAX_PENDING_PROOFS {
+            tokio::time::sleep(PROOF_POLLING_INTERVAL).await;
+            self.process().await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use sov_mock_da::{MockBlockHeader, MockDaService, MockDaSpec, MockHash};
+    use sov_rollup_interface::da::Time;
+
+    use super::*;
+    use crate::ProverServiceError;
+
+    /// Proves the slots instantly, unless it's busy.
+    #[derive(Clone, Default)]
+    struct TestProverService {
+        busy: Arc<AtomicBool>,
+    }
+
+    #[async_trait]
+    impl ProverService for TestProverService {
+        type StateRoot = [u8; 1];
+        type Witness = ();
+        type DaService = MockDaService;
+
+        async fn submit_witness(
+            &self,
+            _state_transition_data: StateTransitionData<[u8; 1], (), MockDaSpec>,
+        ) -> Result<WitnessSubmissionStatus, anyhow::Error> {
+            Ok(WitnessSubmissionStatus::SubmittedForProving)
+        }
+
+        async fn prove(
+            &self,
+            _block_header_hash: MockHash,
+        ) -> Result<ProofProcessingStatus, ProverServiceError> {
+            if self.busy.load(Ordering::SeqCst) {
+                Ok(ProofProcessingStatus::Busy)
+            } else {
+                Ok(ProofProcessingStatus::ProvingInProgress)
+            }
+        }
+
+        async fn send_proof_to_da(
+            &self,
+            _block_header_hash: MockHash,
+        ) -> Result<ProofSubmissionStatus, anyhow::Error> {
+            Ok(ProofSubmissionStatus::Success)
+        }
+
+        async fn take_recovered_jobs(&self) -> Result<Vec<MockBlockHeader>, anyhow::Error> {
+            Ok(vec![])
+        }
+    }
+
+    fn make_transition_data(height: u64) -> StateTransitionData<[u8; 1], (), MockDaSpec> {
+        StateTransitionData {
+            initial_state_root: [0],
+            final_state_root: [0],
+            da_block_header: MockBlockHeader {
+                prev_hash: [0; 32].into(),
+                hash: MockHash::from([height as u8; 32]),
+                height,
+                time: Time::now(),
+            },
+            inclusion_proof: [0; 32],
+            completeness_proof: (),
+            blobs: vec![],
+            state_transition_witness: (),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_block_execution_resumes_when_the_prover_catches_up() -> Result<(), anyhow::Error>
+    {
+        let prover_service = TestProverService::default();
+        prover_service.busy.store(true, Ordering::SeqCst);
+        let mut queue = ProvingQueue::new(prover_service.clone());
+
+        for height in 0..MAX_PENDING_PROOFS as u64 {
+            queue.submit(make_transition_data(height)).await?;
+            queue.process().await?;
+        }
+        assert_eq!(MAX_PENDING_PROOFS, queue.pending.len());
+
+        // The execution of the next DA block waits while the prover is busy.
+        let waited =
+            tokio::time::timeout(PROOF_POLLING_INTERVAL * 5, queue.wait_for_capacity()).await;
+        assert!(waited.is_err());
+        assert_eq!(MAX_PENDING_PROOFS, queue.pending.len());
+
+        // It resumes once the prover accepts new jobs.
+        prover_service.busy.store(false, Ordering::SeqCst);
+        tokio::time::timeout(PROOF_POLLING_INTERVAL * 5, queue.wait_for_capacity()).await??;
+        assert!(queue.pending.is_empty());
+        assert_eq!(MAX_PENDING_PROOFS, queue.in_progress.len());
+
+        Ok(())
+    }
+}
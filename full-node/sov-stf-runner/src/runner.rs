@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::net::SocketAddr;
 
 use jsonrpsee::RpcModule;
@@ -11,11 +11,17 @@ use sov_rollup_interface::zk::{StateTransitionData, Zkvm, ZkvmHost};
 use tokio::sync::oneshot;
 use tracing::{debug, info};
 
+use crate::proving_queue::{ProvingQueue, PROOF_POLLING_INTERVAL};
 use crate::verifier::StateTransitionVerifier;
-use crate::{ProofSubmissionStatus, ProverService, RunnerConfig};
+use crate::{ProverService, RunnerConfig};
 
 type StateRoot<ST, Vm, Da> = <ST as StateTransitionFunction<Vm, Da>>::StateRoot;
 type GenesisParams<ST, Vm, Da> = <ST as StateTransitionFunction<Vm, Da>>::GenesisParams;
+type TransitionData<ST, Vm, Da> = StateTransitionData<
+    <ST as StateTransitionFunction<Vm, Da>>::StateRoot,
+    <ST as StateTransitionFunction<Vm, Da>>::Witness,
+    Da,
+>;
 
 /// Combines `DaService` with `StateTransitionFunction` and "runs" the rollup.
 pub struct StateTransitionRunner<Stf, Sm, Da, Vm, Ps>
@@ -33,7 +39,7 @@ where
     ledger_db: LedgerDB,
     state_root: StateRoot<Stf, Vm, Da::Spec>,
     listen_address: SocketAddr,
-    prover_service: Ps,
+    prover: Option<ProvingQueue<Ps>>,
 }
 
 /// Represents the possible modes of execution for a zkVM program
@@ -85,6 +91,8 @@ where
     /// If a previous state root is provided, uses that as the starting point
     /// for execution. Otherwise, initializes the chain using the provided
     /// genesis config.
+    ///
+    /// A full node which doesn't prove the DA blocks is created without a `prover_service`.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         runner_config: RunnerConfig,
@@ -93,7 +101,7 @@ where
         stf: Stf,
         mut storage_manager: Sm,
         init_variant: InitVariant<Stf, Vm, Da::Spec>,
-        prover_service: Ps,
+        prover_service: Option<Ps>,
     ) -> Result<Self, anyhow::Error> {
         let rpc_config = runner_config.rpc_config;
 
@@ -137,7 +145,7 @@ where
             ledger_db,
             state_root: prev_state_root,
             listen_address,
-            prover_service,
+            prover: prover_service.map(ProvingQueue::new),
         })
     }
 
@@ -168,18 +176,38 @@ where
     /// Runs the rollup.
     pub async fn run_in_process(&mut self) -> Result<(), anyhow::Error> {
         let mut seen_block_headers: VecDeque<<Da::Spec as DaSpec>::BlockHeader> = VecDeque::new();
-        let mut seen_receipts: VecDeque<_> = VecDeque::new();
+        let mut seen_receipts: VecDeque<
+            SlotCommit<Da::FilteredBlock, Stf::BatchReceiptContents, Stf::TxReceiptContents>,
+        > = VecDeque::new();
+        let mut seen_transitions: VecDeque<Option<TransitionData<Stf, Vm, Da::Spec>>> =
+            VecDeque::new();
+        // The state roots after the seen blocks, following the forks of the storage manager.
+        let mut seen_state_roots: HashMap<
+            <Da::Spec as DaSpec>::SlotHash,
+            StateRoot<Stf, Vm, Da::Spec>,
+        > = HashMap::new();
+        let mut finalized_state_root = self.state_root.clone();
         let mut height = self.start_height;
+
+        if let Some(prover) = &mut self.prover {
+            prover.recover().await?;
+        }
+
         loop {
             debug!("Requesting data for height {}", height);
-            let mut filtered_block = self.da_service.get_block_at(height).await?;
+            let mut filtered_block = self.get_block_at(height).await?;
 
             // Checking if reorg happened or not.
             if let Some(prev_block_header) = seen_block_headers.back() {
                 if prev_block_header.hash() != filtered_block.header().prev_hash() {
                     tracing::warn!("Block at height={} does not belong in current chain. Chain has forked. Traversing backwards", height);
                     while let Some(seen_block_header) = seen_block_headers.pop_back() {
-                        seen_receipts.pop_back();
+                        if let Some(receipts) = seen_receipts.pop_back() {
+                            self.ledger_db
+                                .remove_unfinalized_slot(&receipts.slot_data().hash());
+                        }
+                        seen_transitions.pop_back();
+                        seen_state_roots.remove(&seen_block_header.hash());
                         let block = self
                             .da_service
                             .get_block_at(seen_block_header.height())
@@ -212,12 +240,17 @@ where
 
             let mut data_to_commit = SlotCommit::new(filtered_block.clone());
 
+            // The block is executed on top of its parent, which is either a seen block or the last finalized one.
+            let pre_state_root = seen_state_roots
+                .get(&filtered_block.header().prev_hash())
+                .unwrap_or(&finalized_state_root)
+                .clone();
+
             let pre_state = self
                 .storage_manager
                 .create_storage_on(filtered_block.header())?;
             let slot_result = self.stf.apply_slot(
-                // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1247): incorrect pre-state root in case of re-org
-                &self.state_root,
+                &pre_state_root,
                 pre_state,
                 Default::default(),
                 filtered_block.header(),
@@ -229,60 +262,37 @@ where
                 data_to_commit.add_batch(receipt);
             }
 
-            let (inclusion_proof, completeness_proof) = self
-                .da_service
-                .get_extraction_proof(&filtered_block, &blobs)
-                .await;
-
-            let transition_data: StateTransitionData<Stf::StateRoot, Stf::Witness, Da::Spec> =
-                StateTransitionData {
-                    // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1247): incorrect pre-state root in case of re-org
-                    initial_state_root: self.state_root.clone(),
-                    final_state_root: slot_result.state_root.clone(),
-                    da_block_header: filtered_block.header().clone(),
-                    inclusion_proof,
-                    completeness_proof,
-                    blobs,
-                    state_transition_witness: slot_result.witness,
-                };
+            // The witnesses are only needed to prove the finalized slots.
+            let transition_data = match self.prover {
+                Some(_) => {
+                    let (inclusion_proof, completeness_proof) = self
+                        .da_service
+                        .get_extraction_proof(&filtered_block, &blobs)
+                        .await;
+
+                    Some(StateTransitionData {
+                        initial_state_root: pre_state_root,
+                        final_state_root: slot_result.state_root.clone(),
+                        da_block_header: filtered_block.header().clone(),
+                        inclusion_proof,
+                        completeness_proof,
+                        blobs,
+                        state_transition_witness: slot_result.witness,
+                    })
+                }
+                None => None,
+            };
 
             self.storage_manager
                 .save_change_set(filtered_block.header(), slot_result.change_set)?;
 
-            // ----------------
-            // Create ZK proof.
-            {
-                let header_hash = transition_data.da_block_header.hash();
-                self.prover_service.submit_witness(transition_data).await;
-                // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1185):
-                //   This section will be moved and called upon block finalization once we have fork management ready.
-                self.prover_service
-                    .prove(header_hash.clone())
-                    .await
-                    .expect("The proof creation should succeed");
-
-                loop {
-                    let status = self
-                        .prover_service
-                        .send_proof_to_da(header_hash.clone())
-                        .await;
-
-                    match status {
-                        Ok(ProofSubmissionStatus::Success) => {
-                            break;
-                        }
-                        // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1185): Add timeout handling.
-                        Ok(ProofSubmissionStatus::ProofGenerationInProgress) => {
-                            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
-                        }
-                        // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1185): Add handling for DA submission errors.
-                        Err(e) => panic!("{:?}", e),
-                    }
-                }
-            }
             let next_state_root = slot_result.state_root;
 
+            // The transactions are reported as executed until the slot is finalized.
+            self.ledger_db.add_unfinalized_slot(&data_to_commit)?;
             seen_receipts.push_back(data_to_commit);
+            seen_transitions.push_back(transition_data);
+            seen_state_roots.insert(filtered_block.header().hash(), next_state_root.clone());
 
             self.state_root = next_state_root;
             seen_block_headers.push_back(filtered_block.header().clone());
@@ -309,14 +319,54 @@ where
                         earliest_seen_header.height()
                     );
                     self.storage_manager.finalize(earliest_seen_header)?;
+                    finalized_state_root = seen_state_roots
+                        .remove(&earliest_seen_header.hash())
+                        .expect("The state root of a seen block is tracked until its finalization");
                     seen_block_headers.pop_front();
                     let receipts = seen_receipts.pop_front().unwrap();
+                    let transition_data = seen_transitions.pop_front().unwrap();
+                    // The witness is submitted before the slot is committed, so that its proving
+                    // job is recovered if the node restarts in between.
+                    if let (Some(prover), Some(transition_data)) =
+                        (&mut self.prover, transition_data)
+                    {
+                        prover.submit(transition_data).await?;
+                    }
                     self.ledger_db.commit_slot(receipts)?;
                     continue;
                 }
 
                 break;
             }
+
+            // ----------------
+            // Proving. The proofs of the finalized slots are generated in the background.
+            if let Some(prover) = &mut self.prover {
+                prover.process().await?;
+                prover.wait_for_capacity().await?;
+            }
+        }
+    }
+
+    /// Gets the DA block at the given height, and makes progress on the pending proofs while waiting for it.
+    async fn get_block_at(&mut self, height: u64) -> Result<Da::FilteredBlock, anyhow::Error> {
+        // The same future is polled until the block is received, because `DaService::get_block_at` isn't cancel safe.
+        let block = self.da_service.get_block_at(height);
+        tokio::pin!(block);
+
+        let Some(prover) = &mut self.prover else {
+            return block.await;
+        };
+
+        loop {
+            if prover.is_idle() {
+                return block.await;
+            }
+
+            tokio::select! {
+                block = &mut block => return block,
+                _ = tokio::time::sleep(PROOF_POLLING_INTERVAL) => prover.process().await?,
+            }
         }
     }
 
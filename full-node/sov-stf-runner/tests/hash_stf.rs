@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
 use sha2::Digest;
 use sov_mock_da::{
     MockAddress, MockBlob, MockBlock, MockBlockHeader, MockDaSpec, MockValidityCond,
@@ -15,18 +18,45 @@ use sov_state::{
 pub type S = DefaultStorageSpec;
 pub type Q = SnapshotManager;
 
+/// A slot applied by the [`HashStf`].
+struct AppliedSlot {
+    slot_hash: [u8; 32],
+    prev_hash: [u8; 32],
+    pre_state_root: [u8; 32],
+    post_state_root: [u8; 32],
+}
+
 #[derive(Default, Clone)]
 pub struct HashStf<Cond> {
+    applied_slots: Arc<Mutex<Vec<AppliedSlot>>>,
     phantom_data: std::marker::PhantomData<Cond>,
 }
 
 impl<Cond> HashStf<Cond> {
     pub fn new() -> Self {
         Self {
+            applied_slots: Default::default(),
             phantom_data: std::marker::PhantomData,
         }
     }
 
+    /// Checks that every slot has been applied on top of the state root of its parent,
+    /// or on top of `initial_state_root` if its parent hasn't been applied.
+    pub fn check_pre_state_roots(&self, initial_state_root: &[u8; 32]) {
+        let mut post_state_roots: HashMap<[u8; 32], [u8; 32]> = HashMap::new();
+        for slot in self.applied_slots.lock().unwrap().iter() {
+            let expected = post_state_roots
+                .get(&slot.prev_hash)
+                .unwrap_or(initial_state_root);
+            assert_eq!(
+                expected, &slot.pre_state_root,
+                "Slot {:?} has been applied on top of an incorrect state root",
+                slot.slot_hash
+            );
+            post_state_roots.insert(slot.slot_hash, slot.post_state_root);
+        }
+    }
+
     fn hash_key() -> StorageKey {
         let prefix = Prefix::new(b"root".to_vec());
         StorageKey::singleton(&prefix)
@@ -126,6 +156,13 @@ impl<Vm: Zkvm, Cond: ValidityCondition, Da: DaSpec> StateTransitionFunction<Vm,
 
         let (state_root, storage) = HashStf::<Cond>::save_from_hasher(hasher, storage, &witness);
 
+        self.applied_slots.lock().unwrap().push(AppliedSlot {
+            slot_hash: slot_header.hash().into(),
+            prev_hash: slot_header.prev_hash().into(),
+            pre_state_root: *pre_state_root,
+            post_state_root: state_root,
+        });
+
         SlotResult {
             state_root,
             change_set: storage,
@@ -225,6 +262,8 @@ pub fn get_result_from_blocks(
         storage = result.change_set;
     }
 
+    stf.check_pre_state_roots(&genesis_state_root);
+
     let root_hash = storage.get_root_hash(l as u64).ok();
     (state_root, root_hash)
 }
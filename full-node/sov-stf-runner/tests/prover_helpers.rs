@@ -0,0 +1,45 @@
+use sov_mock_da::{MockBlockHeader, MockDaService, MockDaSpec, MockHash};
+use sov_rollup_interface::da::Time;
+use sov_rollup_interface::zk::StateTransitionData;
+use sov_stf_runner::{ProofSubmissionStatus, ProverService};
+
+pub fn make_transition_data(
+    header_hash: MockHash,
+) -> StateTransitionData<[u8; 1], Vec<u8>, MockDaSpec> {
+    make_transition_data_with_roots(header_hash, [0], [0])
+}
+
+pub fn make_transition_data_with_roots(
+    header_hash: MockHash,
+    initial_state_root: [u8; 1],
+    final_state_root: [u8; 1],
+) -> StateTransitionData<[u8; 1], Vec<u8>, MockDaSpec> {
+    StateTransitionData {
+        initial_state_root,
+        final_state_root,
+        da_block_header: MockBlockHeader {
+            prev_hash: [0; 32].into(),
+            hash: header_hash,
+            height: 0,
+            time: Time::now(),
+        },
+        inclusion_proof: [0; 32],
+        completeness_proof: (),
+        blobs: vec![],
+        state_transition_witness: vec![],
+    }
+}
+
+/// Waits for the proof of `header_hash` to be sent to the DA, giving up after a second.
+pub async fn wait_for_proof_da_submission<Ps>(header_hash: MockHash, prover_service: &Ps)
+where
+    Ps: ProverService<DaService = MockDaService>,
+{
+    for _ in 0..10 {
+        let status = prover_service.send_proof_to_da(header_hash).await;
+        if let Ok(ProofSubmissionStatus::Success) = status {
+            return;
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
+    }
+}
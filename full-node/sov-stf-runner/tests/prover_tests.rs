@@ -1,13 +1,27 @@
+use std::sync::Arc;
+
+use sov_db::ledger_db::LedgerDB;
 use sov_mock_da::{
-    MockBlockHeader, MockDaService, MockDaSpec, MockDaVerifier, MockHash, MockValidityCond,
+    MockAddress, MockDaService, MockDaSpec, MockDaVerifier, MockHash, MockValidityCond,
+};
+use sov_mock_zkvm::{MockCodeCommitment, MockZkGuest, MockZkvm};
+use sov_rollup_interface::da::BlockHeaderTrait;
+use sov_rollup_interface::services::da::DaService;
+use sov_rollup_interface::zk::{
+    AggregatedStateTransition, StateTransition, StateTransitionData, Zkvm, ZkvmGuest,
 };
-use sov_mock_zkvm::MockZkvm;
-use sov_rollup_interface::da::Time;
-use sov_rollup_interface::zk::StateTransitionData;
+use sov_stf_runner::aggregator::StateTransitionAggregator;
 use sov_stf_runner::mock::MockStf;
 use sov_stf_runner::{
-    ParallelProverService, ProofProcessingStatus, ProofSubmissionStatus, ProverService,
-    ProverServiceConfig, ProverServiceError, RollupProverConfig, WitnessSubmissionStatus,
+    get_prover_rpc, ParallelProverService, ProofProcessingStatus, ProofSubmissionConfig,
+    ProofSubmissionPolicy, ProofSubmissionStatus, ProverJobStatus, ProverService,
+    ProverServiceConfig, ProverServiceError, ProverStatusResponse, RollupProverConfig,
+    WitnessSubmissionStatus,
+};
+
+mod prover_helpers;
+use prover_helpers::{
+    make_transition_data, make_transition_data_with_roots, wait_for_proof_da_submission,
 };
 
 #[tokio::test]
@@ -19,10 +33,10 @@ async fn test_successful_prover_execution() -> Result<(), ProverServiceError> {
     let header_hash = MockHash::from([0; 32]);
     prover_service
         .submit_witness(make_transition_data(header_hash))
-        .await;
+        .await?;
     prover_service.prove(header_hash).await?;
     vm.make_proof();
-    wait_for_proof_proof_da_submission(header_hash, &prover_service).await;
+    wait_for_proof_da_submission(header_hash, &prover_service).await;
 
     // The proof has already been sent, and the prover_service no longer has a reference to it.
     let err = prover_service
@@ -53,7 +67,7 @@ async fn test_prover_status_busy() -> Result<(), anyhow::Error> {
     for header_hash in header_hashes.clone() {
         prover_service
             .submit_witness(make_transition_data(header_hash))
-            .await;
+            .await?;
 
         let poof_processing_status = prover_service.prove(header_hash).await?;
         assert_eq!(
@@ -69,14 +83,14 @@ async fn test_prover_status_busy() -> Result<(), anyhow::Error> {
     }
 
     // Attempting to create another proof while the prover is busy.
+    let header_hash = MockHash::from([0; 32]);
     {
-        let header_hash = MockHash::from([0; 32]);
         prover_service
             .submit_witness(make_transition_data(header_hash))
-            .await;
+            .await?;
 
         let status = prover_service.prove(header_hash).await?;
-        // The prover is busy and won't accept any new jobs.
+        // The prover is busy and won't start any new jobs.
         assert_eq!(ProofProcessingStatus::Busy, status);
 
         let proof_submission_status = prover_service
@@ -84,24 +98,19 @@ async fn test_prover_status_busy() -> Result<(), anyhow::Error> {
             .await
             .unwrap_err();
 
-        // The new job wasn't accepted.
+        // The witness of the new job is kept.
         assert_eq!(
         proof_submission_status.to_string(),
-        "Missing witness for: 0x0000000000000000000000000000000000000000000000000000000000000000");
+        "Witness for 0x0000000000000000000000000000000000000000000000000000000000000000 was submitted, but the proof generation is not triggered.");
     }
 
     vm.make_proof();
     for header_hash in header_hashes.clone() {
-        wait_for_proof_proof_da_submission(header_hash, &prover_service).await;
+        wait_for_proof_da_submission(header_hash, &prover_service).await;
     }
 
     // Retry once the prover is available to process new proofs.
     {
-        let header_hash = MockHash::from([(num_worker_threads + 1) as u8; 32]);
-        prover_service
-            .submit_witness(make_transition_data(header_hash))
-            .await;
-
         let status = prover_service.prove(header_hash).await?;
         assert_eq!(ProofProcessingStatus::ProvingInProgress, status);
     }
@@ -129,7 +138,7 @@ async fn test_multiple_witness_submissions() -> Result<(), anyhow::Error> {
     let header_hash = MockHash::from([0; 32]);
     let submission_status = prover_service
         .submit_witness(make_transition_data(header_hash))
-        .await;
+        .await?;
 
     assert_eq!(
         WitnessSubmissionStatus::SubmittedForProving,
@@ -138,7 +147,7 @@ async fn test_multiple_witness_submissions() -> Result<(), anyhow::Error> {
 
     let submission_status = prover_service
         .submit_witness(make_transition_data(header_hash))
-        .await;
+        .await?;
 
     assert_eq!(WitnessSubmissionStatus::WitnessExist, submission_status);
 
@@ -152,7 +161,7 @@ async fn test_generate_multiple_proofs_for_the_same_witness() -> Result<(), anyh
     let header_hash = MockHash::from([0; 32]);
     prover_service
         .submit_witness(make_transition_data(header_hash))
-        .await;
+        .await?;
 
     let status = prover_service.prove(header_hash).await?;
     assert_eq!(ProofProcessingStatus::ProvingInProgress, status);
@@ -162,76 +171,499 @@ async fn test_generate_multiple_proofs_for_the_same_witness() -> Result<(), anyh
     Ok(())
 }
 
+#[tokio::test]
+async fn test_aggregated_proofs() -> Result<(), anyhow::Error> {
+    let TestProver {
+        prover_service,
+        vm,
+        da_service,
+        ledger_db,
+        ..
+    } = make_new_prover_with_block_jump(2);
+    vm.make_proof();
+
+    let header_hashes = [1, 2, 3].map(|hash| MockHash::from([hash; 32]));
+    for (root, header_hash) in header_hashes.into_iter().enumerate() {
+        let root = root as u8;
+        prover_service
+            .submit_witness(make_transition_data_with_roots(
+                header_hash,
+                [root],
+                [root + 1],
+            ))
+            .await?;
+        prover_service.prove(header_hash).await?;
+        wait_for_proof_da_submission(header_hash, &prover_service).await;
+    }
+
+    // The aggregated proof of the first two blocks has been posted to the DA,
+    // and the third block waits for the next one to be aggregated.
+    assert!(ledger_db
+        .get_proof_submission(&header_hashes[2].into())?
+        .is_none());
+    let proof_submission = ledger_db
+        .get_proof_submission(&header_hashes[1].into())?
+        .expect("The aggregated proof must be recorded in the ledger");
+    assert_eq!(
+        <[u8; 32]>::from(header_hashes[0]),
+        proof_submission.initial_slot_hash
+    );
+
+    let aggregated_proofs = da_service
+        .get_aggregated_proofs_at(proof_submission.da_height)
+        .await?;
+    assert_eq!(1, aggregated_proofs.len());
+    let output =
+        MockZkvm::<MockValidityCond>::verify(&aggregated_proofs[0], &AGGREGATOR_CODE_COMMITMENT)?;
+    let state_transition: AggregatedStateTransition<MockDaSpec, [u8; 1]> =
+        bincode::deserialize(output)?;
+    assert_eq!(
+        AggregatedStateTransition {
+            initial_state_root: [0],
+            final_state_root: [2],
+            initial_slot_hash: header_hashes[0],
+            final_slot_hash: header_hashes[1],
+            validity_condition: MockValidityCond::default(),
+        },
+        state_transition
+    );
+
+    // The next block doesn't start from the final state root of the third one.
+    let header_hash = MockHash::from([4; 32]);
+    prover_service
+        .submit_witness(make_transition_data_with_roots(header_hash, [0], [1]))
+        .await?;
+    prover_service.prove(header_hash).await?;
+
+    let err = loop {
+        match prover_service.send_proof_to_da(header_hash).await {
+            Ok(ProofSubmissionStatus::ProofGenerationInProgress) => {
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
+            }
+            Ok(ProofSubmissionStatus::Success) => panic!("The proofs must not be aggregated"),
+            Err(e) => break e,
+        }
+    };
+    assert!(err
+        .to_string()
+        .contains("doesn't start from the final state root"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_failed_aggregation_keeps_the_window_and_the_job() -> Result<(), anyhow::Error> {
+    let TestProver {
+        prover_service,
+        vm,
+        da_service,
+        ledger_db,
+        ..
+    } = make_new_prover_with_block_jump(2);
+    vm.make_proof();
+
+    let first_hash = MockHash::from([1; 32]);
+    prover_service
+        .submit_witness(make_transition_data_with_roots(first_hash, [0], [1]))
+        .await?;
+    prover_service.prove(first_hash).await?;
+    wait_for_proof_da_submission(first_hash, &prover_service).await;
+
+    // The proof of the second block can't be aggregated with the first one.
+    let broken_hash = MockHash::from([2; 32]);
+    prover_service
+        .submit_witness(make_transition_data_with_roots(broken_hash, [5], [6]))
+        .await?;
+    prover_service.prove(broken_hash).await?;
+    let err = loop {
+        match prover_service.send_proof_to_da(broken_hash).await {
+            Ok(ProofSubmissionStatus::ProofGenerationInProgress) => {
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
+            }
+            Ok(ProofSubmissionStatus::Success) => panic!("The proofs must not be aggregated"),
+            Err(e) => break e,
+        }
+    };
+    assert!(err
+        .to_string()
+        .contains("doesn't start from the final state root"));
+
+    // The job is kept, in memory and in the ledger.
+    let err = prover_service
+        .send_proof_to_da(broken_hash)
+        .await
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("doesn't start from the final state root"));
+    assert!(ledger_db.get_prover_status(&broken_hash.into())?.is_some());
+
+    // The proof of the first block is still waiting for the next one.
+    let third_hash = MockHash::from([3; 32]);
+    prover_service
+        .submit_witness(make_transition_data_with_roots(third_hash, [1], [2]))
+        .await?;
+    prover_service.prove(third_hash).await?;
+    wait_for_proof_da_submission(third_hash, &prover_service).await;
+
+    let proof_submission = ledger_db
+        .get_proof_submission(&third_hash.into())?
+        .expect("The aggregated proof must cover the first and the third blocks");
+    assert_eq!(
+        <[u8; 32]>::from(first_hash),
+        proof_submission.initial_slot_hash
+    );
+    let aggregated_proofs = da_service
+        .get_aggregated_proofs_at(proof_submission.da_height)
+        .await?;
+    assert_eq!(1, aggregated_proofs.len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prover_jobs_recovery() -> Result<(), anyhow::Error> {
+    let test_prover = make_new_prover();
+    let prover_rpc = get_prover_rpc(test_prover.ledger_db.clone());
+
+    let in_progress_hash = MockHash::from([1; 32]);
+    let submitted_hash = MockHash::from([2; 32]);
+    for header_hash in [in_progress_hash, submitted_hash] {
+        test_prover
+            .prover_service
+            .submit_witness(make_transition_data(header_hash))
+            .await?;
+    }
+    test_prover.prover_service.prove(in_progress_hash).await?;
+
+    let status: ProverStatusResponse = prover_rpc
+        .call("prover_getStatus", [<[u8; 32]>::from(in_progress_hash)])
+        .await?;
+    assert_eq!(
+        ProverStatusResponse {
+            job: Some(ProverJobStatus::ProvingInProgress),
+            backlog: 2,
+        },
+        status
+    );
+
+    // The node restarts before the proof is made: both jobs are started over.
+    let TestProver {
+        prover_service, vm, ..
+    } = restart_prover(&test_prover);
+    let recovered_jobs: Vec<MockHash> = prover_service
+        .take_recovered_jobs()
+        .await?
+        .iter()
+        .map(|header| header.hash())
+        .collect();
+    assert_eq!(2, recovered_jobs.len());
+    assert!(recovered_jobs.contains(&in_progress_hash) && recovered_jobs.contains(&submitted_hash));
+    assert!(prover_service.take_recovered_jobs().await?.is_empty());
+    vm.make_proof();
+    for header_hash in [in_progress_hash, submitted_hash] {
+        wait_for_proof_da_submission(header_hash, &prover_service).await;
+    }
+
+    let status: ProverStatusResponse = prover_rpc
+        .call("prover_getStatus", [<[u8; 32]>::from(submitted_hash)])
+        .await?;
+    assert_eq!(
+        ProverStatusResponse {
+            job: None,
+            backlog: 0,
+        },
+        status
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_aggregated_proof_submission_is_retried() -> Result<(), anyhow::Error> {
+    let TestProver {
+        prover_service,
+        vm,
+        da_service,
+        ledger_db,
+        ..
+    } = make_new_prover_with_proof_submission(1, fast_proof_submission(2));
+    vm.make_proof();
+    da_service.fail_next_proof_submissions(2);
+
+    let header_hash = MockHash::from([1; 32]);
+    prover_service
+        .submit_witness(make_transition_data(header_hash))
+        .await?;
+    prover_service.prove(header_hash).await?;
+    wait_for_proof_da_submission(header_hash, &prover_service).await;
+
+    let proof_submission = ledger_db
+        .get_proof_submission(&header_hash.into())?
+        .expect("The aggregated proof must be posted after the failed attempts");
+    let aggregated_proofs = da_service
+        .get_aggregated_proofs_at(proof_submission.da_height)
+        .await?;
+    assert_eq!(1, aggregated_proofs.len());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_aggregated_proof_submission_gives_up_after_max_retries() -> Result<(), anyhow::Error>
+{
+    let TestProver {
+        prover_service,
+        vm,
+        da_service,
+        ledger_db,
+        ..
+    } = make_new_prover_with_proof_submission(1, fast_proof_submission(2));
+    vm.make_proof();
+    da_service.fail_next_proof_submissions(3);
+
+    let first_hash = MockHash::from([1; 32]);
+    prover_service
+        .submit_witness(make_transition_data(first_hash))
+        .await?;
+    prover_service.prove(first_hash).await?;
+
+    let err = loop {
+        match prover_service.send_proof_to_da(first_hash).await {
+            Ok(ProofSubmissionStatus::ProofGenerationInProgress) => {
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
+            }
+            Ok(ProofSubmissionStatus::Success) => panic!("The aggregated proof must not be posted"),
+            Err(e) => break e,
+        }
+    };
+    assert!(err.to_string().contains("after 2 retries"));
+    assert!(ledger_db
+        .get_proof_submission(&first_hash.into())?
+        .is_none());
+
+    // The aggregated proof which could not be posted is kept, and posted before the next one.
+    let second_hash = MockHash::from([2; 32]);
+    prover_service
+        .submit_witness(make_transition_data(second_hash))
+        .await?;
+    prover_service.prove(second_hash).await?;
+    wait_for_proof_da_submission(second_hash, &prover_service).await;
+
+    let first_submission = ledger_db
+        .get_proof_submission(&first_hash.into())?
+        .expect("The first aggregated proof must be posted with the next one");
+    let second_submission = ledger_db
+        .get_proof_submission(&second_hash.into())?
+        .expect("The second aggregated proof must be posted");
+    assert!(first_submission.da_height < second_submission.da_height);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_aggregated_proofs_are_not_posted_when_submission_is_disabled(
+) -> Result<(), anyhow::Error> {
+    let TestProver {
+        prover_service,
+        vm,
+        da_service,
+        ledger_db,
+        ..
+    } = make_new_prover_with_proof_submission(
+        1,
+        ProofSubmissionConfig {
+            policy: ProofSubmissionPolicy::Disabled,
+            ..Default::default()
+        },
+    );
+    vm.make_proof();
+    // Any submission attempt would fail the proof submission.
+    da_service.fail_next_proof_submissions(usize::MAX);
+
+    let header_hash = MockHash::from([1; 32]);
+    prover_service
+        .submit_witness(make_transition_data(header_hash))
+        .await?;
+    prover_service.prove(header_hash).await?;
+
+    let status = loop {
+        match prover_service.send_proof_to_da(header_hash).await? {
+            ProofSubmissionStatus::ProofGenerationInProgress => {
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
+            }
+            status => break status,
+        }
+    };
+    assert_eq!(ProofSubmissionStatus::Success, status);
+    assert!(ledger_db
+        .get_proof_submission(&header_hash.into())?
+        .is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_aggregation_window_recovery() -> Result<(), anyhow::Error> {
+    let test_prover = make_new_prover_with_block_jump(2);
+    test_prover.vm.make_proof();
+
+    let first_hash = MockHash::from([1; 32]);
+    test_prover
+        .prover_service
+        .submit_witness(make_transition_data_with_roots(first_hash, [0], [1]))
+        .await?;
+    test_prover.prover_service.prove(first_hash).await?;
+    wait_for_proof_da_submission(first_hash, &test_prover.prover_service).await;
+
+    // The node restarts before the first proof is aggregated with the next one.
+    let TestProver {
+        prover_service,
+        vm,
+        ledger_db,
+        ..
+    } = restart_prover(&test_prover);
+    assert!(prover_service.take_recovered_jobs().await?.is_empty());
+    vm.make_proof();
+
+    let second_hash = MockHash::from([2; 32]);
+    prover_service
+        .submit_witness(make_transition_data_with_roots(second_hash, [1], [2]))
+        .await?;
+    prover_service.prove(second_hash).await?;
+    wait_for_proof_da_submission(second_hash, &prover_service).await;
+
+    let proof_submission = ledger_db
+        .get_proof_submission(&second_hash.into())?
+        .expect("The aggregated proof must cover the block proved before the restart");
+    assert_eq!(
+        <[u8; 32]>::from(first_hash),
+        proof_submission.initial_slot_hash
+    );
+    Ok(())
+}
+
+const BLOCK_CODE_COMMITMENT: MockCodeCommitment = MockCodeCommitment([1; 32]);
+const AGGREGATOR_CODE_COMMITMENT: MockCodeCommitment = MockCodeCommitment([2; 32]);
+
+/// The program proving the blocks: it commits the state transition claimed by the test data.
+fn block_program(guest: MockZkGuest) -> anyhow::Result<()> {
+    let data: StateTransitionData<[u8; 1], Vec<u8>, MockDaSpec> = guest.read_from_host();
+    guest.commit(&StateTransition::<MockDaSpec, [u8; 1]> {
+        initial_state_root: data.initial_state_root,
+        final_state_root: data.final_state_root,
+        slot_hash: data.da_block_header.hash(),
+        validity_condition: MockValidityCond::default(),
+    });
+    Ok(())
+}
+
+fn aggregator_program(guest: MockZkGuest) -> anyhow::Result<()> {
+    StateTransitionAggregator::<MockDaSpec, [u8; 1], MockZkGuest>::new(BLOCK_CODE_COMMITMENT)
+        .run_aggregation(guest)
+}
+
 struct TestProver {
     prover_service: ParallelProverService<
-        [u8; 0],
+        [u8; 1],
         Vec<u8>,
         MockDaService,
         MockZkvm<MockValidityCond>,
         MockStf<MockValidityCond>,
     >,
     vm: MockZkvm<MockValidityCond>,
+    da_service: MockDaService,
+    ledger_db: LedgerDB,
+    aggregated_proof_block_jump: u64,
     num_worker_threads: usize,
+    tmpdir: Arc<tempfile::TempDir>,
 }
 
-async fn wait_for_proof_proof_da_submission(
-    header_hash: MockHash,
-    prover_service: &ParallelProverService<
-        [u8; 0],
-        Vec<u8>,
-        MockDaService,
-        MockZkvm<MockValidityCond>,
-        MockStf<MockValidityCond>,
-    >,
-) {
-    for _ in 0..10 {
-        let status = prover_service.send_proof_to_da(header_hash).await;
-        if let Ok(ProofSubmissionStatus::Success) = status {
-            return;
-        }
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
+fn make_new_prover() -> TestProver {
+    make_new_prover_with_block_jump(1)
+}
+
+fn make_new_prover_with_block_jump(aggregated_proof_block_jump: u64) -> TestProver {
+    make_new_prover_with_proof_submission(
+        aggregated_proof_block_jump,
+        ProofSubmissionConfig::default(),
+    )
+}
+
+fn make_new_prover_with_proof_submission(
+    aggregated_proof_block_jump: u64,
+    proof_submission: ProofSubmissionConfig,
+) -> TestProver {
+    let tmpdir = Arc::new(tempfile::tempdir().unwrap());
+    let ledger_db = LedgerDB::with_path(tmpdir.path()).unwrap();
+    make_new_prover_with_ledger_db(
+        aggregated_proof_block_jump,
+        proof_submission,
+        ledger_db,
+        tmpdir,
+    )
+}
+
+/// Creates a prover using an existing ledger, as a restarted node does.
+fn restart_prover(test_prover: &TestProver) -> TestProver {
+    make_new_prover_with_ledger_db(
+        test_prover.aggregated_proof_block_jump,
+        ProofSubmissionConfig::default(),
+        test_prover.ledger_db.clone(),
+        test_prover.tmpdir.clone(),
+    )
+}
+
+/// Retries the failed submissions without waiting, to keep the tests fast.
+fn fast_proof_submission(max_retries: u32) -> ProofSubmissionConfig {
+    ProofSubmissionConfig {
+        max_retries,
+        retry_backoff_ms: 1,
+        ..Default::default()
     }
 }
 
-fn make_new_prover() -> TestProver {
+fn make_new_prover_with_ledger_db(
+    aggregated_proof_block_jump: u64,
+    proof_submission: ProofSubmissionConfig,
+    ledger_db: LedgerDB,
+    tmpdir: Arc<tempfile::TempDir>,
+) -> TestProver {
     let num_threads = num_cpus::get();
-    let vm = MockZkvm::new(MockValidityCond::default());
+    let vm = MockZkvm::with_program(
+        MockValidityCond::default(),
+        BLOCK_CODE_COMMITMENT,
+        block_program,
+    );
+    // The tests hold the proofs of the blocks back, the aggregated proofs are generated right away.
+    let aggregator_vm = MockZkvm::with_program(
+        MockValidityCond::default(),
+        AGGREGATOR_CODE_COMMITMENT,
+        aggregator_program,
+    );
+    aggregator_vm.make_proof();
 
-    let prover_config = RollupProverConfig::Execute;
+    let prover_config = RollupProverConfig::Prove;
     let zk_stf = MockStf::<MockValidityCond>::default();
     let da_verifier = MockDaVerifier::default();
+    let da_service = MockDaService::new(MockAddress::new([11u8; 32]));
     TestProver {
         prover_service: ParallelProverService::new(
             vm.clone(),
+            Some(aggregator_vm),
             zk_stf,
             da_verifier,
+            da_service.clone(),
+            ledger_db.clone(),
             prover_config,
             (),
             num_threads,
             ProverServiceConfig {
-                aggregated_proof_block_jump: 1,
+                aggregated_proof_block_jump,
+                proof_submission,
             },
         ),
         vm,
+        da_service,
+        ledger_db,
+        aggregated_proof_block_jump,
         num_worker_threads: num_threads,
-    }
-}
-
-fn make_transition_data(
-    header_hash: MockHash,
-) -> StateTransitionData<[u8; 0], Vec<u8>, MockDaSpec> {
-    StateTransitionData {
-        initial_state_root: [],
-        final_state_root: [],
-        da_block_header: MockBlockHeader {
-            prev_hash: [0; 32].into(),
-            hash: header_hash,
-            height: 0,
-            time: Time::now(),
-        },
-        inclusion_proof: [0; 32],
-        completeness_proof: (),
-        blobs: vec![],
-        state_transition_witness: vec![],
+        tmpdir,
     }
 }
@@ -0,0 +1,167 @@
+use sov_db::ledger_db::LedgerDB;
+use sov_mock_da::{MockAddress, MockDaService, MockDaVerifier, MockHash, MockValidityCond};
+use sov_mock_zkvm::MockZkvm;
+use sov_stf_runner::mock::MockStf;
+use sov_stf_runner::{
+    start_remote_prover_server, ParallelProverService, ProofProcessingStatus,
+    ProofSubmissionConfig, ProofSubmissionStatus, ProverJobStatus, ProverServerConfig,
+    ProverService, ProverServiceConfig, RemoteProverConfig, RemoteProverService,
+    RollupProverConfig, RpcConfig, StorageConfig, WitnessSubmissionStatus,
+};
+
+mod prover_helpers;
+use prover_helpers::{make_transition_data, wait_for_proof_da_submission};
+
+const AUTH_TOKEN: &str = "SECRET_PROVER_TOKEN";
+
+type TestRemoteProver = RemoteProverService<[u8; 1], Vec<u8>, MockDaService>;
+
+#[tokio::test]
+async fn test_remote_prover_execution() -> Result<(), anyhow::Error> {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let (remote_prover, vm) = start_prover_server(tmpdir.path(), AUTH_TOKEN).await?;
+
+    let header_hash = MockHash::from([0; 32]);
+    let submission_status = remote_prover
+        .submit_witness(make_transition_data(header_hash))
+        .await?;
+    assert_eq!(
+        WitnessSubmissionStatus::SubmittedForProving,
+        submission_status
+    );
+
+    let submission_status = remote_prover
+        .submit_witness(make_transition_data(header_hash))
+        .await?;
+    assert_eq!(WitnessSubmissionStatus::WitnessExist, submission_status);
+
+    let status = remote_prover.prove(header_hash).await?;
+    assert_eq!(ProofProcessingStatus::ProvingInProgress, status);
+
+    let proof_submission_status = remote_prover.send_proof_to_da(header_hash).await?;
+    assert_eq!(
+        ProofSubmissionStatus::ProofGenerationInProgress,
+        proof_submission_status
+    );
+    assert_eq!(None, remote_prover.get_proof(header_hash).await?);
+
+    vm.make_proof();
+    wait_for_proof(header_hash, &remote_prover).await?;
+    assert!(remote_prover.get_proof(header_hash).await?.is_some());
+
+    wait_for_proof_da_submission(header_hash, &remote_prover).await;
+    assert_eq!(None, remote_prover.get_status(header_hash).await?.job);
+    assert_eq!(None, remote_prover.get_proof(header_hash).await?);
+
+    assert!(remote_prover.take_recovered_jobs().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remote_prover_errors() -> Result<(), anyhow::Error> {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let (remote_prover, _vm) = start_prover_server(tmpdir.path(), AUTH_TOKEN).await?;
+
+    // The errors of the prover server are reported as they are by the client.
+    let header_hash = MockHash::from([0; 32]);
+    let err = remote_prover.prove(header_hash).await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Missing witness for block: 0x0000000000000000000000000000000000000000000000000000000000000000"
+    );
+
+    let err = remote_prover
+        .send_proof_to_da(header_hash)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Missing witness for: 0x0000000000000000000000000000000000000000000000000000000000000000"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_remote_prover_rejects_invalid_auth_token() -> Result<(), anyhow::Error> {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let (remote_prover, _vm) = start_prover_server(tmpdir.path(), "WRONG_TOKEN").await?;
+
+    let header_hash = MockHash::from([0; 32]);
+    let err = remote_prover
+        .submit_witness(make_transition_data(header_hash))
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "Invalid prover server auth token");
+
+    let err = remote_prover.take_recovered_jobs().await.unwrap_err();
+    assert_eq!(err.to_string(), "Invalid prover server auth token");
+
+    // Nothing reached the prover service.
+    assert_eq!(None, remote_prover.get_status(header_hash).await?.job);
+
+    Ok(())
+}
+
+/// Starts a prover server on a loopback socket, and returns a client connected to it with `client_auth_token`.
+async fn start_prover_server(
+    path: &std::path::Path,
+    client_auth_token: &str,
+) -> Result<(TestRemoteProver, MockZkvm<MockValidityCond>), anyhow::Error> {
+    let ledger_db = LedgerDB::with_path(path)?;
+    let vm = MockZkvm::new(MockValidityCond::default());
+
+    let prover_service = ParallelProverService::new(
+        vm.clone(),
+        None,
+        MockStf::<MockValidityCond>::default(),
+        MockDaVerifier::default(),
+        MockDaService::new(MockAddress::new([11u8; 32])),
+        ledger_db.clone(),
+        RollupProverConfig::Execute,
+        (),
+        1,
+        ProverServiceConfig {
+            aggregated_proof_block_jump: 1,
+            proof_submission: ProofSubmissionConfig::default(),
+        },
+    );
+
+    let server_config = ProverServerConfig {
+        rpc_config: RpcConfig {
+            bind_host: "127.0.0.1".to_string(),
+            bind_port: 0,
+        },
+        storage: StorageConfig {
+            path: path.to_path_buf(),
+        },
+        auth_token: AUTH_TOKEN.to_string(),
+        max_payload_size: 1024 * 1024,
+    };
+    let (address, server_handle) =
+        start_remote_prover_server(prover_service, ledger_db, &server_config).await?;
+    // The server runs until the end of the test.
+    tokio::spawn(server_handle.stopped());
+
+    let remote_prover = RemoteProverService::new(&RemoteProverConfig {
+        url: format!("http://{}", address),
+        auth_token: client_auth_token.to_string(),
+        max_payload_size: 1024 * 1024,
+    })?;
+    Ok((remote_prover, vm))
+}
+
+async fn wait_for_proof(
+    header_hash: MockHash,
+    remote_prover: &TestRemoteProver,
+) -> Result<(), anyhow::Error> {
+    for _ in 0..10 {
+        let status = remote_prover.get_status(header_hash).await?;
+        if status.job == Some(ProverJobStatus::Proved) {
+            return Ok(());
+        }
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
+    }
+    anyhow::bail!("The proof of {:?} has not been generated", header_hash)
+}
@@ -8,8 +8,9 @@ use sov_prover_storage_manager::ProverStorageManager;
 use sov_rollup_interface::storage::HierarchicalStorageManager;
 use sov_state::{ArrayWitness, DefaultStorageSpec};
 use sov_stf_runner::{
-    InitVariant, ParallelProverService, ProverServiceConfig, RollupConfig, RollupProverConfig,
-    RpcConfig, RunnerConfig, StateTransitionRunner, StorageConfig,
+    InitVariant, ParallelProverService, ProofSubmissionConfig, ProverServiceConfig, RollupConfig,
+    RollupProverConfig, RpcConfig, RunnerConfig, SequencerConfig, StateTransitionRunner,
+    StorageConfig,
 };
 
 mod hash_stf;
@@ -79,7 +80,11 @@ fn initialize_runner(
         },
         prover_service: ProverServiceConfig {
             aggregated_proof_block_jump: 1,
+            proof_submission: ProofSubmissionConfig::default(),
         },
+        sequencer: SequencerConfig::default(),
+        remote_prover: None,
+        prover_server: None,
     };
 
     let da_service = MockDaService::new(address);
@@ -100,8 +105,11 @@ fn initialize_runner(
 
     let prover_service = ParallelProverService::new(
         vm,
+        None,
         stf.clone(),
         verifier,
+        da_service.clone(),
+        ledger_db.clone(),
         prover_config,
         // Should be ZkStorage, but we don't need it for this test
         storage_manager.create_finalized_storage().unwrap(),
@@ -116,7 +124,7 @@ fn initialize_runner(
         stf,
         storage_manager,
         init_variant,
-        prover_service,
+        Some(prover_service),
     )
     .unwrap()
 }
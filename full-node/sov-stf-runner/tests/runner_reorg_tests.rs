@@ -4,8 +4,9 @@ use sov_mock_da::{
 };
 use sov_mock_zkvm::MockZkvm;
 use sov_stf_runner::{
-    InitVariant, ParallelProverService, ProverServiceConfig, RollupConfig, RollupProverConfig,
-    RpcConfig, RunnerConfig, StateTransitionRunner, StorageConfig,
+    InitVariant, ParallelProverService, ProofSubmissionConfig, ProverServiceConfig, RollupConfig,
+    RollupProverConfig, RpcConfig, RunnerConfig, SequencerConfig, StateTransitionRunner,
+    StorageConfig,
 };
 
 mod hash_stf;
@@ -76,6 +77,64 @@ async fn test_simple_reorg_case() {
     assert_eq!(expected_committed_root_hash.unwrap(), committed_root_hash);
 }
 
+#[tokio::test]
+async fn test_multi_block_reorg_case() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let sequencer_address = MockAddress::new([11u8; 32]);
+    let genesis_params = vec![1, 2, 3, 4, 5];
+
+    let main_chain_blobs = vec![
+        vec![1, 1, 1, 1],
+        vec![2, 2, 2, 2],
+        vec![3, 3, 3, 3],
+        vec![4, 4, 4, 4],
+    ];
+    let fork_blobs = vec![
+        vec![12, 12, 12, 12],
+        vec![13, 13, 13, 13],
+        vec![14, 14, 14, 14],
+        vec![15, 15, 15, 15],
+    ];
+    let expected_final_blobs = vec![
+        vec![1, 1, 1, 1],
+        vec![12, 12, 12, 12],
+        vec![13, 13, 13, 13],
+        vec![14, 14, 14, 14],
+        vec![15, 15, 15, 15],
+    ];
+
+    let mut da_service = MockDaService::with_finality(sequencer_address, 4);
+    da_service.set_wait_attempts(2);
+
+    let genesis_header = da_service.get_last_finalized_block_header().await.unwrap();
+
+    // All the seen blocks but the first one are reverted.
+    let planned_fork = PlannedFork::new(5, 1, fork_blobs.clone());
+    da_service.set_planned_fork(planned_fork).await.unwrap();
+
+    for b in &main_chain_blobs {
+        da_service.send_transaction(b).await.unwrap();
+    }
+
+    let (expected_state_root, _expected_final_root_hash) =
+        get_expected_execution_hash_from(&genesis_params, expected_final_blobs);
+    let (_expected_committed_state_root, expected_committed_root_hash) =
+        get_expected_execution_hash_from(&genesis_params, vec![vec![1, 1, 1, 1]]);
+
+    let init_variant: MockInitVariant = InitVariant::Genesis {
+        block_header: genesis_header,
+        genesis_params,
+    };
+
+    let (before, after) = runner_execution(tmpdir.path(), init_variant, da_service).await;
+    assert_ne!(before, after);
+    assert_eq!(expected_state_root, after);
+
+    let committed_root_hash = get_saved_root_hash(tmpdir.path()).unwrap().unwrap();
+
+    assert_eq!(expected_committed_root_hash.unwrap(), committed_root_hash);
+}
+
 #[tokio::test]
 #[ignore = "TBD"]
 async fn test_several_reorgs() {}
@@ -135,7 +194,11 @@ async fn runner_execution(
         },
         prover_service: ProverServiceConfig {
             aggregated_proof_block_jump: 1,
+            proof_submission: ProofSubmissionConfig::default(),
         },
+        sequencer: SequencerConfig::default(),
+        remote_prover: None,
+        prover_server: None,
     };
 
     let ledger_db = LedgerDB::with_path(path).unwrap();
@@ -153,8 +216,11 @@ async fn runner_execution(
 
     let prover_service = ParallelProverService::new(
         vm,
+        None,
         stf.clone(),
         verifier,
+        da_service.clone(),
+        ledger_db.clone(),
         prover_config,
         // Should be ZkStorage, but we don't need it for this test
         storage_manager.create_finalized_storage().unwrap(),
@@ -166,10 +232,10 @@ async fn runner_execution(
         rollup_config.runner,
         da_service,
         ledger_db,
-        stf,
+        stf.clone(),
         storage_manager,
         init_variant,
-        prover_service,
+        Some(prover_service),
     )
     .unwrap();
 
@@ -178,6 +244,9 @@ async fn runner_execution(
     assert!(end.is_err());
     let after = *runner.get_state_root();
 
+    // Every block, including the ones executed after a reorg, is applied on top of its parent.
+    stf.check_pre_state_roots(&before);
+
     (before, after)
 }
 
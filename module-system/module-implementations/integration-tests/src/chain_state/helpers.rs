@@ -105,6 +105,8 @@ impl<C: Context, Da: DaSpec> Runtime<C, Da> for TestRuntime<C> {
 
     type GenesisPaths = ();
 
+    type Event = ();
+
     fn genesis_config(
         _genesis_paths: &Self::GenesisPaths,
     ) -> Result<Self::GenesisConfig, anyhow::Error> {
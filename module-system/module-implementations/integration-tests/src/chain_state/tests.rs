@@ -1,7 +1,7 @@
 // use sov_chain_state::{StateTransitionId, TransitionInProgress};
 // use sov_data_generators::value_setter_data::ValueSetterMessages;
 // use sov_data_generators::{has_tx_events, new_test_blob_from_batch, MessageGenerator};
-// use sov_mock_da::{MockBlock, MockBlockHeader, MockDaSpec, MockHash, MockValidityCond};
+// use sov_mock_da::{MockAddress, MockBlock, MockBlockHeader, MockDaSpec, MockHash, MockValidityCond};
 // use sov_mock_zkvm::MockZkvm;
 // use sov_modules_api::default_context::DefaultContext;
 // use sov_modules_api::runtime::capabilities::Kernel;
@@ -92,7 +92,10 @@
 //     assert_eq!(1, batch_receipts.len());
 //     let apply_blob_outcome = batch_receipts[0].clone();
 //     assert_eq!(
-//         SequencerOutcome::Rewarded(0),
+//         SequencerOutcome::Rewarded {
+//             reward: 0,
+//             sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+//         },
 //         apply_blob_outcome.inner,
 //         "Sequencer execution should have succeeded but failed "
 //     );
@@ -153,7 +156,10 @@
 //     assert_eq!(1, result.batch_receipts.len());
 //     let apply_blob_outcome = result.batch_receipts[0].clone();
 //     assert_eq!(
-//         SequencerOutcome::Rewarded(0),
+//         SequencerOutcome::Rewarded {
+//             reward: 0,
+//             sequencer_da_address: MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+//         },
 //         apply_blob_outcome.inner,
 //         "Sequencer execution should have succeeded but failed "
 //     );
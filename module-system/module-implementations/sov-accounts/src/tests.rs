@@ -1,5 +1,7 @@
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
+use sov_modules_api::secp256k1_context::Secp256k1Context;
+use sov_modules_api::secp256k1_signature::private_key::Secp256k1PrivateKey;
 use sov_modules_api::{
     AddressBech32, Context, Module, PrivateKey, PublicKey, Spec, StateMapAccessor, WorkingSet,
 };
@@ -205,3 +207,51 @@ fn test_response_deserialization_on_wrong_hrp() {
         }
     }
 }
+
+#[test]
+fn test_update_secp256k1_account() {
+    type C = Secp256k1Context;
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let accounts = &mut Accounts::<C>::default();
+
+    let sender = Secp256k1PrivateKey::generate().pub_key();
+    let sequencer = Secp256k1PrivateKey::generate().pub_key();
+    let sender_addr = sender.to_address::<<C as Spec>::Address>();
+    let sender_context = C::new(sender_addr, sequencer.to_address(), 1);
+
+    accounts
+        .create_default_account(&sender, working_set)
+        .unwrap();
+
+    let priv_key = Secp256k1PrivateKey::generate();
+    let new_pub_key = priv_key.pub_key();
+    let sig = priv_key.sign(&call::UPDATE_ACCOUNT_MSG);
+
+    // A signature of another message is rejected.
+    assert!(accounts
+        .call(
+            call::CallMessage::<C>::UpdatePublicKey(new_pub_key, priv_key.sign(b"other")),
+            &sender_context,
+            working_set,
+        )
+        .is_err());
+
+    accounts
+        .call(
+            call::CallMessage::<C>::UpdatePublicKey(new_pub_key, sig),
+            &sender_context,
+            working_set,
+        )
+        .unwrap();
+
+    let query_response = accounts.get_account(new_pub_key, working_set).unwrap();
+    assert_eq!(
+        query_response,
+        query::Response::AccountExists {
+            addr: AddressBech32::from(&sender_addr),
+            nonce: 0
+        }
+    )
+}
@@ -15,7 +15,7 @@ use sov_modules_api::{
 use sov_state::storage::{Storage, StorageKey, StorageProof, StorageValue};
 use thiserror::Error;
 
-use crate::{AttesterIncentives, UnbondingInfo};
+use crate::{AttesterIncentives, Event, UnbondingInfo};
 
 #[derive(PartialEq, Eq, Clone, Serialize, Deserialize)]
 /// A wrapper for attestations which implements `borsh` serialization. This is necessary since
@@ -195,7 +195,9 @@ pub enum AttesterIncentiveErrors {
     MintFailure,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, BorshDeserialize, BorshSerialize, Serialize, Deserialize,
+)]
 /// A role in the attestation process
 pub enum Role {
     /// A user who attests to new state transitions
@@ -257,7 +259,10 @@ where
         bonded_set.remove(user, working_set);
 
         // We raise an event
-        working_set.add_event("user_slashed", &format!("address {user:?}"));
+        working_set.emit_event::<Self>(Event::UserSlashed {
+            user: user.clone(),
+            role,
+        });
 
         reward
     }
@@ -354,9 +359,9 @@ where
             .transfer_from(user_address, &self.address, coins, working_set)
             .map_err(|_err| AttesterIncentiveErrors::TransferFailure)?;
 
-        let (balances, event_key) = match role {
-            Role::Attester => (&self.bonded_attesters, "bonded_attester"),
-            Role::Challenger => (&self.bonded_challengers, "bonded_challenger"),
+        let balances = match role {
+            Role::Attester => &self.bonded_attesters,
+            Role::Challenger => &self.bonded_challengers,
         };
 
         // Update our record of the total bonded amount for the sender.
@@ -366,10 +371,12 @@ where
         balances.set(user_address, &total_balance, working_set);
 
         // Emit the bonding event
-        working_set.add_event(
-            event_key,
-            &format!("new_deposit: {bond_amount:?}. total_bond: {total_balance:?}"),
-        );
+        working_set.emit_event::<Self>(Event::UserBonded {
+            user: user_address.clone(),
+            role,
+            deposit: bond_amount,
+            total_bond: total_balance,
+        });
 
         Ok(CallResponse::default())
     }
@@ -387,10 +394,10 @@ where
             self.reward_sender(context, old_balance, working_set)?;
 
             // Emit the unbonding event
-            working_set.add_event(
-                "unbonded_challenger",
-                &format!("amount_withdrawn: {old_balance:?}"),
-            );
+            working_set.emit_event::<Self>(Event::ChallengerUnbonded {
+                challenger: context.sender().clone(),
+                amount_withdrawn: old_balance,
+            });
         }
 
         Ok(CallResponse::default())
@@ -466,9 +473,9 @@ where
                 .remove(context.sender(), working_set);
 
             // Emit the unbonding event
-            working_set.add_event("unbonded_challenger", {
-                let amount = unbonding_info.amount;
-                &format!("amount_withdrawn: {:?}", amount)
+            working_set.emit_event::<Self>(Event::AttesterUnbonded {
+                attester: context.sender().clone(),
+                amount_withdrawn: unbonding_info.amount,
             });
         } else {
             return Err(AttesterIncentiveErrors::AttesterIsNotUnbonding);
@@ -726,10 +733,9 @@ where
             working_set,
         )?;
 
-        working_set.add_event(
-            "processed_valid_attestation",
-            &format!("attester: {:?}", context.sender()),
-        );
+        working_set.emit_event::<Self>(Event::AttestationProcessed {
+            attester: context.sender().clone(),
+        });
 
         // Now we have to check whether the claimed_transition_num is the max_attested_height.
         // If so, update the maximum attested height and reward the sender
@@ -873,10 +879,9 @@ where
                 // Now remove the bad transition from the pool
                 self.bad_transition_pool.remove(transition_num, working_set);
 
-                working_set.add_event(
-                    "processed_valid_proof",
-                    &format!("challenger: {:?}", context.sender()),
-                );
+                working_set.emit_event::<Self>(Event::ChallengeProcessed {
+                    challenger: context.sender().clone(),
+                });
             }
             Err(_err) => {
                 // Slash the challenger
@@ -0,0 +1,55 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use sov_bank::Amount;
+use sov_modules_api::EventName;
+
+use crate::Role;
+
+/// The events emitted by the `AttesterIncentives` module.
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, EventName, Debug, PartialEq, Clone,
+)]
+pub enum Event<C: sov_modules_api::Context> {
+    /// A user bonded some tokens.
+    UserBonded {
+        /// The address of the user.
+        user: C::Address,
+        /// The role the user bonded for.
+        role: Role,
+        /// The amount of the new deposit.
+        deposit: Amount,
+        /// The total amount bonded by the user.
+        total_bond: Amount,
+    },
+    /// A challenger unbonded all of its tokens.
+    ChallengerUnbonded {
+        /// The address of the challenger.
+        challenger: C::Address,
+        /// The amount withdrawn by the challenger.
+        amount_withdrawn: Amount,
+    },
+    /// An attester finished the two-phase unbonding.
+    AttesterUnbonded {
+        /// The address of the attester.
+        attester: C::Address,
+        /// The amount withdrawn by the attester.
+        amount_withdrawn: Amount,
+    },
+    /// A user was slashed, and lost its bond.
+    UserSlashed {
+        /// The address of the user.
+        user: C::Address,
+        /// The role the user was slashed for.
+        role: Role,
+    },
+    /// A valid attestation was processed.
+    AttestationProcessed {
+        /// The address of the attester.
+        attester: C::Address,
+    },
+    /// A valid challenge was processed.
+    ChallengeProcessed {
+        /// The address of the challenger.
+        challenger: C::Address,
+    },
+}
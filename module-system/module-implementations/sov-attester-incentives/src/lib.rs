@@ -3,10 +3,13 @@
 
 /// Call methods for the module
 mod call;
+/// Events emitted by the module
+mod event;
 /// Methods used to instantiate the module
 mod genesis;
 
 pub use call::*;
+pub use event::Event;
 pub use genesis::*;
 
 #[cfg(test)]
@@ -132,7 +135,7 @@ where
 
     type CallMessage = call::CallMessage<C, Da>;
 
-    type Event = ();
+    type Event = Event<C>;
 
     fn genesis(&self, config: &Self::Config, working_set: &mut WorkingSet<C>) -> Result<(), Error> {
         // The initialization logic
@@ -106,6 +106,7 @@ pub(crate) fn setup(
 
     // Initialize chain state
     let chain_state_config = sov_chain_state::ChainStateConfig {
+        chain_id: 0,
         initial_slot_height: INIT_HEIGHT,
         current_time: Default::default(),
     };
@@ -0,0 +1,57 @@
+use crate::{Amount, Coins};
+
+/// The events emitted by the sov-bank module, one for each successful call message.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    derive(serde::Deserialize)
+)]
+#[derive(
+    borsh::BorshDeserialize,
+    borsh::BorshSerialize,
+    sov_modules_api::EventName,
+    Debug,
+    PartialEq,
+    Clone,
+)]
+pub enum Event<C: sov_modules_api::Context> {
+    /// A new token was created.
+    TokenCreated {
+        /// The name of the token.
+        token_name: String,
+        /// The address of the token.
+        token_address: C::Address,
+        /// The address the initial balance was minted to.
+        minter_address: C::Address,
+        /// The initial balance of the token.
+        initial_balance: Amount,
+    },
+    /// Tokens were transferred.
+    TokenTransferred {
+        /// The sender of the tokens.
+        from: C::Address,
+        /// The receiver of the tokens.
+        to: C::Address,
+        /// The transferred tokens.
+        coins: Coins<C>,
+    },
+    /// Tokens were burned.
+    TokenBurned {
+        /// The owner of the burned tokens.
+        owner: C::Address,
+        /// The burned tokens.
+        coins: Coins<C>,
+    },
+    /// Tokens were minted.
+    TokenMinted {
+        /// The address the tokens were minted to.
+        minter_address: C::Address,
+        /// The minted tokens.
+        coins: Coins<C>,
+    },
+    /// The supply of a token was frozen.
+    TokenFrozen {
+        /// The address of the token.
+        token_address: C::Address,
+    },
+}
@@ -46,11 +46,9 @@ impl<C: Context> TxHooks for Bank<C> {
         let gas_fixed_cost = tx.gas_fixed_cost();
         if working_set.charge_gas(&gas_fixed_cost).is_err() {
             let amount = gas_fixed_cost.value(working_set.gas_price());
-            let token_address = C::Address::from_str(GAS_TOKEN_ADDRESS)
-                .map_err(|_| anyhow::anyhow!("failed to parse gas token address"))?;
             let coins = Coins {
                 amount,
-                token_address,
+                token_address: gas_token_address::<C>()?,
             };
 
             // If the sender's account balance is insufficient to cover the base global cost, the
@@ -66,17 +64,15 @@ impl<C: Context> TxHooks for Bank<C> {
             );
         }
 
+        // Escrow the maximum amount the sender is willing to pay for the transaction. The funds
+        // are settled by the `post_dispatch_tx_hook` and the sequencer registry.
         let amount = tx.gas_limit().saturating_add(tx.gas_tip());
         if amount > 0 {
-            let token_address = C::Address::from_str(GAS_TOKEN_ADDRESS)
-                .map_err(|_| anyhow::anyhow!("failed to parse gas token address"))?;
-            let from = sender;
-            let to = sequencer;
             let coins = Coins {
                 amount,
-                token_address,
+                token_address: gas_token_address::<C>()?,
             };
-            self.transfer_from(from, to, coins, working_set)?;
+            self.transfer_from(sender, &self.address, coins, working_set)?;
         }
 
         Ok(())
@@ -84,24 +80,74 @@ impl<C: Context> TxHooks for Bank<C> {
 
     fn post_dispatch_tx_hook(
         &self,
-        _tx: &Transaction<Self::Context>,
+        tx: &Transaction<Self::Context>,
         ctx: &C,
         working_set: &mut WorkingSet<C>,
     ) -> anyhow::Result<()> {
-        let amount = working_set.gas_remaining_funds();
+        let remaining = working_set.gas_remaining_funds();
+        let consumed = tx.gas_limit().saturating_sub(remaining);
 
+        // Refund the unused gas to the sender.
+        if remaining > 0 {
+            let coins = Coins {
+                amount: remaining,
+                token_address: gas_token_address::<C>()?,
+            };
+            self.transfer_from(&self.address, ctx.sender(), coins, working_set)?;
+        }
+
+        // The consumed gas is priced with the base fee, which is burned.
+        if consumed > 0 {
+            let coins = Coins {
+                amount: consumed,
+                token_address: gas_token_address::<C>()?,
+            };
+            self.burn(coins, &self.address, working_set)?;
+        }
+
+        // The tip stays in escrow until it is paid to the sequencer with `Bank::pay_gas_tip`.
+        Ok(())
+    }
+}
+
+impl<C: Context> Bank<C> {
+    /// Returns the address holding the gas funds escrowed by the transactions until they are settled.
+    pub fn gas_escrow_address(&self) -> &C::Address {
+        &self.address
+    }
+
+    /// Pays `amount` of gas tokens, collected as transaction tips, from the gas escrow to `to`.
+    pub fn pay_gas_tip(
+        &self,
+        to: &C::Address,
+        amount: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<()> {
         if amount > 0 {
-            let token_address = C::Address::from_str(GAS_TOKEN_ADDRESS)
-                .map_err(|_| anyhow::anyhow!("failed to parse gas token address"))?;
-            let from = ctx.sequencer();
-            let to = ctx.sender();
             let coins = Coins {
                 amount,
-                token_address,
+                token_address: gas_token_address::<C>()?,
             };
-            self.transfer_from(from, to, coins, working_set)?;
+            self.transfer_from(&self.address, to, coins, working_set)?;
         }
+        Ok(())
+    }
 
+    /// Burns `amount` of gas tokens, collected as transaction tips, from the gas escrow.
+    /// Used when the tips can't be paid to the sequencer, so that they don't stay in the escrow.
+    pub fn burn_gas_tip(&self, amount: u64, working_set: &mut WorkingSet<C>) -> anyhow::Result<()> {
+        if amount > 0 {
+            let coins = Coins {
+                amount,
+                token_address: gas_token_address::<C>()?,
+            };
+            self.burn(coins, &self.address, working_set)?;
+        }
         Ok(())
     }
 }
+
+fn gas_token_address<C: Context>() -> anyhow::Result<C::Address> {
+    C::Address::from_str(GAS_TOKEN_ADDRESS)
+        .map_err(|_| anyhow::anyhow!("failed to parse gas token address"))
+}
@@ -1,6 +1,7 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 mod call;
+mod event;
 mod genesis;
 mod hooks;
 #[cfg(feature = "native")]
@@ -11,6 +12,7 @@ mod token;
 /// Util functions for bank
 pub mod utils;
 pub use call::*;
+pub use event::Event;
 pub use genesis::*;
 pub use hooks::BankTxHook;
 use sov_modules_api::{CallResponse, Error, GasUnit, ModuleInfo, WorkingSet};
@@ -66,7 +68,7 @@ impl<C: sov_modules_api::Context> sov_modules_api::Module for Bank<C> {
 
     type CallMessage = call::CallMessage<C>;
 
-    type Event = ();
+    type Event = Event<C>;
 
     fn genesis(&self, config: &Self::Config, working_set: &mut WorkingSet<C>) -> Result<(), Error> {
         Ok(self.init_module(config, working_set)?)
@@ -87,26 +89,43 @@ impl<C: sov_modules_api::Context> sov_modules_api::Module for Bank<C> {
                 authorized_minters,
             } => {
                 self.charge_gas(working_set, &self.gas.create_token)?;
-                self.create_token(
-                    token_name,
+                let token_address = self.create_token(
+                    token_name.clone(),
                     salt,
                     initial_balance,
-                    minter_address,
+                    minter_address.clone(),
                     authorized_minters,
                     context,
                     working_set,
                 )?;
+                working_set.emit_event::<Self>(Event::TokenCreated {
+                    token_name,
+                    token_address,
+                    minter_address,
+                    initial_balance,
+                });
                 Ok(CallResponse::default())
             }
 
             call::CallMessage::Transfer { to, coins } => {
                 self.charge_gas(working_set, &self.gas.create_token)?;
-                Ok(self.transfer(to, coins, context, working_set)?)
+                let response = self.transfer(to.clone(), coins.clone(), context, working_set)?;
+                working_set.emit_event::<Self>(Event::TokenTransferred {
+                    from: context.sender().clone(),
+                    to,
+                    coins,
+                });
+                Ok(response)
             }
 
             call::CallMessage::Burn { coins } => {
                 self.charge_gas(working_set, &self.gas.burn)?;
-                Ok(self.burn_from_eoa(coins, context, working_set)?)
+                let response = self.burn_from_eoa(coins.clone(), context, working_set)?;
+                working_set.emit_event::<Self>(Event::TokenBurned {
+                    owner: context.sender().clone(),
+                    coins,
+                });
+                Ok(response)
             }
 
             call::CallMessage::Mint {
@@ -115,12 +134,18 @@ impl<C: sov_modules_api::Context> sov_modules_api::Module for Bank<C> {
             } => {
                 self.charge_gas(working_set, &self.gas.mint)?;
                 self.mint_from_eoa(&coins, &minter_address, context, working_set)?;
+                working_set.emit_event::<Self>(Event::TokenMinted {
+                    minter_address,
+                    coins,
+                });
                 Ok(CallResponse::default())
             }
 
             call::CallMessage::Freeze { token_address } => {
                 self.charge_gas(working_set, &self.gas.freeze)?;
-                Ok(self.freeze(token_address, context, working_set)?)
+                let response = self.freeze(token_address.clone(), context, working_set)?;
+                working_set.emit_event::<Self>(Event::TokenFrozen { token_address });
+                Ok(response)
             }
         }
     }
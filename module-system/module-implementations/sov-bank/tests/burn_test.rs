@@ -1,6 +1,6 @@
-use helpers::{generate_address, C};
+use helpers::{generate_address, take_bank_events, C};
 use sov_bank::{
-    get_genesis_token_address, get_token_address, Bank, BankConfig, CallMessage, Coins,
+    get_genesis_token_address, get_token_address, Bank, BankConfig, CallMessage, Coins, Event,
     TotalSupplyResponse,
 };
 use sov_modules_api::default_context::DefaultContext;
@@ -37,15 +37,22 @@ fn burn_deployed_tokens() {
     // Deploying token
     let mint_message = CallMessage::CreateToken {
         salt,
-        token_name,
+        token_name: token_name.clone(),
         initial_balance,
         minter_address,
         authorized_minters: vec![minter_address],
     };
     bank.call(mint_message, &minter_context, &mut working_set)
         .expect("Failed to mint token");
-    // No events at the moment. If there are, needs to be checked
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenCreated {
+            token_name,
+            token_address,
+            minter_address,
+            initial_balance,
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     let query_total_supply = |working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
         let total_supply: TotalSupplyResponse =
@@ -73,7 +80,16 @@ fn burn_deployed_tokens() {
 
     bank.call(burn_message.clone(), &minter_context, &mut working_set)
         .expect("Failed to burn token");
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenBurned {
+            owner: minter_address,
+            coins: Coins {
+                amount: burn_amount,
+                token_address,
+            },
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     let current_total_supply = query_total_supply(&mut working_set);
     assert_eq!(Some(initial_balance - burn_amount), current_total_supply);
@@ -119,7 +135,7 @@ fn burn_deployed_tokens() {
 
     bank.call(burn_zero_message, &minter_context, &mut working_set)
         .expect("Failed to burn token");
-    assert!(working_set.events().is_empty());
+    assert_eq!(1, take_bank_events(&mut working_set).len());
     let minter_balance_after = query_user_balance(minter_address, &mut working_set);
     assert_eq!(minter_balance, minter_balance_after);
 
@@ -218,7 +234,16 @@ fn burn_initial_tokens() {
     let context = C::new(sender_address, sequencer_address, 1);
     bank.call(burn_message, &context, &mut working_set)
         .expect("Failed to burn token");
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenBurned {
+            owner: sender_address,
+            coins: Coins {
+                amount: burn_amount,
+                token_address,
+            },
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     let balance_after = query_user_balance(sender_address, &mut working_set);
     assert_eq!(Some(initial_balance - burn_amount), balance_after);
@@ -1,5 +1,5 @@
 use helpers::*;
-use sov_bank::{get_token_address, Bank, CallMessage};
+use sov_bank::{get_token_address, Bank, CallMessage, Event};
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Context, Module, WorkingSet};
 use sov_prover_storage_manager::new_orphan_storage;
@@ -33,7 +33,15 @@ fn initial_and_deployed_token() {
     bank.call(create_token_message, &sender_context, &mut working_set)
         .expect("Failed to create token");
 
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenCreated {
+            token_name: token_name.clone(),
+            token_address,
+            minter_address,
+            initial_balance,
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     let sender_balance = bank.get_balance_of(sender_address, token_address, &mut working_set);
     assert!(sender_balance.is_none());
@@ -1,5 +1,7 @@
-use helpers::C;
-use sov_bank::{get_token_address, Bank, BankConfig, CallMessage, Coins, TotalSupplyResponse};
+use helpers::{take_bank_events, C};
+use sov_bank::{
+    get_token_address, Bank, BankConfig, CallMessage, Coins, Event, TotalSupplyResponse,
+};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Address, Context, Error, Module, WorkingSet};
@@ -39,8 +41,15 @@ fn freeze_token() {
     let _minted = bank
         .call(mint_message, &minter_context, &mut working_set)
         .expect("Failed to mint token");
-    // No events at the moment. If there are, needs to be checked
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenCreated {
+            token_name: token_name.clone(),
+            token_address,
+            minter_address,
+            initial_balance,
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     // -----
     // Freeze
@@ -49,7 +58,10 @@ fn freeze_token() {
     let _freeze = bank
         .call(freeze_message, &minter_context, &mut working_set)
         .expect("Failed to freeze token");
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenFrozen { token_address }],
+        take_bank_events(&mut working_set)
+    );
 
     // ----
     // Try to freeze an already frozen token
@@ -88,8 +100,15 @@ fn freeze_token() {
     let _minted = bank
         .call(mint_message, &minter_context, &mut working_set)
         .expect("Failed to mint token");
-    // No events at the moment. If there are, needs to be checked
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenCreated {
+            token_name: token_name_2.clone(),
+            token_address: token_address_2,
+            minter_address,
+            initial_balance,
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     // Try to freeze with a non authorized minter
     let unauthorized_address = generate_address::<C>("unauthorized_address");
@@ -173,7 +192,16 @@ fn freeze_token() {
     let _minted = bank
         .call(mint_message, &minter_context, &mut working_set)
         .expect("Failed to mint token");
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenMinted {
+            minter_address,
+            coins: Coins {
+                amount: mint_amount,
+                token_address: token_address_2,
+            },
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     let total_supply = query_total_supply(token_address_2, &mut working_set);
     assert_eq!(Some(initial_balance + mint_amount), total_supply);
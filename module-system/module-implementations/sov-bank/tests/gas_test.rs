@@ -1,10 +1,14 @@
 use helpers::*;
 use sov_bank::{
-    get_genesis_token_address, Bank, BankConfig, BankGasConfig, CallMessage, TokenConfig,
+    get_genesis_token_address, Bank, BankConfig, BankGasConfig, BankTxHook, CallMessage,
+    TokenConfig,
 };
+use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
+use sov_modules_api::hooks::TxHooks;
 use sov_modules_api::macros::config_constant;
+use sov_modules_api::transaction::Transaction;
 use sov_modules_api::utils::generate_address;
-use sov_modules_api::{Context, GasUnit, Module, WorkingSet};
+use sov_modules_api::{Context, GasUnit, Module, PrivateKey, WorkingSet};
 use sov_prover_storage_manager::new_orphan_storage;
 use tempfile::TempDir;
 
@@ -101,6 +105,81 @@ fn very_high_gas_price_wont_panic_or_overflow() {
     assert!(result.is_err(), "arithmetic overflow shoulnd't panic");
 }
 
+#[test]
+fn gas_is_escrowed_refunded_burned_and_tip_paid() {
+    let sender_balance = 100;
+    let gas_limit = 50;
+    let gas_tip = 5;
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut ws = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+
+    let sender_key = DefaultPrivateKey::generate();
+    let sender_address = sender_key.to_address::<<C as sov_modules_api::Spec>::Address>();
+    let sequencer_address = generate_address::<C>("sequencer");
+    let gas_token_address = get_genesis_token_address::<C>("sov-gas-token", 0);
+
+    let bank = Bank::<C>::default();
+    let bank_config: BankConfig<C> = BankConfig {
+        tokens: vec![TokenConfig {
+            token_name: "sov-gas-token".to_string(),
+            address_and_balances: vec![(sender_address, sender_balance)],
+            authorized_minters: vec![],
+            salt: 0,
+        }],
+    };
+    bank.genesis(&bank_config, &mut ws).unwrap();
+
+    let tx = Transaction::<C>::new_signed_tx(&sender_key, vec![], 0, gas_tip, gas_limit, 0);
+    let hook = BankTxHook {
+        sender: sender_address,
+        sequencer: sequencer_address,
+    };
+    ws.set_gas(gas_limit, [1, 1]);
+    bank.pre_dispatch_tx_hook(&tx, &mut ws, &hook).unwrap();
+
+    let escrow = *bank.gas_escrow_address();
+    assert_eq!(
+        bank.get_balance_of(escrow, gas_token_address, &mut ws),
+        Some(gas_limit + gas_tip)
+    );
+    assert_eq!(
+        bank.get_balance_of(sender_address, gas_token_address, &mut ws),
+        Some(sender_balance - gas_limit - gas_tip)
+    );
+
+    // Simulate the execution of the transaction
+    let consumed = 20;
+    ws.charge_gas(&<C as Context>::GasUnit::from_arbitrary_dimensions(&[
+        10, 10,
+    ]))
+    .unwrap();
+
+    let ctx = C::new(sender_address, sequencer_address, 1);
+    bank.post_dispatch_tx_hook(&tx, &ctx, &mut ws).unwrap();
+
+    // The unused gas is refunded, the consumed gas is burned and the tip stays in escrow
+    assert_eq!(
+        bank.get_balance_of(sender_address, gas_token_address, &mut ws),
+        Some(sender_balance - consumed - gas_tip)
+    );
+    assert_eq!(
+        bank.get_balance_of(escrow, gas_token_address, &mut ws),
+        Some(gas_tip)
+    );
+
+    bank.pay_gas_tip(&sequencer_address, gas_tip, &mut ws)
+        .unwrap();
+    assert_eq!(
+        bank.get_balance_of(sequencer_address, gas_token_address, &mut ws),
+        Some(gas_tip)
+    );
+    assert_eq!(
+        bank.get_balance_of(escrow, gas_token_address, &mut ws),
+        Some(0)
+    );
+}
+
 #[allow(dead_code)]
 pub struct BankGasTestCase {
     ws: WorkingSet<C>,
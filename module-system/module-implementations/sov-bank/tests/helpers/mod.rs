@@ -1,7 +1,8 @@
-use sov_bank::{BankConfig, TokenConfig};
+use borsh::BorshDeserialize;
+use sov_bank::{Bank, BankConfig, Event, TokenConfig};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address as gen_address_generic;
-use sov_modules_api::Address;
+use sov_modules_api::{Address, EventName, ModuleInfo, WorkingSet};
 
 pub type C = DefaultContext;
 
@@ -36,3 +37,21 @@ pub fn create_bank_config_with_token(
         tokens: vec![token_config],
     }
 }
+
+/// Takes the events emitted so far, and decodes them as events of the bank module.
+#[allow(dead_code)]
+pub fn take_bank_events(working_set: &mut WorkingSet<C>) -> Vec<Event<C>> {
+    let bank_prefix = Bank::<C>::module_prefix().to_bytes();
+    working_set
+        .take_events()
+        .iter()
+        .map(|event| {
+            let decoded: Event<C> =
+                Event::try_from_slice(event.value().inner()).expect("Invalid bank event");
+            let mut key = bank_prefix.clone();
+            key.extend_from_slice(decoded.event_name().as_bytes());
+            assert_eq!(&key, event.key().inner());
+            decoded
+        })
+        .collect()
+}
@@ -1,5 +1,7 @@
-use helpers::C;
-use sov_bank::{get_token_address, Bank, BankConfig, CallMessage, Coins, TotalSupplyResponse};
+use helpers::{take_bank_events, C};
+use sov_bank::{
+    get_token_address, Bank, BankConfig, CallMessage, Coins, Event, TotalSupplyResponse,
+};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Address, Context, Error, Module, WorkingSet};
@@ -39,8 +41,15 @@ fn mint_token() {
     let _minted = bank
         .call(mint_message, &minter_context, &mut working_set)
         .expect("Failed to mint token");
-    // No events at the moment. If there are, needs to be checked
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenCreated {
+            token_name: token_name.clone(),
+            token_address,
+            minter_address,
+            initial_balance,
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     let query_total_supply =
         |token_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<u64> {
@@ -72,7 +81,16 @@ fn mint_token() {
     let _minted = bank
         .call(mint_message.clone(), &minter_context, &mut working_set)
         .expect("Failed to mint token");
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenMinted {
+            minter_address: new_holder,
+            coins: Coins {
+                amount: mint_amount,
+                token_address,
+            },
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     let total_supply = query_total_supply(token_address, &mut working_set);
     assert_eq!(Some(initial_balance + mint_amount), total_supply);
@@ -135,8 +153,15 @@ fn mint_token() {
     let _minted = bank
         .call(mint_message, &minter_context, &mut working_set)
         .expect("Failed to mint token");
-    // No events at the moment. If there are, needs to be checked
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenCreated {
+            token_name: token_name.clone(),
+            token_address,
+            minter_address,
+            initial_balance,
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     // Try to mint new token with original token creator, in this case minter_context
     let mint_amount = 10;
@@ -185,7 +210,16 @@ fn mint_token() {
         .call(mint_message, &authorized_minter_2_context, &mut working_set)
         .expect("Failed to mint token");
     let supply = query_total_supply(token_address, &mut working_set);
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenMinted {
+            minter_address: new_holder,
+            coins: Coins {
+                amount: mint_amount,
+                token_address,
+            },
+        }],
+        take_bank_events(&mut working_set)
+    );
     assert_eq!(Some(110), supply);
 
     // Try to mint new token with authorized sender 1
@@ -202,7 +236,16 @@ fn mint_token() {
         .call(mint_message, &authorized_minter_1_context, &mut working_set)
         .expect("Failed to mint token");
     let supply = query_total_supply(token_address, &mut working_set);
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenMinted {
+            minter_address: new_holder,
+            coins: Coins {
+                amount: mint_amount,
+                token_address,
+            },
+        }],
+        take_bank_events(&mut working_set)
+    );
     assert_eq!(Some(120), supply);
 
     // Overflow test - account balance
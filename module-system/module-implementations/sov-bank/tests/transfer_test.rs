@@ -2,7 +2,7 @@ mod helpers;
 
 use helpers::*;
 use sov_bank::{
-    get_genesis_token_address, get_token_address, Bank, BankConfig, CallMessage, Coins,
+    get_genesis_token_address, get_token_address, Bank, BankConfig, CallMessage, Coins, Event,
     TotalSupplyResponse,
 };
 use sov_modules_api::default_context::DefaultContext;
@@ -66,7 +66,17 @@ fn transfer_initial_token() {
 
         bank.call(transfer_message, &sender_context, &mut working_set)
             .expect("Transfer call failed");
-        assert!(working_set.events().is_empty());
+        assert_eq!(
+            vec![Event::TokenTransferred {
+                from: sender_address,
+                to: receiver_address,
+                coins: Coins {
+                    amount: transfer_amount,
+                    token_address,
+                },
+            }],
+            take_bank_events(&mut working_set)
+        );
 
         let sender_balance_after = query_user_balance(sender_address, &mut working_set);
         let receiver_balance_after = query_user_balance(receiver_address, &mut working_set);
@@ -227,7 +237,17 @@ fn transfer_initial_token() {
 
         bank.call(transfer_message, &sender_context, &mut working_set)
             .expect("Transfer call failed");
-        assert!(working_set.events().is_empty());
+        assert_eq!(
+            vec![Event::TokenTransferred {
+                from: sender_address,
+                to: unknown_receiver,
+                coins: Coins {
+                    amount: 1,
+                    token_address,
+                },
+            }],
+            take_bank_events(&mut working_set)
+        );
 
         let receiver_balance_after = query_user_balance(unknown_receiver, &mut working_set);
         assert_eq!(Some(1), receiver_balance_after)
@@ -248,7 +268,17 @@ fn transfer_initial_token() {
         };
         bank.call(transfer_message, &sender_context, &mut working_set)
             .expect("Transfer call failed");
-        assert!(working_set.events().is_empty());
+        assert_eq!(
+            vec![Event::TokenTransferred {
+                from: sender_address,
+                to: sender_address,
+                coins: Coins {
+                    amount: 1,
+                    token_address,
+                },
+            }],
+            take_bank_events(&mut working_set)
+        );
 
         let sender_balance_after = query_user_balance(sender_address, &mut working_set);
         assert_eq!(sender_balance_before, sender_balance_after);
@@ -299,15 +329,22 @@ fn transfer_deployed_token() {
 
     let mint_message = CallMessage::CreateToken {
         salt,
-        token_name,
+        token_name: token_name.clone(),
         initial_balance,
         minter_address: sender_address,
         authorized_minters: vec![sender_address],
     };
     bank.call(mint_message, &sender_context, &mut working_set)
         .expect("Failed to mint token");
-    // No events at the moment. If there are, needs to be checked
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenCreated {
+            token_name,
+            token_address,
+            minter_address: sender_address,
+            initial_balance,
+        }],
+        take_bank_events(&mut working_set)
+    );
     let total_supply_before = query_total_supply(&mut working_set);
     assert!(total_supply_before.is_some());
 
@@ -328,7 +365,17 @@ fn transfer_deployed_token() {
 
     bank.call(transfer_message, &sender_context, &mut working_set)
         .expect("Transfer call failed");
-    assert!(working_set.events().is_empty());
+    assert_eq!(
+        vec![Event::TokenTransferred {
+            from: sender_address,
+            to: receiver_address,
+            coins: Coins {
+                amount: transfer_amount,
+                token_address,
+            },
+        }],
+        take_bank_events(&mut working_set)
+    );
 
     let sender_balance_after = query_user_balance(sender_address, &mut working_set);
     let receiver_balance_after = query_user_balance(receiver_address, &mut working_set);
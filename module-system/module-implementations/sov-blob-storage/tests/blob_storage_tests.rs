@@ -17,6 +17,7 @@ fn empty_test() {
     let chain_state = ChainState::<C, Da>::default();
     let initial_slot_height = 1;
     let chain_state_config = ChainStateConfig {
+        chain_id: 0,
         initial_slot_height,
         current_time: Default::default(),
     };
@@ -40,6 +41,7 @@ fn store_and_retrieve_standard() {
     let chain_state = ChainState::<C, Da>::default();
     let initial_slot_height = 1;
     let chain_state_config = ChainStateConfig {
+        chain_id: 0,
         initial_slot_height,
         current_time: Default::default(),
     };
@@ -1,9 +1,12 @@
 use sov_modules_api::prelude::*;
-use sov_modules_api::WorkingSet;
+use sov_modules_api::{GasUnit, WorkingSet};
 use sov_state::storage::KernelWorkingSet;
 use sov_state::Storage;
 
-use crate::{ChainState, StateTransitionId, TransitionHeight};
+use crate::{
+    ChainState, StateTransitionId, TransitionHeight, BASE_FEE_MAX_CHANGE_DENOMINATOR,
+    GAS_TARGET_PER_SLOT,
+};
 
 impl<C, Da> ChainState<C, Da>
 where
@@ -27,4 +30,67 @@ where
         self.historical_transitions
             .set(&height, &transition, working_set);
     }
+
+    /// Adjust the base fee for the next slot, given the gas consumed during the current one
+    pub(crate) fn update_base_fee_per_gas(
+        &self,
+        gas_used: &C::GasUnit,
+        working_set: &mut WorkingSet<C>,
+    ) {
+        let base_fee = self
+            .base_fee_per_gas
+            .get(working_set)
+            .expect("Base fee per gas must be set at genesis");
+
+        let next_base_fee = compute_next_base_fee(
+            &base_fee,
+            &gas_used.to_dimensions(),
+            GAS_TARGET_PER_SLOT,
+            BASE_FEE_MAX_CHANGE_DENOMINATOR,
+        );
+
+        self.base_fee_per_gas.set(&next_base_fee, working_set);
+    }
+}
+
+/// Computes the base fee of the next slot following the EIP-1559 update rule, applied
+/// independently to every gas dimension:
+/// - if the gas used is above the target, the base fee increases by at least one unit;
+/// - if the gas used is below the target, the base fee decreases;
+/// - the relative change is bounded by `1 / max_change_denominator`.
+///
+/// Dimensions with a zero target keep their base fee unchanged.
+pub(crate) fn compute_next_base_fee(
+    base_fee: &[u64],
+    gas_used: &[u64],
+    gas_target: &[u64],
+    max_change_denominator: u64,
+) -> Vec<u64> {
+    let gas_used = gas_used.iter().copied().chain(core::iter::repeat(0));
+    let gas_target = gas_target.iter().copied().chain(core::iter::repeat(0));
+
+    base_fee
+        .iter()
+        .copied()
+        .zip(gas_used.zip(gas_target))
+        .map(|(base_fee, (used, target))| {
+            if target == 0 || max_change_denominator == 0 || used == target {
+                return base_fee;
+            }
+
+            // Use 128 bits integers to avoid overflowing in the intermediate products.
+            let delta = |gas_delta: u64| -> u64 {
+                let delta = u128::from(base_fee) * u128::from(gas_delta)
+                    / u128::from(target)
+                    / u128::from(max_change_denominator);
+                u64::try_from(delta).unwrap_or(u64::MAX)
+            };
+
+            if used > target {
+                base_fee.saturating_add(delta(used - target).max(1))
+            } else {
+                base_fee.saturating_sub(delta(target - used))
+            }
+        })
+        .collect()
 }
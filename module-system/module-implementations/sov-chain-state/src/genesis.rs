@@ -3,11 +3,13 @@ use serde::{Deserialize, Serialize};
 use sov_modules_api::da::Time;
 use sov_modules_api::{StateValueAccessor, WorkingSet};
 
-use crate::{ChainState, TransitionHeight};
+use crate::{ChainState, TransitionHeight, INITIAL_BASE_FEE_PER_GAS};
 
 /// Initial configuration of the chain state
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub struct ChainStateConfig {
+    /// The ID of the chain. Transactions signed for a different chain are rejected.
+    pub chain_id: u64,
     /// Initial slot height
     pub initial_slot_height: TransitionHeight,
     /// The time at genesis
@@ -20,12 +22,17 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainState<C, Da>
         config: &<Self as sov_modules_api::KernelModule>::Config,
         working_set: &mut WorkingSet<C>,
     ) -> Result<()> {
+        self.chain_id.set(&config.chain_id, working_set);
+
         self.genesis_height
             .set(&config.initial_slot_height, working_set);
 
         self.true_height
             .set(&config.initial_slot_height, working_set);
 
+        self.base_fee_per_gas
+            .set(&INITIAL_BASE_FEE_PER_GAS.to_vec(), working_set);
+
         self.time.set_genesis(&config.current_time, working_set);
         Ok(())
     }
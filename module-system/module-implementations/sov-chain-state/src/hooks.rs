@@ -56,8 +56,11 @@ impl<C: Context, Da: sov_modules_api::DaSpec> ChainState<C, Da> {
         );
     }
 
-    /// Update the chain state at the end of each slot, if necessary
-    pub fn end_slot_hook(&self, _working_set: &mut KernelWorkingSet<C>) {}
+    /// Update the chain state at the end of each slot, if necessary.
+    /// Adjusts the base fee of the next slot given the gas consumed by the current one.
+    pub fn end_slot_hook(&self, gas_used: &C::GasUnit, working_set: &mut KernelWorkingSet<C>) {
+        self.update_base_fee_per_gas(gas_used, working_set.inner);
+    }
 }
 
 impl<C: Context, Da: sov_modules_api::DaSpec> FinalizeHook<Da> for ChainState<C, Da> {
@@ -21,8 +21,11 @@ pub use query::*;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use sov_modules_api::da::Time;
+use sov_modules_api::macros::config_constant;
 use sov_modules_api::prelude::*;
-use sov_modules_api::{DaSpec, Error, KernelModuleInfo, ValidityConditionChecker, WorkingSet};
+use sov_modules_api::{
+    DaSpec, Error, GasUnit, KernelModuleInfo, ValidityConditionChecker, WorkingSet,
+};
 use sov_state::codec::BcsCodec;
 use sov_state::storage::kernel_state::VersionReader;
 use sov_state::storage::KernelWorkingSet;
@@ -31,6 +34,20 @@ use sov_state::Storage;
 /// Type alias that contains the height of a given transition
 pub type TransitionHeight = u64;
 
+/// The base fee per gas unit used for the first slot after genesis.
+#[config_constant]
+pub const INITIAL_BASE_FEE_PER_GAS: &[u64];
+
+/// The amount of gas a slot is expected to consume. If a slot consumes more gas than this
+/// target, the base fee increases; if it consumes less, the base fee decreases.
+#[config_constant]
+pub const GAS_TARGET_PER_SLOT: &[u64];
+
+/// Bounds the amount by which the base fee can change between two consecutive slots.
+/// With a denominator of `8`, the base fee changes by at most 12.5% per slot.
+#[config_constant]
+pub const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64;
+
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 /// Structure that contains the information needed to represent a single state transition.
 pub struct StateTransitionId<Da: DaSpec, StateRoot> {
@@ -117,6 +134,10 @@ pub struct ChainState<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec>
     #[address]
     address: C::Address,
 
+    /// The ID of the chain, set at genesis. Used to prevent replaying transactions across chains.
+    #[state]
+    chain_id: sov_modules_api::StateValue<u64>,
+
     /// The current block height
     // We use a standard StateValue here instead of a `KernelStateValue` to avoid a chicken-and-egg problem.
     // You need to load the current visible_height in order to create a `KernelWorkingSet`, which is itself
@@ -130,6 +151,11 @@ pub struct ChainState<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec>
     #[state]
     true_height: sov_modules_api::StateValue<TransitionHeight>,
 
+    /// The base fee per gas unit, for each gas dimension, applied to the transactions of the current slot.
+    /// Updated at the end of each slot based on the gas consumed in that slot.
+    #[state]
+    base_fee_per_gas: sov_modules_api::StateValue<Vec<u64>>,
+
     /// The current time, as reported by the DA layer
     #[state]
     time: sov_modules_api::VersionedStateValue<Time>,
@@ -174,6 +200,22 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainState<C, Da>
         self.visible_height.get(working_set).unwrap_or_default()
     }
 
+    /// Returns the ID of the chain, as configured at genesis
+    pub fn get_chain_id(&self, working_set: &mut WorkingSet<C>) -> u64 {
+        self.chain_id
+            .get(working_set)
+            .expect("Chain id must be set at genesis")
+    }
+
+    /// Returns the base fee per gas unit that applies to the transactions of the current slot
+    pub fn get_base_fee_per_gas(&self, working_set: &mut WorkingSet<C>) -> C::GasUnit {
+        let base_fee = self
+            .base_fee_per_gas
+            .get(working_set)
+            .expect("Base fee per gas must be set at genesis");
+        C::GasUnit::from_arbitrary_dimensions(&base_fee)
+    }
+
     /// Returns the current time, as reported by the DA layer
     pub fn get_time(&self, working_set: &mut impl VersionReader) -> Time {
         self.time
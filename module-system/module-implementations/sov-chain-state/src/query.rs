@@ -1,11 +1,23 @@
-// use jsonrpsee::core::RpcResult;
+use jsonrpsee::core::RpcResult;
 use sov_modules_api::macros::rpc_gen;
+use sov_modules_api::{GasUnit, WorkingSet};
 
-// use sov_modules_api::WorkingSet;
 use crate::ChainState;
 
 #[rpc_gen(client, server, namespace = "chainState")]
 impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainState<C, Da> {
+    /// Get the ID of the chain, which must be included in every signed transaction.
+    #[rpc_method(name = "getChainId")]
+    pub fn get_chain_id_rpc(&self, working_set: &mut WorkingSet<C>) -> RpcResult<u64> {
+        Ok(self.get_chain_id(working_set))
+    }
+
+    /// Get the base fee per gas unit, for each gas dimension, applied to the transactions of the current slot.
+    #[rpc_method(name = "getBaseFeePerGas")]
+    pub fn get_base_fee_per_gas_rpc(&self, working_set: &mut WorkingSet<C>) -> RpcResult<Vec<u64>> {
+        Ok(self.get_base_fee_per_gas(working_set).to_dimensions())
+    }
+
     // TODO: Re-enable this RPC method once the `KernelWorkingSet` type is removed
     // /// Get the height of the current slot.
     // /// Panics if the slot height is not set
@@ -17,3 +29,39 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainState<C, Da>
     //     Ok(self.get_slot_height(working_set))
     // }
 }
+
+/// Storage handle used to serve the [`ChainState`] rpc methods.
+///
+/// The chain state is a kernel module, so it is not part of the runtime and its rpc methods
+/// are not registered by `expose_rpc`.
+struct ChainStateRpcStorage<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> {
+    storage: C::Storage,
+    // Function pointers are always Send + Sync, regardless of whether the return type is.
+    _phantom: std::marker::PhantomData<fn() -> Da>,
+}
+
+impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainStateRpcImpl<C, Da>
+    for ChainStateRpcStorage<C, Da>
+{
+    fn get_working_set(&self) -> WorkingSet<C> {
+        WorkingSet::new(self.storage.clone())
+    }
+}
+
+/// Returns a [`jsonrpsee::RpcModule`] with all the rpc methods exposed by the [`ChainState`] module.
+pub fn get_chain_state_rpc_methods<C, Da>(storage: C::Storage) -> jsonrpsee::RpcModule<()>
+where
+    C: sov_modules_api::Context,
+    Da: sov_modules_api::DaSpec,
+{
+    let mut module = jsonrpsee::RpcModule::new(());
+    module
+        .merge(ChainStateRpcServer::<C, Da>::into_rpc(
+            ChainStateRpcStorage::<C, Da> {
+                storage,
+                _phantom: std::marker::PhantomData,
+            },
+        ))
+        .expect("The chain state rpc methods must not conflict with each other");
+    module
+}
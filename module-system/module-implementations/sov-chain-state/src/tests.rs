@@ -1,17 +1,20 @@
 use sov_modules_api::da::{NanoSeconds, Time};
 
+use crate::call::compute_next_base_fee;
 use crate::ChainStateConfig;
 
 #[test]
 fn test_config_serialization() {
     let time = Time::new(2, NanoSeconds::new(3).unwrap());
     let config = ChainStateConfig {
+        chain_id: 5,
         initial_slot_height: 1,
         current_time: time,
     };
 
     let data = r#"
     {
+        "chain_id":5,
         "initial_slot_height":1,
         "current_time":{
             "secs":2,
@@ -22,3 +25,43 @@ fn test_config_serialization() {
     let parsed_config: ChainStateConfig = serde_json::from_str(data).unwrap();
     assert_eq!(config, parsed_config)
 }
+
+#[test]
+fn test_base_fee_unchanged_at_target() {
+    let next = compute_next_base_fee(&[100, 100], &[1000, 1000], &[1000, 1000], 8);
+    assert_eq!(next, vec![100, 100]);
+}
+
+#[test]
+fn test_base_fee_increases_above_target() {
+    // Twice the target: the base fee increases by the maximum of 1/8.
+    let next = compute_next_base_fee(&[800, 800], &[2000, 1500], &[1000, 1000], 8);
+    assert_eq!(next, vec![900, 850]);
+
+    // A zero base fee still increases when the slot is congested.
+    let next = compute_next_base_fee(&[0, 0], &[2000, 1000], &[1000, 1000], 8);
+    assert_eq!(next, vec![1, 0]);
+}
+
+#[test]
+fn test_base_fee_decreases_below_target() {
+    // An empty slot decreases the base fee by the maximum of 1/8.
+    let next = compute_next_base_fee(&[800, 800], &[0, 500], &[1000, 1000], 8);
+    assert_eq!(next, vec![700, 750]);
+
+    // The base fee never goes below zero.
+    let next = compute_next_base_fee(&[0, 0], &[0, 0], &[1000, 1000], 8);
+    assert_eq!(next, vec![0, 0]);
+}
+
+#[test]
+fn test_base_fee_ignores_dimensions_without_target() {
+    let next = compute_next_base_fee(&[800, 800], &[2000, 2000], &[1000, 0], 8);
+    assert_eq!(next, vec![900, 800]);
+}
+
+#[test]
+fn test_base_fee_does_not_overflow() {
+    let next = compute_next_base_fee(&[u64::MAX], &[u64::MAX], &[1], 8);
+    assert_eq!(next, vec![u64::MAX]);
+}
@@ -64,8 +64,10 @@ pub(crate) fn create_tx_env(tx: &TransactionSignedEcRecovered) -> TxEnv {
         data: Bytes::from(tx.input().to_vec()),
         chain_id: tx.chain_id(),
         nonce: Some(tx.nonce()),
-        // TODO handle access list
-        access_list: vec![],
+        access_list: tx
+            .access_list()
+            .map(|access_list| access_list.clone().flattened())
+            .unwrap_or_default(),
         // EIP-4844 related fields
         // https://github.com/Sovereign-Labs/sovereign-sdk/issues/912
         blob_hashes: vec![],
@@ -1,28 +1,35 @@
+use std::cell::RefCell;
 use std::convert::Infallible;
 
 use reth_primitives::{Address, Bytes, H256};
 use revm::primitives::{AccountInfo as ReVmAccountInfo, Bytecode, B160, B256, U256};
-use revm::Database;
-use sov_modules_api::{StateMapAccessor, WorkingSet};
+use revm::{Database, DatabaseRef};
+use sov_modules_api::{StateMapAccessor, StateVecAccessor, WorkingSet};
 use sov_state::codec::BcsCodec;
 
-use super::DbAccount;
+use super::{DbAccount, BLOCK_HASHES_TO_KEEP};
 
 pub(crate) struct EvmDb<'a, C: sov_modules_api::Context> {
     pub(crate) accounts: sov_modules_api::StateMap<Address, DbAccount, BcsCodec>,
+    pub(crate) account_incarnations: sov_modules_api::StateMap<Address, u64, BcsCodec>,
     pub(crate) code: sov_modules_api::StateMap<H256, Bytes, BcsCodec>,
+    pub(crate) latest_block_hashes: sov_modules_api::StateVec<H256, BcsCodec>,
     pub(crate) working_set: &'a mut WorkingSet<C>,
 }
 
 impl<'a, C: sov_modules_api::Context> EvmDb<'a, C> {
     pub(crate) fn new(
         accounts: sov_modules_api::StateMap<Address, DbAccount, BcsCodec>,
+        account_incarnations: sov_modules_api::StateMap<Address, u64, BcsCodec>,
         code: sov_modules_api::StateMap<H256, Bytes, BcsCodec>,
+        latest_block_hashes: sov_modules_api::StateVec<H256, BcsCodec>,
         working_set: &'a mut WorkingSet<C>,
     ) -> Self {
         Self {
             accounts,
+            account_incarnations,
             code,
+            latest_block_hashes,
             working_set,
         }
     }
@@ -60,7 +67,43 @@ impl<'a, C: sov_modules_api::Context> Database for EvmDb<'a, C> {
         Ok(storage_value)
     }
 
-    fn block_hash(&mut self, _number: U256) -> Result<B256, Self::Error> {
-        todo!("block_hash not yet implemented")
+    fn block_hash(&mut self, number: U256) -> Result<B256, Self::Error> {
+        // The EVM only queries the hashes of the last 256 blocks, which are all in the ring buffer.
+        let index = (number % U256::from(BLOCK_HASHES_TO_KEEP)).to::<usize>();
+        let block_hash = self
+            .latest_block_hashes
+            .get(index, self.working_set)
+            .unwrap_or_default();
+
+        Ok(block_hash)
+    }
+}
+
+/// Read-only view of an [`EvmDb`], for the inspectors reading the state before the execution.
+pub(crate) struct EvmDbRef<'a, C: sov_modules_api::Context>(RefCell<EvmDb<'a, C>>);
+
+impl<'a, C: sov_modules_api::Context> EvmDbRef<'a, C> {
+    pub(crate) fn new(db: EvmDb<'a, C>) -> Self {
+        Self(RefCell::new(db))
+    }
+}
+
+impl<'a, C: sov_modules_api::Context> DatabaseRef for EvmDbRef<'a, C> {
+    type Error = Infallible;
+
+    fn basic(&self, address: B160) -> Result<Option<ReVmAccountInfo>, Self::Error> {
+        self.0.borrow_mut().basic(address)
+    }
+
+    fn code_by_hash(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.0.borrow_mut().code_by_hash(code_hash)
+    }
+
+    fn storage(&self, address: B160, index: U256) -> Result<U256, Self::Error> {
+        self.0.borrow_mut().storage(address, index)
+    }
+
+    fn block_hash(&self, number: U256) -> Result<B256, Self::Error> {
+        self.0.borrow_mut().block_hash(number)
     }
 }
@@ -8,10 +8,9 @@ use super::DbAccount;
 impl<'a, C: sov_modules_api::Context> DatabaseCommit for EvmDb<'a, C> {
     fn commit(&mut self, changes: HashMap<B160, Account>) {
         for (address, account) in changes {
-            // TODO figure out what to do when account is destroyed.
-            // https://github.com/Sovereign-Labs/sovereign-sdk/issues/425
             if account.is_selfdestructed() {
-                todo!("Account destruction not supported")
+                self.destroy_account(&address);
+                continue;
             }
 
             let accounts_prefix = self.accounts.prefix();
@@ -19,7 +18,13 @@ impl<'a, C: sov_modules_api::Context> DatabaseCommit for EvmDb<'a, C> {
             let mut db_account = self
                 .accounts
                 .get(&address, self.working_set)
-                .unwrap_or_else(|| DbAccount::new(accounts_prefix, address));
+                .unwrap_or_else(|| {
+                    let incarnation = self
+                        .account_incarnations
+                        .get(&address, self.working_set)
+                        .unwrap_or_default();
+                    DbAccount::new(accounts_prefix, address, incarnation)
+                });
 
             let account_info = account.info;
 
@@ -45,3 +50,21 @@ impl<'a, C: sov_modules_api::Context> DatabaseCommit for EvmDb<'a, C> {
         }
     }
 }
+
+impl<'a, C: sov_modules_api::Context> EvmDb<'a, C> {
+    /// Removes the account in constant time, whatever the size of its storage.
+    /// The storage is left in the state but can't be read anymore: if the address is used again,
+    /// the new account gets the storage prefix of the next incarnation.
+    /// The code is kept, because it is stored by hash and can be shared with other accounts.
+    fn destroy_account(&mut self, address: &B160) {
+        if self.accounts.get(address, self.working_set).is_some() {
+            let incarnation = self
+                .account_incarnations
+                .get(address, self.working_set)
+                .unwrap_or_default();
+            self.account_incarnations
+                .set(address, &(incarnation + 1), self.working_set);
+            self.accounts.delete(address, self.working_set);
+        }
+    }
+}
@@ -3,7 +3,7 @@ use std::convert::Infallible;
 use reth_primitives::TransactionSignedEcRecovered;
 use reth_revm::tracing::{TracingInspector, TracingInspectorConfig};
 use revm::primitives::{CfgEnv, EVMError, Env, ExecutionResult, ResultAndState, TxEnv};
-use revm::{self, Database, DatabaseCommit};
+use revm::{self, Database, DatabaseCommit, Inspector};
 
 use super::conversions::create_tx_env;
 use super::primitive_types::BlockEnv;
@@ -33,6 +33,24 @@ pub(crate) fn inspect<DB: Database<Error = Infallible> + DatabaseCommit>(
     tx: TxEnv,
     config_env: CfgEnv,
 ) -> Result<ResultAndState, EVMError<Infallible>> {
+    let config = TracingInspectorConfig::all();
+
+    let mut inspector = TracingInspector::new(config);
+
+    inspect_with_inspector(db, block_env, tx, config_env, &mut inspector)
+}
+
+pub(crate) fn inspect_with_inspector<DB, I>(
+    db: DB,
+    block_env: &BlockEnv,
+    tx: TxEnv,
+    config_env: CfgEnv,
+    inspector: I,
+) -> Result<ResultAndState, EVMError<Infallible>>
+where
+    DB: Database<Error = Infallible> + DatabaseCommit,
+    I: Inspector<DB>,
+{
     let mut evm = revm::new();
 
     let env = Env {
@@ -44,9 +62,5 @@ pub(crate) fn inspect<DB: Database<Error = Infallible> + DatabaseCommit>(
     evm.env = env;
     evm.database(db);
 
-    let config = TracingInspectorConfig::all();
-
-    let mut inspector = TracingInspector::new(config);
-
-    evm.inspect(&mut inspector)
+    evm.inspect(inspector)
 }
@@ -19,6 +19,9 @@ pub(crate) use call::prepare_call_env;
 pub use primitive_types::RlpEvmTransaction;
 use sov_state::codec::BcsCodec;
 
+/// Number of the most recent block hashes available to the `BLOCKHASH` opcode.
+pub(crate) const BLOCK_HASHES_TO_KEEP: u64 = 256;
+
 // Stores information about an EVM account
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone, Default)]
 pub(crate) struct AccountInfo {
@@ -35,8 +38,9 @@ pub(crate) struct DbAccount {
 }
 
 impl DbAccount {
-    fn new(parent_prefix: &Prefix, address: Address) -> Self {
-        let prefix = Self::create_storage_prefix(parent_prefix, address);
+    /// Creates an account whose storage belongs to the given incarnation of the address.
+    pub(crate) fn new(parent_prefix: &Prefix, address: Address, incarnation: u64) -> Self {
+        let prefix = Self::create_storage_prefix(parent_prefix, address, incarnation);
         Self {
             info: Default::default(),
             storage: StateMap::with_codec(prefix, BcsCodec {}),
@@ -48,16 +52,22 @@ impl DbAccount {
         address: Address,
         info: AccountInfo,
     ) -> Self {
-        let prefix = Self::create_storage_prefix(parent_prefix, address);
+        let prefix = Self::create_storage_prefix(parent_prefix, address, 0);
         Self {
             info,
             storage: StateMap::with_codec(prefix, BcsCodec {}),
         }
     }
 
-    fn create_storage_prefix(parent_prefix: &Prefix, address: Address) -> Prefix {
+    /// Every incarnation of an address has its own storage prefix, so an account created again
+    /// after being destroyed doesn't see the storage of the previous one.
+    /// The first incarnation uses the prefix of the address alone.
+    fn create_storage_prefix(parent_prefix: &Prefix, address: Address, incarnation: u64) -> Prefix {
         let mut prefix = parent_prefix.as_aligned_vec().clone().into_inner();
         prefix.extend_from_slice(&address.0);
+        if incarnation > 0 {
+            prefix.extend_from_slice(&incarnation.to_be_bytes());
+        }
         Prefix::new(prefix)
     }
 }
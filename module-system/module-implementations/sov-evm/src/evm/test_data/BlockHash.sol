@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+// solc --abi --bin  BlockHash.sol  -o . --overwrite
+pragma solidity ^0.8.0;
+contract BlockHash {
+    bytes32 public storedHash;
+
+    function storeBlockHash(uint256 number) public {
+        storedHash = blockhash(number);
+    }
+}
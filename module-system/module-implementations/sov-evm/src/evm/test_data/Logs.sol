@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+// solc --abi --bin  Logs.sol  -o . --overwrite
+pragma solidity ^0.8.0;
+contract Logs {
+    event Topic(bytes32 indexed topic) anonymous;
+
+    function emitTopic(bytes32 topic) public {
+        emit Topic(topic);
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+// solc --abi --bin  SelfDestructor.sol  -o . --overwrite
+pragma solidity ^0.8.0;
+contract SelfDestructor {
+    uint256 public x;
+
+    constructor() {
+        x = 42;
+    }
+
+    function die(address payable to) public {
+        selfdestruct(to);
+    }
+}
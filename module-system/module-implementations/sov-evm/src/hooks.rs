@@ -4,6 +4,7 @@ use sov_modules_api::{AccessoryWorkingSet, Spec, WorkingSet};
 use sov_state::Storage;
 
 use crate::evm::primitive_types::{Block, BlockEnv};
+use crate::evm::BLOCK_HASHES_TO_KEEP;
 use crate::experimental::PendingTransaction;
 use crate::Evm;
 
@@ -26,6 +27,19 @@ where
         parent_block.header.state_root = H256(pre_state_root.clone().into());
         self.head.set(&parent_block, working_set);
 
+        // The parent block hash is final now that its state root is set.
+        // Once the ring buffer is full, it replaces the oldest hash.
+        let parent_block_hash = parent_block.clone().seal().header.hash;
+        let index = (parent_block.header.number % BLOCK_HASHES_TO_KEEP) as usize;
+        if index < self.latest_block_hashes.len(working_set) {
+            self.latest_block_hashes
+                .set(index, &parent_block_hash, working_set)
+                .expect("The index is within the ring buffer");
+        } else {
+            self.latest_block_hashes
+                .push(&parent_block_hash, working_set);
+        }
+
         let cfg = self.cfg.get(working_set).unwrap_or_default();
         let new_pending_env = BlockEnv {
             number: parent_block.header.number + 1,
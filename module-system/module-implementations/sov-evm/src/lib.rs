@@ -23,7 +23,9 @@ pub use signer::DevSigner;
 #[cfg(feature = "smart_contracts")]
 mod smart_contracts;
 #[cfg(feature = "smart_contracts")]
-pub use smart_contracts::SimpleStorageContract;
+pub use smart_contracts::{
+    BlockHashContract, LogsContract, SelfDestructorContract, SimpleStorageContract,
+};
 #[cfg(feature = "experimental")]
 #[cfg(test)]
 mod tests;
@@ -69,6 +71,11 @@ mod experimental {
         #[state]
         pub(crate) accounts: sov_modules_api::StateMap<Address, DbAccount, BcsCodec>,
 
+        /// Number of times every address had its account destroyed by `SELFDESTRUCT`.
+        /// The storage of an account is kept under a prefix specific to its incarnation.
+        #[state]
+        pub(crate) account_incarnations: sov_modules_api::StateMap<Address, u64, BcsCodec>,
+
         /// Mapping from code hash to code. Used for lazy-loading code into a contract account.
         #[state]
         pub(crate) code:
@@ -92,6 +99,12 @@ mod experimental {
         #[state]
         pub(crate) head: sov_modules_api::StateValue<Block, BcsCodec>,
 
+        /// Ring buffer of the hashes of the last 256 blocks, used by the `BLOCKHASH` opcode:
+        /// the hash of block `n` is at index `n % 256`.
+        /// The hash of a block is added in `begin_slot_hook` of the next block, once its `state_root` is known.
+        #[state]
+        pub(crate) latest_block_hashes: sov_modules_api::StateVec<reth_primitives::H256, BcsCodec>,
+
         /// Used only by the RPC: This represents the head of the chain and is set in two distinct stages:
         /// 1. `end_slot_hook`: the pending head is populated with data from pending_transactions.
         /// 2. `finalize_hook` the `root_hash` is populated.
@@ -152,7 +165,13 @@ mod experimental {
 
     impl<C: sov_modules_api::Context> Evm<C> {
         pub(crate) fn get_db<'a>(&self, working_set: &'a mut WorkingSet<C>) -> EvmDb<'a, C> {
-            EvmDb::new(self.accounts.clone(), self.code.clone(), working_set)
+            EvmDb::new(
+                self.accounts.clone(),
+                self.account_incarnations.clone(),
+                self.code.clone(),
+                self.latest_block_hashes.clone(),
+                working_set,
+            )
         }
     }
 }
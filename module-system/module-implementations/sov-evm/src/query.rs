@@ -4,9 +4,17 @@ use ethereum_types::U64;
 use jsonrpsee::core::RpcResult;
 use reth_primitives::contract::create_address;
 use reth_primitives::TransactionKind::{Call, Create};
-use reth_primitives::{TransactionSignedEcRecovered, U128, U256};
+use reth_primitives::{BlockNumberOrTag, TransactionSignedEcRecovered, U128, U256};
+use reth_revm::access_list::AccessListInspector;
+use reth_revm::tracing::{TracingInspector, TracingInspectorConfig};
+use reth_rpc_types::trace::geth::{
+    GethDebugBuiltInTracerType, GethDebugTracerType, GethDebugTracingCallOptions,
+    GethDebugTracingOptions, GethTrace,
+};
+use reth_rpc_types::{FilterBlockOption, FilteredParams};
+use revm::precompile::{Precompiles, SpecId as PrecompilesSpecId};
 use revm::primitives::{
-    EVMError, ExecutionResult, Halt, InvalidTransaction, TransactTo, KECCAK_EMPTY,
+    EVMError, ExecutionResult, Halt, InvalidTransaction, SpecId, TransactTo, KECCAK_EMPTY,
 };
 use sov_modules_api::macros::rpc_gen;
 use sov_modules_api::prelude::*;
@@ -15,7 +23,8 @@ use tracing::info;
 
 use crate::call::get_cfg_env;
 use crate::error::rpc::{ensure_success, RevertError, RpcInvalidTransactionError};
-use crate::evm::db::EvmDb;
+use crate::evm::conversions::create_tx_env;
+use crate::evm::db::{EvmDb, EvmDbRef};
 use crate::evm::primitive_types::{BlockEnv, Receipt, SealedBlock, TransactionSignedAndRecovered};
 use crate::evm::{executor, prepare_call_env};
 use crate::experimental::{MIN_CREATE_GAS, MIN_TRANSACTION_GAS};
@@ -138,13 +147,12 @@ impl<C: sov_modules_api::Context> Evm<C> {
     pub fn get_balance(
         &self,
         address: reth_primitives::Address,
-        _block_number: Option<String>,
+        block_number: Option<String>,
         working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_primitives::U256> {
         info!("evm module: eth_getBalance");
 
-        // TODO: Implement block_number once we have archival state #951
-        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/951
+        self.set_state_to_end_of_evm_block(block_number, working_set)?;
 
         let balance = self
             .accounts
@@ -161,13 +169,12 @@ impl<C: sov_modules_api::Context> Evm<C> {
         &self,
         address: reth_primitives::Address,
         index: reth_primitives::U256,
-        _block_number: Option<String>,
+        block_number: Option<String>,
         working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_primitives::U256> {
         info!("evm module: eth_getStorageAt");
 
-        // TODO: Implement block_number once we have archival state #951
-        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/951
+        self.set_state_to_end_of_evm_block(block_number, working_set)?;
 
         let storage_slot = self
             .accounts
@@ -183,13 +190,12 @@ impl<C: sov_modules_api::Context> Evm<C> {
     pub fn get_transaction_count(
         &self,
         address: reth_primitives::Address,
-        _block_number: Option<String>,
+        block_number: Option<String>,
         working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_primitives::U64> {
         info!("evm module: eth_getTransactionCount");
 
-        // TODO: Implement block_number once we have archival state #882
-        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/882
+        self.set_state_to_end_of_evm_block(block_number, working_set)?;
 
         let nonce = self
             .accounts
@@ -205,13 +211,12 @@ impl<C: sov_modules_api::Context> Evm<C> {
     pub fn get_code(
         &self,
         address: reth_primitives::Address,
-        _block_number: Option<String>,
+        block_number: Option<String>,
         working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_primitives::Bytes> {
         info!("evm module: eth_getCode");
 
-        // TODO: Implement block_number once we have archival state #951
-        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/951
+        self.set_state_to_end_of_evm_block(block_number, working_set)?;
 
         let code = self
             .accounts
@@ -319,6 +324,116 @@ impl<C: sov_modules_api::Context> Evm<C> {
         Ok(receipt)
     }
 
+    /// Handler for: `eth_getLogs`
+    ///
+    /// The logs bloom of every block in the range is checked first, so that only the receipts of
+    /// the blocks that may contain matching logs are read.
+    /// At most [`MAX_LOGS_BLOCK_RANGE`] blocks are scanned by a single call.
+    #[rpc_method(name = "eth_getLogs")]
+    pub fn eth_get_logs(
+        &self,
+        filter: reth_rpc_types::Filter,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<Vec<reth_rpc_types::Log>> {
+        info!("evm module: eth_getLogs");
+        self.get_logs_in_range(filter, MAX_LOGS_BLOCK_RANGE, working_set)
+    }
+
+    /// Returns the logs matching the filter, rejecting the ranges of more than `max_range` blocks.
+    pub(crate) fn get_logs_in_range(
+        &self,
+        filter: reth_rpc_types::Filter,
+        max_range: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<Vec<reth_rpc_types::Log>> {
+        let mut accessory_state = working_set.accessory_state();
+
+        let (from_block, to_block) = match filter.block_option {
+            FilterBlockOption::AtBlockHash(block_hash) => {
+                let block_number = self
+                    .block_hashes
+                    .get(&block_hash, &mut accessory_state)
+                    .ok_or(EthApiError::UnknownBlockNumber)?;
+                (block_number, block_number)
+            }
+            FilterBlockOption::Range {
+                from_block,
+                to_block,
+            } => {
+                let head_number = self
+                    .blocks
+                    .last(&mut accessory_state)
+                    .expect("Head block must be set")
+                    .header
+                    .number;
+                let from_block = resolve_block_number(from_block, head_number);
+                let to_block = resolve_block_number(to_block, head_number).min(head_number);
+                if from_block > to_block {
+                    return Err(EthApiError::InvalidBlockRange.into());
+                }
+                if to_block - from_block >= max_range {
+                    return Err(EthApiError::InvalidParams(format!(
+                        "Block range too large: at most {} blocks can be queried at once",
+                        max_range
+                    ))
+                    .into());
+                }
+                (from_block, to_block)
+            }
+        };
+
+        let filter_params = FilteredParams::new(Some(filter.clone()));
+        let address_filter = FilteredParams::address_filter(&filter.address);
+        let topics_filter = FilteredParams::topics_filter(&filter.topics);
+
+        let mut logs = Vec::new();
+        for block_number in from_block..=to_block {
+            let block = self
+                .blocks
+                .get(block_number as usize, &mut accessory_state)
+                .ok_or(EthApiError::UnknownBlockNumber)?;
+
+            let bloom = block.header.logs_bloom;
+            if !FilteredParams::matches_address(bloom, &address_filter)
+                || !FilteredParams::matches_topics(bloom, &topics_filter)
+            {
+                continue;
+            }
+
+            for tx_number in block.transactions.clone() {
+                let tx = self
+                    .transactions
+                    .get(tx_number as usize, &mut accessory_state)
+                    .expect("Transaction of a known block must be set");
+                let receipt = self
+                    .receipts
+                    .get(tx_number as usize, &mut accessory_state)
+                    .expect("Receipt of a known transaction must be set");
+
+                let transaction_index = tx_number - block.transactions.start;
+                for (idx, log) in receipt.receipt.logs.into_iter().enumerate() {
+                    if !filter_params.filter_address(&log) || !filter_params.filter_topics(&log) {
+                        continue;
+                    }
+
+                    logs.push(reth_rpc_types::Log {
+                        address: log.address,
+                        topics: log.topics,
+                        data: log.data,
+                        block_hash: Some(block.header.hash),
+                        block_number: Some(U256::from(block.header.number)),
+                        transaction_hash: Some(tx.signed_transaction.hash),
+                        transaction_index: Some(U256::from(transaction_index)),
+                        log_index: Some(U256::from(receipt.log_index_start + idx as u64)),
+                        removed: false,
+                    });
+                }
+            }
+        }
+
+        Ok(logs)
+    }
+
     /// Handler for: `eth_call`
     //https://github.com/paradigmxyz/reth/blob/f577e147807a783438a3f16aad968b4396274483/crates/rpc/rpc/src/eth/api/transactions.rs#L502
     //https://github.com/paradigmxyz/reth/blob/main/crates/rpc/rpc-types/src/eth/call.rs#L7
@@ -383,7 +498,7 @@ impl<C: sov_modules_api::Context> Evm<C> {
         working_set: &mut WorkingSet<C>,
     ) -> RpcResult<reth_primitives::U64> {
         info!("evm module: eth_estimateGas");
-        let mut block_env = match block_number {
+        let block_env = match block_number {
             Some(ref block_number) if block_number == "pending" => {
                 self.block_env.get(working_set).unwrap_or_default().clone()
             }
@@ -393,7 +508,95 @@ impl<C: sov_modules_api::Context> Evm<C> {
             }
         };
 
-        let tx_env = prepare_call_env(&block_env, request.clone()).unwrap();
+        let gas = self.estimate_gas_with_env(request, block_env, working_set)?;
+        Ok(U64::from(gas))
+    }
+
+    /// Handler for: `eth_createAccessList`
+    // https://github.com/paradigmxyz/reth/blob/main/crates/rpc/rpc/src/eth/api/call.rs#L371
+    #[rpc_method(name = "eth_createAccessList")]
+    pub fn eth_create_access_list(
+        &self,
+        request: reth_rpc_types::CallRequest,
+        block_number: Option<String>,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<reth_rpc_types::AccessListWithGasUsed> {
+        info!("evm module: eth_createAccessList");
+        let block_env = match block_number {
+            Some(ref block_number) if block_number == "pending" => {
+                self.block_env.get(working_set).unwrap_or_default().clone()
+            }
+            _ => {
+                let block = self.get_sealed_block_by_number(block_number, working_set);
+                BlockEnv::from(&block)
+            }
+        };
+
+        let mut tx_env = prepare_call_env(&block_env, request.clone())?;
+
+        let cfg = self.cfg.get(working_set).unwrap_or_default();
+        let cfg_env = get_cfg_env(&block_env, cfg, Some(get_cfg_env_template()));
+
+        let from = request.from.unwrap_or_default();
+        let to = match request.to {
+            Some(to) => to,
+            None => {
+                let nonce = self
+                    .accounts
+                    .get(&from, working_set)
+                    .map(|account| account.info.nonce)
+                    .unwrap_or_default();
+                create_address(from, nonce)
+            }
+        };
+
+        // The gas limit of the call must not prevent the discovery of the accessed slots
+        tx_env.gas_limit = block_env.gas_limit;
+
+        let precompiles = get_precompiles(cfg_env.spec_id);
+        let mut inspector = AccessListInspector::new(
+            request.access_list.clone().unwrap_or_default(),
+            from,
+            to,
+            precompiles,
+        );
+
+        let evm_db = self.get_db(working_set);
+        let result =
+            executor::inspect_with_inspector(evm_db, &block_env, tx_env, cfg_env, &mut inspector)
+                .map_err(EthApiError::from)?;
+
+        match result.result {
+            ExecutionResult::Success { .. } => {}
+            ExecutionResult::Halt { reason, gas_used } => {
+                return Err(RpcInvalidTransactionError::halt(reason, gas_used).into())
+            }
+            ExecutionResult::Revert { output, .. } => {
+                return Err(RpcInvalidTransactionError::Revert(RevertError::new(output)).into())
+            }
+        }
+
+        let access_list = inspector.into_access_list();
+
+        // The gas used by the transaction depends on the access list
+        let mut request = request;
+        request.access_list = Some(access_list.clone());
+        let gas_used = self.estimate_gas_with_env(request, block_env, working_set)?;
+
+        Ok(reth_rpc_types::AccessListWithGasUsed {
+            access_list,
+            gas_used: U256::from(gas_used),
+        })
+    }
+
+    /// Estimates the gas of the `request` executed on top of the block `block_env`.
+    fn estimate_gas_with_env(
+        &self,
+        request: reth_rpc_types::CallRequest,
+        mut block_env: BlockEnv,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<u64> {
+        let tx_env = prepare_call_env(&block_env, request.clone())?;
 
         let cfg = self.cfg.get(working_set).unwrap_or_default();
         let cfg_env = get_cfg_env(&block_env, cfg, Some(get_cfg_env_template()));
@@ -412,8 +615,9 @@ impl<C: sov_modules_api::Context> Evm<C> {
             .map(|account| account.info)
             .unwrap_or_default();
 
-        // if the request is a simple transfer we can optimize
-        if tx_env.data.is_empty() {
+        // if the request is a simple transfer we can optimize,
+        // unless the access list adds to the intrinsic gas of the transaction
+        if tx_env.data.is_empty() && tx_env.access_list.is_empty() {
             if let TransactTo::Call(to) = tx_env.transact_to {
                 let to_account = self
                     .accounts
@@ -427,7 +631,7 @@ impl<C: sov_modules_api::Context> Evm<C> {
                     if tx_env.value > available_funds {
                         return Err(RpcInvalidTransactionError::InsufficientFundsForTransfer.into());
                     }
-                    return Ok(U64::from(MIN_TRANSACTION_GAS));
+                    return Ok(MIN_TRANSACTION_GAS);
                 }
             }
         }
@@ -510,10 +714,12 @@ impl<C: sov_modules_api::Context> Evm<C> {
             let evm_db = self.get_db(working_set);
             let result = executor::inspect(evm_db, &block_env, tx_env.clone(), cfg_env.clone());
 
-            // Exceptional case: init used too much gas, we need to increase the gas limit and try
-            // again
-            if let Err(EVMError::Transaction(InvalidTransaction::CallerGasLimitMoreThanBlock)) =
-                result
+            // Exceptional case: init used too much gas, or the gas limit does not cover the
+            // intrinsic gas of the access list, we need to increase the gas limit and try again
+            if let Err(EVMError::Transaction(
+                InvalidTransaction::CallerGasLimitMoreThanBlock
+                | InvalidTransaction::CallGasCostMoreThanGasLimit,
+            )) = result
             {
                 // increase the lowest gas limit
                 lowest_gas_limit = mid_gas_limit;
@@ -554,7 +760,248 @@ impl<C: sov_modules_api::Context> Evm<C> {
             mid_gas_limit = ((highest_gas_limit as u128 + lowest_gas_limit as u128) / 2) as u64;
         }
 
-        Ok(U64::from(highest_gas_limit))
+        Ok(highest_gas_limit)
+    }
+
+    /// Handler for: `debug_traceTransaction`
+    ///
+    /// Replays the transaction on top of the state at the start of its block, after the
+    /// transactions preceding it in the block.
+    // https://github.com/paradigmxyz/reth/blob/main/crates/rpc/rpc/src/debug.rs
+    #[rpc_method(name = "debug_traceTransaction")]
+    pub fn debug_trace_transaction(
+        &self,
+        tx_hash: reth_primitives::H256,
+        opts: Option<GethDebugTracingOptions>,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<GethTrace> {
+        info!("evm module: debug_traceTransaction");
+
+        let mut accessory_state = working_set.accessory_state();
+
+        let tx_number = self
+            .transaction_hashes
+            .get(&tx_hash, &mut accessory_state)
+            .ok_or(EthApiError::TransactionNotFound)?;
+        let tx = self
+            .transactions
+            .get(tx_number as usize, &mut accessory_state)
+            .expect("Transaction with known hash must be set");
+        let block = self
+            .blocks
+            .get(tx.block_number as usize, &mut accessory_state)
+            .expect("Block number for known transaction must be set");
+        let preceding_txs: Vec<TransactionSignedEcRecovered> = (block.transactions.start
+            ..tx_number)
+            .map(|number| {
+                self.transactions
+                    .get(number as usize, &mut accessory_state)
+                    .expect("Transaction of a known block must be set")
+                    .into()
+            })
+            .collect();
+
+        let block_env = BlockEnv::from(&block);
+        let cfg = self.cfg.get(working_set).unwrap_or_default();
+        let cfg_env = get_cfg_env(&block_env, cfg, None);
+
+        // The state at the end of the parent block, see `set_state_to_end_of_evm_block`.
+        working_set.set_archival_version(block.header.number);
+
+        for preceding_tx in preceding_txs.iter() {
+            executor::execute_tx(
+                self.get_db(working_set),
+                &block_env,
+                preceding_tx,
+                cfg_env.clone(),
+            )
+            .map_err(EthApiError::from)?;
+        }
+
+        let tx_env = create_tx_env(&tx.into());
+        self.trace_with_env(
+            block_env,
+            tx_env,
+            cfg_env,
+            opts.unwrap_or_default(),
+            working_set,
+        )
+    }
+
+    /// Handler for: `debug_traceCall`
+    ///
+    /// State and block overrides are not supported and are ignored.
+    #[rpc_method(name = "debug_traceCall")]
+    pub fn debug_trace_call(
+        &self,
+        request: reth_rpc_types::CallRequest,
+        block_number: Option<String>,
+        opts: Option<GethDebugTracingCallOptions>,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<GethTrace> {
+        info!("evm module: debug_traceCall");
+        let block_env = match block_number {
+            Some(ref block_number) if block_number == "pending" => {
+                self.block_env.get(working_set).unwrap_or_default().clone()
+            }
+            _ => {
+                let block = self.get_sealed_block_by_number(block_number.clone(), working_set);
+                BlockEnv::from(&block)
+            }
+        };
+
+        let tx_env = prepare_call_env(&block_env, request)?;
+
+        let cfg = self.cfg.get(working_set).unwrap_or_default();
+        let cfg_env = get_cfg_env(&block_env, cfg, Some(get_cfg_env_template()));
+
+        self.set_state_to_end_of_evm_block(block_number, working_set)?;
+
+        let opts = opts.map(|opts| opts.tracing_options).unwrap_or_default();
+        self.trace_with_env(block_env, tx_env, cfg_env, opts, working_set)
+    }
+
+    /// Executes `tx_env` without committing it and returns its trace in the format selected by `opts`.
+    fn trace_with_env(
+        &self,
+        block_env: BlockEnv,
+        tx_env: revm::primitives::TxEnv,
+        cfg_env: revm::primitives::CfgEnv,
+        opts: GethDebugTracingOptions,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<GethTrace> {
+        let GethDebugTracingOptions {
+            config,
+            tracer,
+            tracer_config,
+            ..
+        } = opts;
+
+        let tracer = match tracer {
+            None => {
+                let mut inspector =
+                    TracingInspector::new(TracingInspectorConfig::from_geth_config(&config));
+                let result = executor::inspect_with_inspector(
+                    self.get_db(working_set),
+                    &block_env,
+                    tx_env,
+                    cfg_env,
+                    &mut inspector,
+                )
+                .map_err(EthApiError::from)?;
+
+                let gas_used = result.result.gas_used();
+                let return_value = result.result.into_output().unwrap_or_default().into();
+                let frame =
+                    inspector
+                        .into_geth_builder()
+                        .geth_traces(gas_used, return_value, config);
+                return Ok(frame.into());
+            }
+            Some(GethDebugTracerType::BuiltInTracer(tracer)) => tracer,
+            Some(GethDebugTracerType::JsTracer(_)) => {
+                return Err(EthApiError::Unsupported("JS tracers are not supported").into())
+            }
+        };
+
+        match tracer {
+            GethDebugBuiltInTracerType::CallTracer => {
+                let call_config = tracer_config
+                    .into_call_config()
+                    .map_err(|_| EthApiError::InvalidTracerConfig)?;
+                let mut inspector =
+                    TracingInspector::new(TracingInspectorConfig::from_geth_config(&config));
+                let result = executor::inspect_with_inspector(
+                    self.get_db(working_set),
+                    &block_env,
+                    tx_env,
+                    cfg_env,
+                    &mut inspector,
+                )
+                .map_err(EthApiError::from)?;
+
+                let frame = inspector
+                    .into_geth_builder()
+                    .geth_call_traces(call_config, result.result.gas_used());
+                Ok(frame.into())
+            }
+            GethDebugBuiltInTracerType::PreStateTracer => {
+                let prestate_config = tracer_config
+                    .into_pre_state_config()
+                    .map_err(|_| EthApiError::InvalidTracerConfig)?;
+                let mut inspector =
+                    TracingInspector::new(TracingInspectorConfig::from_geth_config(&config));
+                let result = executor::inspect_with_inspector(
+                    self.get_db(working_set),
+                    &block_env,
+                    tx_env,
+                    cfg_env,
+                    &mut inspector,
+                )
+                .map_err(EthApiError::from)?;
+
+                // The execution is not committed, so the database still holds the state before it.
+                let db = EvmDbRef::new(self.get_db(working_set));
+                let frame = match inspector.into_geth_builder().geth_prestate_traces(
+                    &result,
+                    prestate_config,
+                    db,
+                ) {
+                    Ok(frame) => frame,
+                    Err(infallible) => match infallible {},
+                };
+                Ok(frame.into())
+            }
+            GethDebugBuiltInTracerType::FourByteTracer | GethDebugBuiltInTracerType::NoopTracer => {
+                Err(
+                    EthApiError::Unsupported("Only callTracer and prestateTracer are supported")
+                        .into(),
+                )
+            }
+        }
+    }
+
+    /// Sets the archival version of the `working_set` to the state at the end of the given EVM block,
+    /// so that subsequent reads return the historical values.
+    /// `latest`, `pending` and a missing block number keep reading the current state.
+    ///
+    /// Every slot produces exactly one EVM block and one storage version, and the genesis state
+    /// (EVM block 0) is committed at version 1, so block `n` maps to version `n + 1`.
+    fn set_state_to_end_of_evm_block(
+        &self,
+        block_number: Option<String>,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<()> {
+        let block_number = match block_number {
+            None => return Ok(()),
+            Some(ref block_number) if block_number == "latest" || block_number == "pending" => {
+                return Ok(())
+            }
+            Some(ref block_number) if block_number == "earliest" => 0,
+            Some(ref block_number) => {
+                // hex representation may have 0x prefix
+                u64::from_str_radix(block_number.trim_start_matches("0x"), 16)
+                    .map_err(|_| EthApiError::UnknownBlockNumber)?
+            }
+        };
+
+        let head_number = self
+            .blocks
+            .last(&mut working_set.accessory_state())
+            .expect("Head block must be set")
+            .header
+            .number;
+
+        if block_number > head_number {
+            return Err(EthApiError::UnknownBlockNumber.into());
+        }
+
+        // The head block is the current state, no need to read from the archive.
+        if block_number < head_number {
+            working_set.set_archival_version(block_number + 1);
+        }
+
+        Ok(())
     }
 
     fn get_sealed_block_by_number(
@@ -588,6 +1035,34 @@ impl<C: sov_modules_api::Context> Evm<C> {
     }
 }
 
+/// The maximum number of blocks scanned by a single `eth_getLogs` call,
+/// so that a client can't make the node read the whole chain at once.
+pub const MAX_LOGS_BLOCK_RANGE: u64 = 10_000;
+
+/// Resolves a block number of a log filter, a missing block number being the head of the chain.
+/// `safe`, `finalized` and `pending` are not supported and resolve to the head of the chain.
+fn resolve_block_number(block_number: Option<BlockNumberOrTag>, head_number: u64) -> u64 {
+    match block_number {
+        Some(BlockNumberOrTag::Number(number)) => number,
+        Some(BlockNumberOrTag::Earliest) => 0,
+        Some(BlockNumberOrTag::Latest)
+        | Some(BlockNumberOrTag::Pending)
+        | Some(BlockNumberOrTag::Safe)
+        | Some(BlockNumberOrTag::Finalized)
+        | None => head_number,
+    }
+}
+
+/// Returns the addresses of the precompiles enabled in the given spec.
+/// They are always warm, so they are not added to the access lists.
+fn get_precompiles(spec_id: SpecId) -> impl IntoIterator<Item = reth_primitives::Address> {
+    let spec = PrecompilesSpecId::from_spec_id(spec_id);
+    Precompiles::new(spec)
+        .addresses()
+        .into_iter()
+        .map(reth_primitives::Address::from)
+}
+
 fn get_cfg_env_template() -> revm::primitives::CfgEnv {
     let mut cfg_env = revm::primitives::CfgEnv::default();
     // Reth sets this to true and uses only timeout, but other clients use this as a part of DOS attacks protection, with 100mln gas limit
@@ -0,0 +1,38 @@
+use ethers_contract::BaseContract;
+use ethers_core::types::Bytes;
+
+use super::load_contract;
+
+/// BlockHash wrapper.
+pub struct BlockHashContract {
+    bytecode: Bytes,
+    base_contract: BaseContract,
+}
+
+impl Default for BlockHashContract {
+    fn default() -> Self {
+        let (bytecode, base_contract) = load_contract("BlockHash");
+        Self {
+            bytecode,
+            base_contract,
+        }
+    }
+}
+
+impl BlockHashContract {
+    /// BlockHash bytecode.
+    pub fn byte_code(&self) -> Bytes {
+        self.bytecode.clone()
+    }
+
+    /// Stores the hash of the block `number` in the contract storage.
+    pub fn store_block_hash_call_data(&self, number: u64) -> Bytes {
+        let number = ethereum_types::U256::from(number);
+        self.base_contract.encode("storeBlockHash", number).unwrap()
+    }
+
+    /// Getter for the stored block hash.
+    pub fn stored_hash_call_data(&self) -> Bytes {
+        self.base_contract.encode("storedHash", ()).unwrap()
+    }
+}
@@ -0,0 +1,32 @@
+use ethers_contract::BaseContract;
+use ethers_core::types::Bytes;
+
+use super::load_contract;
+
+/// Logs wrapper.
+pub struct LogsContract {
+    bytecode: Bytes,
+    base_contract: BaseContract,
+}
+
+impl Default for LogsContract {
+    fn default() -> Self {
+        let (bytecode, base_contract) = load_contract("Logs");
+        Self {
+            bytecode,
+            base_contract,
+        }
+    }
+}
+
+impl LogsContract {
+    /// Logs bytecode.
+    pub fn byte_code(&self) -> Bytes {
+        self.bytecode.clone()
+    }
+
+    /// Emits an anonymous log whose only topic is `topic`.
+    pub fn emit_topic_call_data(&self, topic: ethereum_types::H256) -> Bytes {
+        self.base_contract.encode("emitTopic", topic).unwrap()
+    }
+}
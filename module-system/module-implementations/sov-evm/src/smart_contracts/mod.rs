@@ -1,2 +1,48 @@
+mod block_hash_contract;
+mod logs_contract;
+mod self_destructor_contract;
 mod simple_storage_contract;
+
+use std::path::PathBuf;
+
+pub use block_hash_contract::BlockHashContract;
+use ethers_contract::BaseContract;
+use ethers_core::abi::Abi;
+use ethers_core::types::Bytes;
+pub use logs_contract::LogsContract;
+pub use self_destructor_contract::SelfDestructorContract;
 pub use simple_storage_contract::SimpleStorageContract;
+
+fn test_data_path() -> PathBuf {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("src");
+    path.push("evm");
+    path.push("test_data");
+    path
+}
+
+fn make_contract_from_abi(path: PathBuf) -> BaseContract {
+    let abi_json = std::fs::read_to_string(path).unwrap();
+    let abi: Abi = serde_json::from_str(&abi_json).unwrap();
+    BaseContract::from(abi)
+}
+
+/// Loads the bytecode (`<name>.bin`) and the ABI (`<name>.abi`) generated by solc from the test data.
+fn load_contract(name: &str) -> (Bytes, BaseContract) {
+    let contract_data = {
+        let mut path = test_data_path();
+        path.push(format!("{}.bin", name));
+
+        let contract_data = std::fs::read_to_string(path).unwrap();
+        hex::decode(contract_data).unwrap()
+    };
+
+    let contract = {
+        let mut path = test_data_path();
+        path.push(format!("{}.abi", name));
+
+        make_contract_from_abi(path)
+    };
+
+    (Bytes::from(contract_data), contract)
+}
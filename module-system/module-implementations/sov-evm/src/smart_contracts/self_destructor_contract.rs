@@ -0,0 +1,37 @@
+use ethers_contract::BaseContract;
+use ethers_core::types::Bytes;
+
+use super::load_contract;
+
+/// SelfDestructor wrapper.
+pub struct SelfDestructorContract {
+    bytecode: Bytes,
+    base_contract: BaseContract,
+}
+
+impl Default for SelfDestructorContract {
+    fn default() -> Self {
+        let (bytecode, base_contract) = load_contract("SelfDestructor");
+        Self {
+            bytecode,
+            base_contract,
+        }
+    }
+}
+
+impl SelfDestructorContract {
+    /// SelfDestructor bytecode.
+    pub fn byte_code(&self) -> Bytes {
+        self.bytecode.clone()
+    }
+
+    /// Getter for the value set by the constructor.
+    pub fn x_call_data(&self) -> Bytes {
+        self.base_contract.encode("x", ()).unwrap()
+    }
+
+    /// Destroys the contract, sending its balance to `to`.
+    pub fn die_call_data(&self, to: ethereum_types::Address) -> Bytes {
+        self.base_contract.encode("die", to).unwrap()
+    }
+}
@@ -1,22 +1,7 @@
-use std::path::PathBuf;
-
 use ethers_contract::BaseContract;
-use ethers_core::abi::Abi;
 use ethers_core::types::Bytes;
 
-fn test_data_path() -> PathBuf {
-    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    path.push("src");
-    path.push("evm");
-    path.push("test_data");
-    path
-}
-
-fn make_contract_from_abi(path: PathBuf) -> BaseContract {
-    let abi_json = std::fs::read_to_string(path).unwrap();
-    let abi: Abi = serde_json::from_str(&abi_json).unwrap();
-    BaseContract::from(abi)
-}
+use super::load_contract;
 
 /// SimpleStorageContract wrapper.
 pub struct SimpleStorageContract {
@@ -26,24 +11,10 @@ pub struct SimpleStorageContract {
 
 impl Default for SimpleStorageContract {
     fn default() -> Self {
-        let contract_data = {
-            let mut path = test_data_path();
-            path.push("SimpleStorage.bin");
-
-            let contract_data = std::fs::read_to_string(path).unwrap();
-            hex::decode(contract_data).unwrap()
-        };
-
-        let contract = {
-            let mut path = test_data_path();
-            path.push("SimpleStorage.abi");
-
-            make_contract_from_abi(path)
-        };
-
+        let (bytecode, base_contract) = load_contract("SimpleStorage");
         Self {
-            bytecode: Bytes::from(contract_data),
-            base_contract: contract,
+            bytecode,
+            base_contract,
         }
     }
 }
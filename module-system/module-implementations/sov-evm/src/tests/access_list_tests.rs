@@ -0,0 +1,121 @@
+use reth_primitives::contract::create_address;
+use reth_primitives::{
+    AccessListItem, Bytes, TransactionKind, TransactionSignedEcRecovered, H256, U64, U8,
+};
+use reth_rpc_types::{CallInput, CallRequest};
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::utils::generate_address;
+use sov_modules_api::{Context, Module};
+
+use crate::call::CallMessage;
+use crate::smart_contracts::SimpleStorageContract;
+use crate::tests::call_tests::create_contract_message;
+use crate::tests::genesis_tests::get_evm;
+use crate::tests::test_signer::TestSigner;
+use crate::{AccountData, EvmConfig};
+
+type C = DefaultContext;
+
+#[test]
+fn access_list_transaction_test() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        // SHANGAI instead of LATEST
+        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/912
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let (evm, mut working_set) = get_evm(&config);
+    let contract_addr = create_address(dev_signer.address(), 0);
+    let sender_address = generate_address::<C>("sender");
+    let sequencer_address = generate_address::<C>("sequencer");
+    let context = C::new(sender_address, sequencer_address, 1);
+
+    evm.begin_slot_hook([5u8; 32], &[10u8; 32].into(), &mut working_set);
+    evm.call(
+        create_contract_message(&dev_signer, 0),
+        &context,
+        &mut working_set,
+    )
+    .unwrap();
+    evm.end_slot_hook(&mut working_set);
+    evm.finalize_hook(&[99u8; 32].into(), &mut working_set.accessory_state());
+
+    let set_call_data = SimpleStorageContract::default().set_call_data(42).to_vec();
+    let request = CallRequest {
+        from: Some(dev_signer.address()),
+        to: Some(contract_addr),
+        input: CallInput::new(set_call_data.clone().into()),
+        chain_id: Some(U64::from(1u64)),
+        transaction_type: Some(U8::from(1u8)),
+        ..Default::default()
+    };
+
+    let access_list_with_gas = evm
+        .eth_create_access_list(request.clone(), None, &mut working_set)
+        .unwrap();
+
+    // `set` only writes to the slot 0 of the contract
+    assert_eq!(
+        access_list_with_gas.access_list.0,
+        vec![AccessListItem {
+            address: contract_addr,
+            storage_keys: vec![H256::zero()],
+        }]
+    );
+
+    let gas_with_access_list = evm
+        .eth_estimate_gas(
+            CallRequest {
+                access_list: Some(access_list_with_gas.access_list.clone()),
+                ..request.clone()
+            },
+            None,
+            &mut working_set,
+        )
+        .unwrap();
+    assert_eq!(
+        U256::from(gas_with_access_list.as_u64()),
+        access_list_with_gas.gas_used
+    );
+
+    let signed_tx = dev_signer
+        .sign_access_list_transaction(
+            TransactionKind::Call(contract_addr),
+            set_call_data,
+            1,
+            access_list_with_gas.access_list,
+        )
+        .unwrap();
+    let tx_hash = TransactionSignedEcRecovered::try_from(signed_tx.clone())
+        .unwrap()
+        .hash;
+
+    evm.begin_slot_hook([5u8; 32], &[99u8; 32].into(), &mut working_set);
+    evm.call(CallMessage { tx: signed_tx }, &context, &mut working_set)
+        .unwrap();
+    evm.end_slot_hook(&mut working_set);
+    evm.finalize_hook(&[100u8; 32].into(), &mut working_set.accessory_state());
+
+    let receipt = evm
+        .get_transaction_receipt(tx_hash, &mut working_set)
+        .unwrap()
+        .unwrap();
+    assert_eq!(receipt.transaction_type, U8::from(1u8));
+    assert_eq!(receipt.status_code, Some(U64::from(1)));
+    assert!(receipt.gas_used.unwrap() <= access_list_with_gas.gas_used);
+
+    let storage_value = evm
+        .get_storage_at(contract_addr, U256::ZERO, None, &mut working_set)
+        .unwrap();
+    assert_eq!(storage_value, U256::from(42));
+}
@@ -0,0 +1,142 @@
+use reth_primitives::{Address, Bytes};
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::utils::generate_address;
+use sov_modules_api::{Context, Module, WorkingSet};
+use sov_prover_storage_manager::{new_orphan_storage, SnapshotManager};
+use sov_state::{DefaultStorageSpec, ProverStorage, Storage};
+
+use crate::tests::call_tests::{create_contract_message, set_arg_message};
+use crate::tests::test_signer::TestSigner;
+use crate::{AccountData, Evm, EvmConfig};
+
+type C = DefaultContext;
+
+#[test]
+fn queries_at_historical_block_numbers() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let initial_balance = U256::from(1000000000);
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: initial_balance,
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        // SHANGAI instead of LATEST
+        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/912
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let evm = Evm::<C>::default();
+
+    // Block 0: genesis
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.genesis(&config, &mut working_set).unwrap();
+    let genesis_root = commit(&evm, working_set, &storage);
+
+    // Block 1: deploy the contract and set its value
+    let contract_addr: Address = Address::from_slice(
+        hex::decode("819c5497b157177315e1204f52e588b393771719")
+            .unwrap()
+            .as_slice(),
+    );
+    let set_arg = 999;
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.begin_slot_hook([5u8; 32], &genesis_root, &mut working_set);
+    {
+        let sender_address = generate_address::<C>("sender");
+        let sequencer_address = generate_address::<C>("sequencer");
+        let context = C::new(sender_address, sequencer_address, 1);
+
+        let messages = vec![
+            create_contract_message(&dev_signer, 0),
+            set_arg_message(contract_addr, &dev_signer, 1, set_arg),
+        ];
+        for tx in messages {
+            evm.call(tx, &context, &mut working_set).unwrap();
+        }
+    }
+    evm.end_slot_hook(&mut working_set);
+    commit(&evm, working_set, &storage);
+
+    for block_number in ["0x0", "earliest"] {
+        let block_number = Some(block_number.to_string());
+        let working_set = &mut WorkingSet::new(storage.clone());
+        assert_eq!(
+            evm.get_transaction_count(dev_signer.address(), block_number.clone(), working_set)
+                .unwrap(),
+            0u64.into()
+        );
+        let working_set = &mut WorkingSet::new(storage.clone());
+        assert_eq!(
+            evm.get_balance(dev_signer.address(), block_number.clone(), working_set)
+                .unwrap(),
+            initial_balance
+        );
+        let working_set = &mut WorkingSet::new(storage.clone());
+        assert_eq!(
+            evm.get_code(contract_addr, block_number.clone(), working_set)
+                .unwrap(),
+            Bytes::default()
+        );
+        let working_set = &mut WorkingSet::new(storage.clone());
+        assert_eq!(
+            evm.get_storage_at(contract_addr, U256::ZERO, block_number, working_set)
+                .unwrap(),
+            U256::ZERO
+        );
+    }
+
+    for block_number in [Some("0x1".to_string()), Some("latest".to_string()), None] {
+        let working_set = &mut WorkingSet::new(storage.clone());
+        assert_eq!(
+            evm.get_transaction_count(dev_signer.address(), block_number.clone(), working_set)
+                .unwrap(),
+            2u64.into()
+        );
+        let working_set = &mut WorkingSet::new(storage.clone());
+        assert_ne!(
+            evm.get_code(contract_addr, block_number.clone(), working_set)
+                .unwrap(),
+            Bytes::default()
+        );
+        let working_set = &mut WorkingSet::new(storage.clone());
+        assert_eq!(
+            evm.get_storage_at(contract_addr, U256::ZERO, block_number, working_set)
+                .unwrap(),
+            U256::from(set_arg)
+        );
+    }
+
+    // Blocks that don't exist yet can't be queried
+    let working_set = &mut WorkingSet::new(storage.clone());
+    assert!(evm
+        .get_balance(dev_signer.address(), Some("0x2".to_string()), working_set)
+        .is_err());
+}
+
+pub(crate) fn commit(
+    evm: &Evm<C>,
+    working_set: WorkingSet<C>,
+    storage: &ProverStorage<DefaultStorageSpec, SnapshotManager>,
+) -> <ProverStorage<DefaultStorageSpec, SnapshotManager> as Storage>::Root {
+    let mut checkpoint = working_set.checkpoint();
+    let (cache_log, witness) = checkpoint.freeze();
+
+    let (root, authenticated_node_batch) = storage
+        .compute_state_update(cache_log, &witness)
+        .expect("jellyfish merkle tree update must succeed");
+
+    let mut working_set = checkpoint.to_revertable();
+    evm.finalize_hook(&root, &mut working_set.accessory_state());
+
+    let accessory_log = working_set.checkpoint().freeze_non_provable();
+    storage.commit(&authenticated_node_batch, &accessory_log);
+
+    root
+}
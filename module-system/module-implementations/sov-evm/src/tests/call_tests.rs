@@ -1,12 +1,15 @@
+use reth_primitives::contract::create_address;
 use reth_primitives::{Address, Bytes, TransactionKind};
 use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use revm::Database;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Context, Module, StateMapAccessor, StateValueAccessor, StateVecAccessor};
 
 use crate::call::CallMessage;
 use crate::evm::primitive_types::Receipt;
-use crate::smart_contracts::SimpleStorageContract;
+use crate::evm::DbAccount;
+use crate::smart_contracts::{BlockHashContract, SelfDestructorContract, SimpleStorageContract};
 use crate::tests::genesis_tests::get_evm;
 use crate::tests::test_signer::TestSigner;
 use crate::{AccountData, EvmConfig};
@@ -123,7 +126,153 @@ fn failed_transaction_test() {
     assert_eq!(block.transactions.end, 0);
 }
 
-fn create_contract_message(dev_signer: &TestSigner, nonce: u64) -> CallMessage {
+#[test]
+fn selfdestruct_test() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        // SHANGAI instead of LATEST
+        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/912
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let (evm, mut working_set) = get_evm(&config);
+    let contract = SelfDestructorContract::default();
+    let contract_addr = create_address(dev_signer.address(), 0);
+
+    evm.begin_slot_hook([5u8; 32], &[10u8; 32].into(), &mut working_set);
+    {
+        let sender_address = generate_address::<C>("sender");
+        let sequencer_address = generate_address::<C>("sequencer");
+        let context = C::new(sender_address, sequencer_address, 1);
+
+        let message = sign_message(
+            &dev_signer,
+            TransactionKind::Create,
+            contract.byte_code().to_vec(),
+            0,
+        );
+        evm.call(message, &context, &mut working_set).unwrap();
+
+        let db_account = evm.accounts.get(&contract_addr, &mut working_set).unwrap();
+        assert_eq!(
+            db_account.storage.get(&U256::ZERO, &mut working_set),
+            Some(U256::from(42))
+        );
+
+        let beneficiary = ethereum_types::Address::from_slice(dev_signer.address().as_bytes());
+        let message = sign_message(
+            &dev_signer,
+            TransactionKind::Call(contract_addr),
+            contract.die_call_data(beneficiary).to_vec(),
+            1,
+        );
+        evm.call(message, &context, &mut working_set).unwrap();
+
+        // The account is removed, and an account created again at its address gets a new storage
+        assert!(evm.accounts.get(&contract_addr, &mut working_set).is_none());
+        assert_eq!(
+            evm.account_incarnations
+                .get(&contract_addr, &mut working_set),
+            Some(1)
+        );
+        let recreated_account = DbAccount::new(evm.accounts.prefix(), contract_addr, 1);
+        assert!(recreated_account
+            .storage
+            .get(&U256::ZERO, &mut working_set)
+            .is_none());
+        assert_eq!(
+            evm.get_db(&mut working_set)
+                .storage(contract_addr, U256::ZERO)
+                .unwrap(),
+            U256::ZERO
+        );
+    }
+    evm.end_slot_hook(&mut working_set);
+}
+
+#[test]
+fn blockhash_test() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        // SHANGAI instead of LATEST
+        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/912
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let (evm, mut working_set) = get_evm(&config);
+    let contract = BlockHashContract::default();
+    let contract_addr = create_address(dev_signer.address(), 0);
+    let genesis_hash = evm
+        .blocks
+        .get(0, &mut working_set.accessory_state())
+        .unwrap()
+        .header
+        .hash;
+
+    evm.begin_slot_hook([5u8; 32], &[10u8; 32].into(), &mut working_set);
+    {
+        let sender_address = generate_address::<C>("sender");
+        let sequencer_address = generate_address::<C>("sequencer");
+        let context = C::new(sender_address, sequencer_address, 1);
+
+        let messages = vec![
+            sign_message(
+                &dev_signer,
+                TransactionKind::Create,
+                contract.byte_code().to_vec(),
+                0,
+            ),
+            sign_message(
+                &dev_signer,
+                TransactionKind::Call(contract_addr),
+                contract.store_block_hash_call_data(0).to_vec(),
+                1,
+            ),
+        ];
+        for tx in messages {
+            evm.call(tx, &context, &mut working_set).unwrap();
+        }
+    }
+    evm.end_slot_hook(&mut working_set);
+
+    let db_account = evm.accounts.get(&contract_addr, &mut working_set).unwrap();
+    let storage_value = db_account
+        .storage
+        .get(&U256::ZERO, &mut working_set)
+        .unwrap();
+
+    assert_eq!(U256::from_be_bytes(genesis_hash.0), storage_value);
+}
+
+pub(crate) fn sign_message(
+    dev_signer: &TestSigner,
+    kind: TransactionKind,
+    data: Vec<u8>,
+    nonce: u64,
+) -> CallMessage {
+    let signed_tx = dev_signer
+        .sign_default_transaction(kind, data, nonce)
+        .unwrap();
+    CallMessage { tx: signed_tx }
+}
+
+pub(crate) fn create_contract_message(dev_signer: &TestSigner, nonce: u64) -> CallMessage {
     let contract = SimpleStorageContract::default();
     let signed_tx = dev_signer
         .sign_default_transaction(
@@ -135,7 +284,7 @@ fn create_contract_message(dev_signer: &TestSigner, nonce: u64) -> CallMessage {
     CallMessage { tx: signed_tx }
 }
 
-fn set_arg_message(
+pub(crate) fn set_arg_message(
     contract_addr: Address,
     dev_signer: &TestSigner,
     nonce: u64,
@@ -35,6 +35,44 @@ fn begin_slot_hook_creates_pending_block() {
     );
 }
 
+#[test]
+fn begin_slot_hook_keeps_latest_block_hashes() {
+    let (evm, mut working_set) = get_evm(&TEST_CONFIG);
+
+    let genesis_hash = evm
+        .blocks
+        .get(0, &mut working_set.accessory_state())
+        .unwrap()
+        .header
+        .hash;
+
+    evm.begin_slot_hook(DA_ROOT_HASH.0, &[10u8; 32].into(), &mut working_set);
+    assert_eq!(
+        evm.latest_block_hashes.get(0, &mut working_set),
+        Some(genesis_hash)
+    );
+    evm.end_slot_hook(&mut working_set);
+
+    for _ in 0..256 {
+        evm.begin_slot_hook(DA_ROOT_HASH.0, &[10u8; 32].into(), &mut working_set);
+        evm.end_slot_hook(&mut working_set);
+    }
+
+    // Only the last 256 hashes are kept: the hash of block 257 replaces the hash of block 1
+    evm.begin_slot_hook(DA_ROOT_HASH.0, &[10u8; 32].into(), &mut working_set);
+    let head = evm.head.get(&mut working_set).unwrap();
+    assert_eq!(head.header.number, 257);
+    assert_eq!(evm.latest_block_hashes.len(&mut working_set), 256);
+    assert_ne!(
+        evm.latest_block_hashes.get(0, &mut working_set),
+        Some(genesis_hash)
+    );
+    assert_eq!(
+        evm.latest_block_hashes.get(1, &mut working_set),
+        Some(head.seal().header.hash)
+    );
+}
+
 #[test]
 fn end_slot_hook_sets_head() {
     let (evm, mut working_set) = get_evm(&TEST_CONFIG);
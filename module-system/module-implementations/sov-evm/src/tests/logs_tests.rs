@@ -0,0 +1,135 @@
+use reth_primitives::contract::create_address;
+use reth_primitives::{Bytes, TransactionKind, H256};
+use reth_rpc_types::Filter;
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::utils::generate_address;
+use sov_modules_api::{Context, Module};
+
+use crate::smart_contracts::LogsContract;
+use crate::tests::call_tests::sign_message;
+use crate::tests::genesis_tests::get_evm;
+use crate::tests::test_signer::TestSigner;
+use crate::{AccountData, EvmConfig};
+
+type C = DefaultContext;
+
+#[test]
+fn get_logs_test() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        // SHANGAI instead of LATEST
+        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/912
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let (evm, mut working_set) = get_evm(&config);
+
+    let contract = LogsContract::default();
+    let contract_addr = create_address(dev_signer.address(), 0);
+    let topic_a = H256::from_low_u64_be(0xa);
+    let topic_b = H256::from_low_u64_be(0xb);
+
+    evm.begin_slot_hook([5u8; 32], &[10u8; 32].into(), &mut working_set);
+    {
+        let sender_address = generate_address::<C>("sender");
+        let sequencer_address = generate_address::<C>("sequencer");
+        let context = C::new(sender_address, sequencer_address, 1);
+
+        let messages = vec![
+            sign_message(
+                &dev_signer,
+                TransactionKind::Create,
+                contract.byte_code().to_vec(),
+                0,
+            ),
+            sign_message(
+                &dev_signer,
+                TransactionKind::Call(contract_addr),
+                contract
+                    .emit_topic_call_data(ethereum_types::H256::from_slice(topic_a.as_bytes()))
+                    .to_vec(),
+                1,
+            ),
+            sign_message(
+                &dev_signer,
+                TransactionKind::Call(contract_addr),
+                contract
+                    .emit_topic_call_data(ethereum_types::H256::from_slice(topic_b.as_bytes()))
+                    .to_vec(),
+                2,
+            ),
+        ];
+        for tx in messages {
+            evm.call(tx, &context, &mut working_set).unwrap();
+        }
+    }
+    evm.end_slot_hook(&mut working_set);
+    evm.finalize_hook(&[99u8; 32].into(), &mut working_set.accessory_state());
+
+    let logs = evm
+        .eth_get_logs(
+            Filter::new()
+                .from_block(0u64)
+                .to_block(1u64)
+                .address(contract_addr),
+            &mut working_set,
+        )
+        .unwrap();
+    assert_eq!(logs.len(), 2);
+    assert_eq!(logs[0].topics, vec![topic_a]);
+    assert_eq!(logs[0].block_number, Some(U256::from(1)));
+    assert_eq!(logs[0].log_index, Some(U256::from(0)));
+    assert_eq!(logs[1].topics, vec![topic_b]);
+    assert_eq!(logs[1].log_index, Some(U256::from(1)));
+
+    let logs = evm
+        .eth_get_logs(
+            Filter::new()
+                .from_block(0u64)
+                .to_block(1u64)
+                .event_signature(topic_b),
+            &mut working_set,
+        )
+        .unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].topics, vec![topic_b]);
+    assert_eq!(logs[0].transaction_index, Some(U256::from(2)));
+
+    // The genesis block has no logs
+    let logs = evm
+        .eth_get_logs(
+            Filter::new().from_block(0u64).to_block(0u64),
+            &mut working_set,
+        )
+        .unwrap();
+    assert!(logs.is_empty());
+
+    // Inverted ranges are rejected
+    assert!(evm
+        .eth_get_logs(
+            Filter::new().from_block(1u64).to_block(0u64),
+            &mut working_set,
+        )
+        .is_err());
+
+    // Ranges larger than the maximum are rejected
+    let filter = Filter::new().from_block(0u64).to_block(1u64);
+    assert!(evm
+        .get_logs_in_range(filter.clone(), 1, &mut working_set)
+        .is_err());
+    assert_eq!(
+        evm.get_logs_in_range(filter, 2, &mut working_set)
+            .unwrap()
+            .len(),
+        2
+    );
+}
@@ -1,6 +1,10 @@
+mod access_list_tests;
+mod archival_query_tests;
 mod call_tests;
 mod cfg_tests;
 mod genesis_tests;
 mod hooks_tests;
+mod logs_tests;
 pub(crate) mod test_signer;
+mod trace_tests;
 mod tx_tests;
@@ -1,8 +1,8 @@
 use ethers_core::rand::rngs::StdRng;
 use ethers_core::rand::SeedableRng;
 use reth_primitives::{
-    Address, Bytes as RethBytes, Transaction as RethTransaction, TransactionKind,
-    TxEip1559 as RethTxEip1559,
+    AccessList, Address, Bytes as RethBytes, Transaction as RethTransaction, TransactionKind,
+    TxEip1559 as RethTxEip1559, TxEip2930 as RethTxEip2930,
 };
 use secp256k1::{PublicKey, SecretKey};
 
@@ -62,4 +62,31 @@ impl TestSigner {
             rlp: signed.envelope_encoded().to_vec(),
         })
     }
+
+    /// Signs default Eip2930 transaction with to, data, nonce and access list overridden.
+    pub(crate) fn sign_access_list_transaction(
+        &self,
+        to: TransactionKind,
+        data: Vec<u8>,
+        nonce: u64,
+        access_list: AccessList,
+    ) -> Result<RlpEvmTransaction, SignError> {
+        let reth_tx = RethTxEip2930 {
+            to,
+            input: RethBytes::from(data),
+            nonce,
+            chain_id: 1,
+            gas_limit: 1_000_000u64,
+            gas_price: u128::from(reth_primitives::constants::MIN_PROTOCOL_BASE_FEE * 2),
+            access_list,
+            ..Default::default()
+        };
+
+        let reth_tx = RethTransaction::Eip2930(reth_tx);
+        let signed = self.signer.sign_transaction(reth_tx, self.address)?;
+
+        Ok(RlpEvmTransaction {
+            rlp: signed.envelope_encoded().to_vec(),
+        })
+    }
 }
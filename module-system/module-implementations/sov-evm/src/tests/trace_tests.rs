@@ -0,0 +1,160 @@
+use reth_primitives::{Address, Bytes, TransactionSignedEcRecovered, H256};
+use reth_rpc_types::trace::geth::{
+    GethDebugBuiltInTracerType, GethDebugTracerType, GethDebugTracingCallOptions,
+    GethDebugTracingOptions, GethTrace, PreStateFrame,
+};
+use reth_rpc_types::{CallInput, CallRequest};
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::utils::generate_address;
+use sov_modules_api::{Context, Module, WorkingSet};
+use sov_prover_storage_manager::new_orphan_storage;
+
+use crate::smart_contracts::SimpleStorageContract;
+use crate::tests::archival_query_tests::commit;
+use crate::tests::call_tests::{create_contract_message, set_arg_message};
+use crate::tests::test_signer::TestSigner;
+use crate::{AccountData, Evm, EvmConfig};
+
+type C = DefaultContext;
+
+#[test]
+fn trace_transaction_and_call_test() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        // SHANGAI instead of LATEST
+        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/912
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let evm = Evm::<C>::default();
+
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.genesis(&config, &mut working_set).unwrap();
+    let genesis_root = commit(&evm, working_set, &storage);
+
+    let contract_addr: Address = Address::from_slice(
+        hex::decode("819c5497b157177315e1204f52e588b393771719")
+            .unwrap()
+            .as_slice(),
+    );
+    let traced_message = set_arg_message(contract_addr, &dev_signer, 2, 1000);
+    let traced_tx_hash = TransactionSignedEcRecovered::try_from(traced_message.tx.clone())
+        .unwrap()
+        .hash;
+
+    // Block 1: the traced transaction is preceded by another write to the same slot
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.begin_slot_hook([5u8; 32], &genesis_root, &mut working_set);
+    {
+        let sender_address = generate_address::<C>("sender");
+        let sequencer_address = generate_address::<C>("sequencer");
+        let context = C::new(sender_address, sequencer_address, 1);
+
+        let messages = vec![
+            create_contract_message(&dev_signer, 0),
+            set_arg_message(contract_addr, &dev_signer, 1, 999),
+            traced_message,
+        ];
+        for tx in messages {
+            evm.call(tx, &context, &mut working_set).unwrap();
+        }
+    }
+    evm.end_slot_hook(&mut working_set);
+    commit(&evm, working_set, &storage);
+
+    // Struct logs
+    let trace = evm
+        .debug_trace_transaction(traced_tx_hash, None, &mut WorkingSet::new(storage.clone()))
+        .unwrap();
+    let GethTrace::Default(frame) = trace else {
+        panic!("Expected struct logs, got {:?}", trace);
+    };
+    assert!(!frame.failed);
+    assert!(frame.struct_logs.iter().any(|log| log.op == "SSTORE"));
+
+    // Call tracer
+    let trace = evm
+        .debug_trace_transaction(
+            traced_tx_hash,
+            Some(tracer_options(GethDebugBuiltInTracerType::CallTracer)),
+            &mut WorkingSet::new(storage.clone()),
+        )
+        .unwrap();
+    let GethTrace::CallTracer(frame) = trace else {
+        panic!("Expected a call frame, got {:?}", trace);
+    };
+    assert_eq!(frame.typ, "CALL");
+    assert_eq!(frame.from, dev_signer.address());
+    assert_eq!(frame.to, Some(contract_addr));
+    assert!(frame.error.is_none());
+
+    // Prestate tracer: the preceding transaction of the block is replayed before the traced one
+    let trace = evm
+        .debug_trace_transaction(
+            traced_tx_hash,
+            Some(tracer_options(GethDebugBuiltInTracerType::PreStateTracer)),
+            &mut WorkingSet::new(storage.clone()),
+        )
+        .unwrap();
+    let GethTrace::PreStateTracer(PreStateFrame::Default(prestate)) = trace else {
+        panic!("Expected a prestate frame, got {:?}", trace);
+    };
+    let contract_state = prestate.0.get(&contract_addr).unwrap();
+    assert_eq!(
+        contract_state.storage.as_ref().unwrap().get(&H256::zero()),
+        Some(&H256::from_low_u64_be(999))
+    );
+
+    // Unknown transactions can't be traced
+    assert!(evm
+        .debug_trace_transaction(H256::zero(), None, &mut WorkingSet::new(storage.clone()))
+        .is_err());
+
+    let request = CallRequest {
+        from: Some(dev_signer.address()),
+        to: Some(contract_addr),
+        input: CallInput::new(
+            SimpleStorageContract::default()
+                .get_call_data()
+                .to_vec()
+                .into(),
+        ),
+        ..Default::default()
+    };
+    let trace = evm
+        .debug_trace_call(
+            request,
+            Some("latest".to_string()),
+            Some(GethDebugTracingCallOptions {
+                tracing_options: tracer_options(GethDebugBuiltInTracerType::CallTracer),
+                ..Default::default()
+            }),
+            &mut WorkingSet::new(storage.clone()),
+        )
+        .unwrap();
+    let GethTrace::CallTracer(frame) = trace else {
+        panic!("Expected a call frame, got {:?}", trace);
+    };
+    assert_eq!(
+        frame.output,
+        Some(U256::from(1000).to_be_bytes::<32>().to_vec().into())
+    );
+}
+
+fn tracer_options(tracer: GethDebugBuiltInTracerType) -> GethDebugTracingOptions {
+    GethDebugTracingOptions {
+        tracer: Some(GethDebugTracerType::BuiltInTracer(tracer)),
+        ..Default::default()
+    }
+}
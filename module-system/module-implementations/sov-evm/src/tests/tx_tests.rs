@@ -5,10 +5,14 @@ use ethers_core::types::transaction::eip2718::TypedTransaction;
 use ethers_core::types::{Bytes, Eip1559TransactionRequest};
 use ethers_core::utils::rlp::Rlp;
 use ethers_signers::{LocalWallet, Signer};
-use reth_primitives::{Address, TransactionSignedEcRecovered, U256, U8};
+use reth_primitives::{
+    AccessList, AccessListItem, Address, Signature, Transaction, TransactionKind,
+    TransactionSigned, TransactionSignedEcRecovered, TxEip2930, H256, U256, U8,
+};
 use reth_rpc_types::{CallInput, CallRequest};
 use revm::primitives::{TransactTo, TxEnv};
 
+use crate::evm::conversions::create_tx_env;
 use crate::evm::prepare_call_env;
 use crate::evm::primitive_types::TransactionSignedAndRecovered;
 use crate::primitive_types::{Block, BlockEnv};
@@ -68,6 +72,33 @@ fn tx_conversion() {
     assert_eq!(signer, reth_tx.signer());
 }
 
+#[test]
+fn tx_env_keeps_access_list() {
+    let signer = Address::random();
+    let to = Address::random();
+    let tx = Transaction::Eip2930(TxEip2930 {
+        chain_id: 1,
+        to: TransactionKind::Call(to),
+        gas_limit: 100_000,
+        access_list: AccessList(vec![AccessListItem {
+            address: to,
+            storage_keys: vec![H256::zero(), H256::from_low_u64_be(1)],
+        }]),
+        ..Default::default()
+    });
+    let tx = TransactionSignedEcRecovered::from_signed_transaction(
+        TransactionSigned::from_transaction_and_signature(tx, Signature::default()),
+        signer,
+    );
+
+    let tx_env = create_tx_env(&tx);
+
+    assert_eq!(
+        tx_env.access_list,
+        vec![(to, vec![U256::ZERO, U256::from(1)])]
+    );
+}
+
 // TODO: Needs more complex tests later
 #[test]
 fn prepare_call_env_conversion() {
@@ -0,0 +1,35 @@
+/// The events emitted by the sov-sequencer-registry module.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    derive(serde::Deserialize)
+)]
+#[derive(
+    borsh::BorshDeserialize,
+    borsh::BorshSerialize,
+    sov_modules_api::EventName,
+    Debug,
+    PartialEq,
+    Clone,
+)]
+pub enum Event<C: sov_modules_api::Context> {
+    /// A sequencer was registered, and its coins were locked.
+    SequencerRegistered {
+        /// The rollup address of the sequencer.
+        sequencer: C::Address,
+        /// The raw Da address of the sequencer.
+        da_address: Vec<u8>,
+    },
+    /// A sequencer exited, and its locked coins were returned.
+    SequencerExited {
+        /// The rollup address of the sequencer.
+        sequencer: C::Address,
+        /// The raw Da address of the sequencer.
+        da_address: Vec<u8>,
+    },
+    /// A sequencer was removed from the registry for submitting an invalid blob.
+    SequencerSlashed {
+        /// The raw Da address of the sequencer.
+        da_address: Vec<u8>,
+    },
+}
@@ -5,7 +5,7 @@ use sov_zk_cycle_macros::cycle_tracker;
 #[cfg(all(target_os = "zkvm", feature = "bench"))]
 use sov_zk_cycle_utils::print_cycle_count;
 
-use crate::{SequencerOutcome, SequencerRegistry};
+use crate::{Event, SequencerOutcome, SequencerRegistry};
 
 impl<C: Context, Da: sov_modules_api::DaSpec> ApplyBlobHooks<Da::BlobTransaction>
     for SequencerRegistry<C, Da>
@@ -35,11 +35,40 @@ impl<C: Context, Da: sov_modules_api::DaSpec> ApplyBlobHooks<Da::BlobTransaction
         working_set: &mut WorkingSet<C>,
     ) -> anyhow::Result<()> {
         match result {
-            SequencerOutcome::Completed => (),
+            SequencerOutcome::Rewarded { sequencer, reward } => {
+                if let Err(e) = self.pay_reward(&sequencer, reward, working_set) {
+                    // Nothing else settles the tips escrowed by the transactions of the blob.
+                    self.bank.burn_gas_tip(reward, working_set)?;
+                    return Err(e);
+                }
+            }
             SequencerOutcome::Slashed { sequencer } => {
                 self.delete(&sequencer, working_set);
+                working_set.emit_event::<Self>(Event::SequencerSlashed {
+                    da_address: sequencer.as_ref().to_vec(),
+                });
             }
         }
         Ok(())
     }
 }
+
+impl<C: Context, Da: sov_modules_api::DaSpec> SequencerRegistry<C, Da> {
+    /// Pays the tips collected from the transactions of a blob to the rollup address of its sequencer.
+    fn pay_reward(
+        &self,
+        sequencer: &Da::Address,
+        reward: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<()> {
+        if reward > 0 {
+            let rollup_address = self
+                .allowed_sequencers
+                .get(sequencer, working_set)
+                .ok_or_else(|| anyhow::anyhow!("sequencer {} is not registered", sequencer))?;
+            self.bank
+                .pay_gas_tip(&rollup_address, reward, working_set)?;
+        }
+        Ok(())
+    }
+}
@@ -7,11 +7,13 @@
 
 #![deny(missing_docs)]
 mod call;
+mod event;
 mod genesis;
 mod hooks;
 #[cfg(feature = "native")]
 mod query;
 pub use call::*;
+pub use event::Event;
 pub use genesis::*;
 #[cfg(feature = "native")]
 pub use query::*;
@@ -58,7 +60,13 @@ pub struct SequencerRegistry<C: sov_modules_api::Context, Da: sov_modules_api::D
 /// Result of applying a blob, from sequencer's point of view.
 pub enum SequencerOutcome<Da: sov_modules_api::DaSpec> {
     /// The blob was applied successfully and the operation is concluded.
-    Completed,
+    /// The sequencer is paid the tips collected from the transactions of the blob.
+    Rewarded {
+        /// The address of the sequencer that submitted the blob.
+        sequencer: Da::Address,
+        /// Amount of gas tokens collected as transaction tips.
+        reward: u64,
+    },
     /// The blob was *not* applied successfully. The sequencer has been slashed
     /// as a result of the invalid blob.
     Slashed {
@@ -76,7 +84,7 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> sov_modules_api::
 
     type CallMessage = CallMessage;
 
-    type Event = ();
+    type Event = Event<C>;
 
     fn genesis(&self, config: &Self::Config, working_set: &mut WorkingSet<C>) -> Result<(), Error> {
         Ok(self.init_module(config, working_set)?)
@@ -90,12 +98,22 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> sov_modules_api::
     ) -> Result<CallResponse, Error> {
         Ok(match message {
             CallMessage::Register { da_address } => {
-                let da_address = Da::Address::try_from(&da_address)?;
-                self.register(&da_address, context, working_set)?
+                let response =
+                    self.register(&Da::Address::try_from(&da_address)?, context, working_set)?;
+                working_set.emit_event::<Self>(Event::SequencerRegistered {
+                    sequencer: context.sender().clone(),
+                    da_address,
+                });
+                response
             }
             CallMessage::Exit { da_address } => {
-                let da_address = Da::Address::try_from(&da_address)?;
-                self.exit(&da_address, context, working_set)?
+                let response =
+                    self.exit(&Da::Address::try_from(&da_address)?, context, working_set)?;
+                working_set.emit_event::<Self>(Event::SequencerExited {
+                    sequencer: context.sender().clone(),
+                    da_address,
+                });
+                response
             }
         })
     }
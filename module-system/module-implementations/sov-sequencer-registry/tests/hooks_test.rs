@@ -86,7 +86,10 @@ fn end_blob_hook_success() {
 
     <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
         &test_sequencer.registry,
-        SequencerOutcome::Completed,
+        SequencerOutcome::Rewarded {
+            sequencer: genesis_sequencer_da_address,
+            reward: 0,
+        },
         working_set,
     )
     .unwrap();
@@ -99,6 +102,105 @@ fn end_blob_hook_success() {
     assert!(resp.address.is_some());
 }
 
+#[test]
+fn end_blob_hook_reward() {
+    let reward = 7;
+    let mut test_sequencer = create_test_sequencer();
+
+    // Fund the gas escrow, as if the tips were collected from the transactions of the blob.
+    let gas_escrow_address = *test_sequencer.bank.gas_escrow_address();
+    test_sequencer
+        .bank_config
+        .tokens
+        .push(sov_bank::TokenConfig {
+            token_name: "sov-gas-token".to_owned(),
+            address_and_balances: vec![(gas_escrow_address, reward)],
+            authorized_minters: vec![],
+            salt: 0,
+        });
+    let gas_token_address = sov_bank::get_genesis_token_address::<C>("sov-gas-token", 0);
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+
+    let genesis_sequencer_da_address = MockAddress::from(GENESIS_SEQUENCER_DA_ADDRESS);
+    let seq_rollup_address = test_sequencer.sequencer_config.seq_rollup_address;
+
+    <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
+        &test_sequencer.registry,
+        SequencerOutcome::Rewarded {
+            sequencer: genesis_sequencer_da_address,
+            reward,
+        },
+        working_set,
+    )
+    .unwrap();
+
+    let sequencer_balance =
+        test_sequencer
+            .bank
+            .get_balance_of(seq_rollup_address, gas_token_address, working_set);
+    assert_eq!(Some(reward), sequencer_balance);
+    let escrow_balance =
+        test_sequencer
+            .bank
+            .get_balance_of(gas_escrow_address, gas_token_address, working_set);
+    assert_eq!(Some(0), escrow_balance);
+}
+
+#[test]
+fn end_blob_hook_burns_unpaid_reward() {
+    let reward = 7;
+    let mut test_sequencer = create_test_sequencer();
+
+    // Fund the gas escrow, as if the tips were collected from the transactions of the blob.
+    let gas_escrow_address = *test_sequencer.bank.gas_escrow_address();
+    test_sequencer
+        .bank_config
+        .tokens
+        .push(sov_bank::TokenConfig {
+            token_name: "sov-gas-token".to_owned(),
+            address_and_balances: vec![(gas_escrow_address, reward)],
+            authorized_minters: vec![],
+            salt: 0,
+        });
+    let gas_token_address = sov_bank::get_genesis_token_address::<C>("sov-gas-token", 0);
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    test_sequencer.genesis(working_set);
+
+    // The reward of a sequencer which isn't registered can't be paid
+    let unknown_sequencer_da_address = MockAddress::from(UNKNOWN_SEQUENCER_DA_ADDRESS);
+    let result = <SequencerRegistry<C, Da> as ApplyBlobHooks<MockBlob>>::end_blob_hook(
+        &test_sequencer.registry,
+        SequencerOutcome::Rewarded {
+            sequencer: unknown_sequencer_da_address,
+            reward,
+        },
+        working_set,
+    );
+    assert_eq!(
+        format!(
+            "sequencer {} is not registered",
+            unknown_sequencer_da_address
+        ),
+        result.unwrap_err().to_string()
+    );
+
+    // The tips don't stay in the escrow: they are burned
+    let escrow_balance =
+        test_sequencer
+            .bank
+            .get_balance_of(gas_escrow_address, gas_token_address, working_set);
+    assert_eq!(Some(0), escrow_balance);
+    let total_supply = test_sequencer
+        .bank
+        .get_total_supply_of(&gas_token_address, working_set);
+    assert_eq!(Some(0), total_supply);
+}
+
 #[test]
 fn end_blob_hook_slash() {
     let mut test_sequencer = create_test_sequencer();
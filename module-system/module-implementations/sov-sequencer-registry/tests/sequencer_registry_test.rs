@@ -1,8 +1,9 @@
+use borsh::BorshDeserialize;
 use helpers::*;
 use sov_mock_da::MockAddress;
 use sov_modules_api::{Context, Error, Module, ModuleInfo, WorkingSet};
 use sov_prover_storage_manager::new_orphan_storage;
-use sov_sequencer_registry::{CallMessage, SequencerRegistry};
+use sov_sequencer_registry::{CallMessage, Event, SequencerRegistry};
 
 mod helpers;
 
@@ -56,6 +57,24 @@ fn test_registration_lifecycle() {
         .call(register_message, &sender_context, working_set)
         .expect("Sequencer registration has failed");
 
+    let events = working_set.take_events();
+    assert_eq!(1, events.len());
+    assert_eq!(
+        &[
+            SequencerRegistry::<C, Da>::module_prefix().to_bytes(),
+            b"SequencerRegistered".to_vec()
+        ]
+        .concat(),
+        events[0].key().inner()
+    );
+    assert_eq!(
+        Event::<C>::SequencerRegistered {
+            sequencer: sequencer_address,
+            da_address: da_address.as_ref().to_vec(),
+        },
+        Event::try_from_slice(events[0].value().inner()).unwrap()
+    );
+
     let balance_after_registration = test_sequencer
         .query_balance(sequencer_address, working_set)
         .unwrap()
@@ -0,0 +1,233 @@
+//! Password-based encryption of the key files of the wallet.
+//!
+//! The format of an encrypted key file is similar to the version 3 of the Ethereum keystore:
+//! a key is derived from the password with scrypt, and the private key is encrypted with AES-256-GCM.
+//! The address stored alongside the encrypted key is authenticated, but not encrypted.
+use aes_gcm::aead::{Aead, NewAead, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use serde::{Deserialize, Serialize};
+
+/// The version of the encrypted key file format.
+pub const KEYSTORE_VERSION: u32 = 1;
+
+/// The base 2 logarithm of the scrypt cost parameter used for new key files.
+pub const DEFAULT_SCRYPT_LOG_N: u8 = 16;
+
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+/// A private key encrypted with a password.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptedKeyFile {
+    /// The version of the format, [`KEYSTORE_VERSION`].
+    pub version: u32,
+    /// The address associated with the private key.
+    pub address: String,
+    /// The encrypted private key, and the parameters to decrypt it.
+    pub crypto: CryptoParams,
+}
+
+/// The encrypted private key of an [`EncryptedKeyFile`], and the parameters to decrypt it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CryptoParams {
+    /// The cipher used to encrypt the private key.
+    pub cipher: Cipher,
+    /// The parameters of the cipher.
+    pub cipherparams: CipherParams,
+    /// The encrypted private key, followed by its authentication tag.
+    #[serde(with = "hex::serde")]
+    pub ciphertext: Vec<u8>,
+    /// The function deriving the encryption key from the password.
+    #[serde(flatten)]
+    pub kdf: Kdf,
+}
+
+/// The ciphers supported by [`EncryptedKeyFile`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cipher {
+    /// AES-256 in Galois/Counter Mode.
+    #[serde(rename = "aes-256-gcm")]
+    Aes256Gcm,
+}
+
+/// The parameters of the [`Cipher`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CipherParams {
+    /// The nonce used to encrypt the private key.
+    #[serde(with = "hex::serde")]
+    pub nonce: Vec<u8>,
+}
+
+/// The key derivation functions supported by [`EncryptedKeyFile`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kdf", content = "kdfparams", rename_all = "lowercase")]
+pub enum Kdf {
+    /// The scrypt function.
+    Scrypt(ScryptParams),
+}
+
+/// The parameters of the scrypt key derivation function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScryptParams {
+    /// The base 2 logarithm of the cost parameter.
+    pub log_n: u8,
+    /// The block size.
+    pub r: u32,
+    /// The parallelization parameter.
+    pub p: u32,
+    /// The length of the derived key.
+    pub dklen: usize,
+    /// The salt, chosen randomly for each key file.
+    #[serde(with = "hex::serde")]
+    pub salt: Vec<u8>,
+}
+
+impl Kdf {
+    fn derive_key(&self, password: &str) -> Result<Vec<u8>, anyhow::Error> {
+        match self {
+            Kdf::Scrypt(params) => {
+                let scrypt_params = scrypt::Params::new(params.log_n, params.r, params.p)
+                    .map_err(|e| anyhow::anyhow!("Invalid scrypt parameters: {}", e))?;
+                let mut key = vec![0; params.dklen];
+                scrypt::scrypt(password.as_bytes(), &params.salt, &scrypt_params, &mut key)
+                    .map_err(|e| anyhow::anyhow!("Invalid scrypt key length: {}", e))?;
+                Ok(key)
+            }
+        }
+    }
+}
+
+impl EncryptedKeyFile {
+    /// Encrypts the serialized private key associated with `address` with the password,
+    /// using the default scrypt cost.
+    pub fn encrypt(
+        private_key: &[u8],
+        address: String,
+        password: &str,
+    ) -> Result<Self, anyhow::Error> {
+        Self::encrypt_with_cost(private_key, address, password, DEFAULT_SCRYPT_LOG_N)
+    }
+
+    /// Encrypts the serialized private key associated with `address` with the password,
+    /// deriving the encryption key with a scrypt cost parameter of `2^log_n`.
+    pub fn encrypt_with_cost(
+        private_key: &[u8],
+        address: String,
+        password: &str,
+        log_n: u8,
+    ) -> Result<Self, anyhow::Error> {
+        let kdf = Kdf::Scrypt(ScryptParams {
+            log_n,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+            dklen: KEY_LEN,
+            salt: rand::random::<[u8; SALT_LEN]>().to_vec(),
+        });
+        let key = kdf.derive_key(password)?;
+        let nonce = rand::random::<[u8; NONCE_LEN]>().to_vec();
+
+        let ciphertext = Aes256Gcm::new(Key::from_slice(&key))
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: private_key,
+                    aad: address.as_bytes(),
+                },
+            )
+            .map_err(|_| anyhow::anyhow!("Failed to encrypt the private key"))?;
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            address,
+            crypto: CryptoParams {
+                cipher: Cipher::Aes256Gcm,
+                cipherparams: CipherParams { nonce },
+                ciphertext,
+                kdf,
+            },
+        })
+    }
+
+    /// Decrypts the serialized private key with the password.
+    /// Fails if the password is wrong, or if the key file was tampered with.
+    pub fn decrypt(&self, password: &str) -> Result<Vec<u8>, anyhow::Error> {
+        anyhow::ensure!(
+            self.version == KEYSTORE_VERSION,
+            "Unsupported key file version {}. Expected {}",
+            self.version,
+            KEYSTORE_VERSION
+        );
+        let CryptoParams {
+            cipher: Cipher::Aes256Gcm,
+            cipherparams,
+            ciphertext,
+            kdf,
+        } = &self.crypto;
+        anyhow::ensure!(
+            cipherparams.nonce.len() == NONCE_LEN,
+            "Invalid nonce length {}. Expected {}",
+            cipherparams.nonce.len(),
+            NONCE_LEN
+        );
+        let key = kdf.derive_key(password)?;
+        anyhow::ensure!(
+            key.len() == KEY_LEN,
+            "Invalid key length {}. Expected {}",
+            key.len(),
+            KEY_LEN
+        );
+
+        Aes256Gcm::new(Key::from_slice(&key))
+            .decrypt(
+                Nonce::from_slice(&cipherparams.nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: self.address.as_bytes(),
+                },
+            )
+            .map_err(|_| {
+                anyhow::anyhow!("Failed to decrypt the private key. Is the password correct?")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A low scrypt cost, to keep the tests fast.
+    const TEST_LOG_N: u8 = 4;
+
+    #[test]
+    fn test_encryption_round_trip() {
+        let encrypted =
+            EncryptedKeyFile::encrypt_with_cost(b"secret", "addr".to_string(), "pass", TEST_LOG_N)
+                .unwrap();
+        assert_ne!(b"secret".to_vec(), encrypted.crypto.ciphertext);
+
+        let json = serde_json::to_string(&encrypted).unwrap();
+        let decoded: EncryptedKeyFile = serde_json::from_str(&json).unwrap();
+        assert_eq!(encrypted, decoded);
+        assert_eq!(b"secret".to_vec(), decoded.decrypt("pass").unwrap());
+    }
+
+    #[test]
+    fn test_decryption_fails_with_wrong_password() {
+        let encrypted =
+            EncryptedKeyFile::encrypt_with_cost(b"secret", "addr".to_string(), "pass", TEST_LOG_N)
+                .unwrap();
+        assert!(encrypted.decrypt("wrong").is_err());
+    }
+
+    #[test]
+    fn test_decryption_fails_with_tampered_address() {
+        let mut encrypted =
+            EncryptedKeyFile::encrypt_with_cost(b"secret", "addr".to_string(), "pass", TEST_LOG_N)
+                .unwrap();
+        encrypted.address = "other".to_string();
+        assert!(encrypted.decrypt("pass").is_err());
+    }
+}
@@ -6,6 +6,7 @@ use std::path::Path;
 use directories::BaseDirs;
 pub use sov_modules_api::clap;
 
+pub mod keystore;
 /// Types and functionality storing and loading the persistent state of the wallet
 pub mod wallet_state;
 pub mod workflows;
@@ -1,27 +1,39 @@
 //! Key management workflows for the sov CLI wallet
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use sov_modules_api::{clap, PrivateKey, PublicKey, Spec};
 
+use crate::keystore::EncryptedKeyFile;
 use crate::wallet_state::{KeyIdentifier, PrivateKeyAndAddress, WalletState};
 
+const SOV_WALLET_PASSWORD_ENV_VAR: &str = "SOV_WALLET_PASSWORD";
+const SOV_WALLET_NEW_PASSWORD_ENV_VAR: &str = "SOV_WALLET_NEW_PASSWORD";
+
 #[derive(clap::Subcommand)]
 /// View and manage keys associated with this wallet
 pub enum KeyWorkflow<C: sov_modules_api::Context> {
-    /// Generate a new key pair
+    /// Generate a new key pair. The private key is encrypted with a password
     Generate {
         #[clap(short, long)]
         /// A nickname for this key pair
         nickname: Option<String>,
+        #[clap(long)]
+        /// Save the private key without encrypting it
+        unencrypted: bool,
     },
-    /// Generate a new key pair if none exist
+    /// Generate a new key pair if none exist. The private key is encrypted with a password
     GenerateIfMissing {
         #[clap(short, long)]
         /// A nickname for this key pair
         nickname: Option<String>,
+        #[clap(long)]
+        /// Save the private key without encrypting it
+        unencrypted: bool,
     },
     /// Import an existing key pair
     Import {
@@ -49,6 +61,22 @@ pub enum KeyWorkflow<C: sov_modules_api::Context> {
         #[clap(subcommand)]
         identifier: KeyIdentifier<C>,
     },
+    /// Encrypt the private key of a key pair with a new password.
+    /// Unencrypted private keys are encrypted for the first time
+    ChangePassword {
+        /// The identifier of the key to encrypt
+        #[clap(subcommand)]
+        identifier: KeyIdentifier<C>,
+    },
+    /// Export the private key of a key pair, decrypted, in the format accepted by `import`
+    Export {
+        #[clap(short, long)]
+        /// The path to write the key file to (default: print it)
+        output: Option<PathBuf>,
+        /// The identifier of the key to export
+        #[clap(subcommand)]
+        identifier: KeyIdentifier<C>,
+    },
 }
 
 impl<C: sov_modules_api::Context> KeyWorkflow<C> {
@@ -62,8 +90,12 @@ impl<C: sov_modules_api::Context> KeyWorkflow<C> {
         Tx: Serialize + DeserializeOwned + BorshSerialize + BorshDeserialize,
     {
         match self {
-            KeyWorkflow::Generate { nickname } => {
-                generate_and_save_key(nickname, app_dir, wallet_state)?;
+            KeyWorkflow::Generate {
+                nickname,
+                unencrypted,
+            } => {
+                let password = (!unencrypted).then(read_new_password).transpose()?;
+                generate_and_save_key(nickname, password.as_deref(), app_dir, wallet_state)?;
             }
             KeyWorkflow::Import {
                 nickname,
@@ -98,31 +130,153 @@ impl<C: sov_modules_api::Context> KeyWorkflow<C> {
                     })?;
                 println!("Activated key {}", identifier);
             }
-            KeyWorkflow::GenerateIfMissing { nickname } => {
+            KeyWorkflow::GenerateIfMissing {
+                nickname,
+                unencrypted,
+            } => {
                 if wallet_state.addresses.default_address().is_none() {
-                    generate_and_save_key(nickname, app_dir, wallet_state)?;
+                    let password = (!unencrypted).then(read_new_password).transpose()?;
+                    generate_and_save_key(nickname, password.as_deref(), app_dir, wallet_state)?;
                 }
             }
             KeyWorkflow::Remove { identifier } => {
                 wallet_state.addresses.remove(&identifier);
             }
+            KeyWorkflow::ChangePassword { identifier } => {
+                let entry = wallet_state
+                    .addresses
+                    .get_address(&identifier)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Could not find key with identifier {}", identifier)
+                    })?;
+                let key_and_address = load_key_and_address::<C>(&entry.location, read_password)?;
+                let password = read_new_password()?;
+                save_key(&entry.location, &key_and_address, Some(&password))?;
+                println!("Changed the password of key {}", identifier);
+            }
+            KeyWorkflow::Export { output, identifier } => {
+                let entry = wallet_state
+                    .addresses
+                    .get_address(&identifier)
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Could not find key with identifier {}", identifier)
+                    })?;
+                let key_and_address = load_key_and_address::<C>(&entry.location, read_password)?;
+                match output {
+                    Some(path) => {
+                        save_key(&path, &key_and_address, None)?;
+                        println!("Exported key {} to {}", identifier, path.display());
+                    }
+                    None => println!("{}", serde_json::to_string_pretty(&key_and_address)?),
+                }
+            }
         }
         Ok(())
     }
 }
 
-/// Load a key from the given path
+/// Load a key from the given path.
+/// If the key file is encrypted, its password is read from the `SOV_WALLET_PASSWORD`
+/// environment variable, or prompted for.
 pub fn load_key<C: sov_modules_api::Context>(
     path: impl AsRef<Path>,
 ) -> Result<C::PrivateKey, anyhow::Error> {
+    Ok(load_key_and_address::<C>(path, read_password)?.private_key)
+}
+
+/// Load a key and its address from the given path.
+/// If the key file is encrypted, it is decrypted with the password returned by `get_password`.
+pub fn load_key_and_address<C: sov_modules_api::Context>(
+    path: impl AsRef<Path>,
+    get_password: impl FnOnce(&Path) -> Result<String, anyhow::Error>,
+) -> Result<PrivateKeyAndAddress<C>, anyhow::Error> {
+    let path = path.as_ref();
     let data = std::fs::read_to_string(path)?;
-    let key_and_address: PrivateKeyAndAddress<C> = serde_json::from_str(&data)?;
-    Ok(key_and_address.private_key)
+    let Ok(encrypted) = serde_json::from_str::<EncryptedKeyFile>(&data) else {
+        return Ok(serde_json::from_str(&data)?);
+    };
+
+    let password = get_password(path)?;
+    let private_key = serde_json::from_slice(&encrypted.decrypt(&password)?)?;
+    let address = C::Address::from_str(&encrypted.address)
+        .map_err(|e| anyhow::anyhow!("Invalid address in key file: {}", e))?;
+    Ok(PrivateKeyAndAddress {
+        private_key,
+        address,
+    })
+}
+
+/// Save a key and its address to the given path, encrypted with the password if there is one.
+/// The key file is only readable by its owner, and replaces an existing file atomically,
+/// so that the key isn't lost if the wallet is interrupted.
+pub fn save_key<C: sov_modules_api::Context>(
+    path: impl AsRef<Path>,
+    key_and_address: &PrivateKeyAndAddress<C>,
+    password: Option<&str>,
+) -> Result<(), anyhow::Error> {
+    let data = match password {
+        Some(password) => serde_json::to_string_pretty(&EncryptedKeyFile::encrypt(
+            &serde_json::to_vec(&key_and_address.private_key)?,
+            key_and_address.address.to_string(),
+            password,
+        )?)?,
+        None => serde_json::to_string(key_and_address)?,
+    };
+
+    let path = path.as_ref();
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+    // A leftover of an interrupted save is replaced, so that the file is created with the right permissions.
+    if tmp_path.exists() {
+        std::fs::remove_file(&tmp_path)?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(&tmp_path)?;
+    file.write_all(data.as_bytes())?;
+    file.sync_all()?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// Reads the password of the key file at the given path from the `SOV_WALLET_PASSWORD`
+/// environment variable, or prompts for it.
+fn read_password(path: &Path) -> Result<String, anyhow::Error> {
+    if let Ok(password) = std::env::var(SOV_WALLET_PASSWORD_ENV_VAR) {
+        return Ok(password);
+    }
+    Ok(rpassword::prompt_password(format!(
+        "Password of the key file {}: ",
+        path.display()
+    ))?)
+}
+
+/// Reads the password to encrypt a key file with from the `SOV_WALLET_NEW_PASSWORD`
+/// environment variable, or prompts for it twice.
+/// It is distinct from `SOV_WALLET_PASSWORD`, so that changing a password never reuses the old one.
+fn read_new_password() -> Result<String, anyhow::Error> {
+    let password = match std::env::var(SOV_WALLET_NEW_PASSWORD_ENV_VAR) {
+        Ok(password) => password,
+        Err(_) => {
+            let password = rpassword::prompt_password("New password of the key file: ")?;
+            let confirmation = rpassword::prompt_password("Repeat the password: ")?;
+            anyhow::ensure!(password == confirmation, "The passwords don't match");
+            password
+        }
+    };
+    anyhow::ensure!(!password.is_empty(), "The password must not be empty");
+    Ok(password)
 }
 
-/// Generate a new key pair and save it to the wallet
+/// Generate a new key pair and save it to the wallet.
+/// The private key is encrypted with the password if there is one.
 pub fn generate_and_save_key<Tx, C: sov_modules_api::Context>(
     nickname: Option<String>,
+    password: Option<&str>,
     app_dir: impl AsRef<Path>,
     wallet_state: &mut WalletState<Tx, C>,
 ) -> Result<(), anyhow::Error>
@@ -139,7 +293,7 @@ where
         address,
         key_path.display()
     );
-    std::fs::write(&key_path, serde_json::to_string(&key_and_address)?)?;
+    save_key(&key_path, &key_and_address, password)?;
     wallet_state
         .addresses
         .add(address, nickname, public_key, key_path);
@@ -1,8 +1,12 @@
 use demo_stf::runtime::RuntimeCall;
 use sov_cli::wallet_state::{KeyIdentifier, PrivateKeyAndAddress, WalletState};
-use sov_cli::workflows::keys::KeyWorkflow;
+use sov_cli::workflows::keys::{
+    generate_and_save_key, load_key_and_address, save_key, KeyWorkflow,
+};
 use sov_mock_da::MockDaSpec;
 use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::secp256k1_context::Secp256k1Context;
+use sov_modules_api::secp256k1_signature::private_key::Secp256k1PrivateKey;
 use sov_modules_api::{PrivateKey, PublicKey, Spec};
 
 type Da = MockDaSpec;
@@ -12,12 +16,98 @@ fn test_key_gen() {
     let app_dir = tempfile::tempdir().unwrap();
     let mut wallet_state =
         WalletState::<RuntimeCall<DefaultContext, Da>, DefaultContext>::default();
-    let workflow = KeyWorkflow::Generate { nickname: None };
+    let workflow = KeyWorkflow::Generate {
+        nickname: None,
+        unencrypted: true,
+    };
     workflow.run(&mut wallet_state, app_dir).unwrap();
 
     assert!(wallet_state.addresses.default_address().is_some());
 }
 
+#[test]
+fn test_encrypted_key_gen() {
+    let app_dir = tempfile::tempdir().unwrap();
+    let mut wallet_state =
+        WalletState::<RuntimeCall<DefaultContext, Da>, DefaultContext>::default();
+    generate_and_save_key(None, Some("password"), &app_dir, &mut wallet_state).unwrap();
+    let entry = wallet_state.addresses.default_address().unwrap();
+
+    // The private key must not be readable without the password
+    let data = std::fs::read_to_string(&entry.location).unwrap();
+    assert!(serde_json::from_str::<PrivateKeyAndAddress<DefaultContext>>(&data).is_err());
+    assert!(
+        load_key_and_address::<DefaultContext>(&entry.location, |_| Ok("wrong".to_string()))
+            .is_err()
+    );
+
+    let key_and_address =
+        load_key_and_address::<DefaultContext>(&entry.location, |_| Ok("password".to_string()))
+            .unwrap();
+    assert_eq!(key_and_address.address, entry.address);
+    assert!(key_and_address.is_matching_to_default());
+}
+
+#[test]
+fn test_change_password() {
+    let app_dir = tempfile::tempdir().unwrap();
+    let key_path = app_dir.path().join("test_key");
+    let key_and_address = PrivateKeyAndAddress::<DefaultContext>::from_key(
+        <DefaultContext as Spec>::PrivateKey::generate(),
+    );
+
+    // An unencrypted key is loaded without asking for a password
+    save_key(&key_path, &key_and_address, None).unwrap();
+    let loaded = load_key_and_address::<DefaultContext>(&key_path, |_| {
+        panic!("The key file is not encrypted")
+    })
+    .unwrap();
+    assert_eq!(loaded.address, key_and_address.address);
+
+    save_key(&key_path, &key_and_address, Some("old")).unwrap();
+    let loaded =
+        load_key_and_address::<DefaultContext>(&key_path, |_| Ok("old".to_string())).unwrap();
+    save_key(&key_path, &loaded, Some("new")).unwrap();
+
+    assert!(load_key_and_address::<DefaultContext>(&key_path, |_| Ok("old".to_string())).is_err());
+    let loaded =
+        load_key_and_address::<DefaultContext>(&key_path, |_| Ok("new".to_string())).unwrap();
+    assert_eq!(loaded.address, key_and_address.address);
+    assert_eq!(
+        loaded.private_key.pub_key(),
+        key_and_address.private_key.pub_key()
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_key_files_are_private() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let app_dir = tempfile::tempdir().unwrap();
+    let key_and_address = PrivateKeyAndAddress::<DefaultContext>::from_key(
+        <DefaultContext as Spec>::PrivateKey::generate(),
+    );
+
+    // An existing file, such as the destination of an export, doesn't keep its permissions
+    let key_path = app_dir.path().join("exported_key");
+    std::fs::write(&key_path, "").unwrap();
+    std::fs::set_permissions(&key_path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+    for password in [None, Some("password")] {
+        save_key(&key_path, &key_and_address, password).unwrap();
+        let mode = std::fs::metadata(&key_path).unwrap().permissions().mode();
+        assert_eq!(0o600, mode & 0o777);
+    }
+
+    // The key is written to a temporary file which replaces the key file
+    let entries: Vec<_> = std::fs::read_dir(app_dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(vec![std::ffi::OsString::from("exported_key")], entries);
+}
+
 #[test]
 fn test_key_import() {
     let app_dir = tempfile::tempdir().unwrap();
@@ -62,10 +152,12 @@ fn test_activate() {
         WalletState::<RuntimeCall<DefaultContext, Da>, DefaultContext>::default();
     let workflow = KeyWorkflow::Generate {
         nickname: Some("key1".into()),
+        unencrypted: true,
     };
     workflow.run(&mut wallet_state, &app_dir).unwrap();
     let workflow = KeyWorkflow::Generate {
         nickname: Some("key2".into()),
+        unencrypted: true,
     };
     workflow.run(&mut wallet_state, &app_dir).unwrap();
 
@@ -96,3 +188,31 @@ fn test_activate() {
     let current_active_wallet = wallet_state.addresses.default_address().unwrap();
     assert!(current_active_wallet.is_nicknamed("key1"));
 }
+
+#[test]
+fn test_secp256k1_key_import() {
+    type C = Secp256k1Context;
+
+    let app_dir = tempfile::tempdir().unwrap();
+    // A key exported from an Ethereum wallet, and its Ethereum address
+    let key = Secp256k1PrivateKey::from_hex(
+        "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+    )
+    .unwrap();
+    let eth_address = hex::decode("f39fd6e51aad88f6f4ce6ab8827279cfffb92266").unwrap();
+    let key_path = app_dir.path().join("test_key");
+    save_key(&key_path, &PrivateKeyAndAddress::<C>::from_key(key), None).unwrap();
+
+    let mut wallet_state = WalletState::<RuntimeCall<C, Da>, C>::default();
+    let workflow = KeyWorkflow::Import {
+        nickname: None,
+        address_override: None,
+        path: key_path.clone(),
+    };
+    workflow.run(&mut wallet_state, &app_dir).unwrap();
+
+    let entry = wallet_state.addresses.default_address().unwrap();
+    assert_eq!(&entry.address.as_ref()[12..], &eth_address[..]);
+    let loaded = load_key_and_address::<C>(&key_path, |_| unreachable!()).unwrap();
+    assert!(loaded.is_matching_to_default());
+}
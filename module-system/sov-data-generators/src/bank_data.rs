@@ -431,6 +431,65 @@ impl MessageGenerator for BadSignatureBankCallMessages {
     }
 }
 
+pub struct BadChainIdBankCallMessages;
+
+impl BadChainIdBankCallMessages {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for BadChainIdBankCallMessages {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageGenerator for BadChainIdBankCallMessages {
+    type Module = Bank<DefaultContext>;
+    type Context = DefaultContext;
+
+    fn create_messages(&self) -> Vec<Message<Self::Context, Self::Module>> {
+        let mut messages = Vec::<Message<DefaultContext, Bank<DefaultContext>>>::new();
+        let minter_key = DefaultPrivateKey::from_hex(DEFAULT_PVT_KEY).unwrap();
+        let minter_address = minter_key.default_address();
+        let salt = DEFAULT_SALT;
+        let token_name = DEFAULT_TOKEN_NAME.to_owned();
+        messages.push(Message::new(
+            Rc::new(DefaultPrivateKey::from_hex(DEFAULT_PVT_KEY).unwrap()),
+            CallMessage::CreateToken {
+                salt,
+                token_name,
+                initial_balance: 1000,
+                minter_address,
+                authorized_minters: Vec::from([minter_address]),
+            },
+            // A transaction signed for some other chain
+            DEFAULT_CHAIN_ID + 1,
+            DEFAULT_GAS_TIP,
+            DEFAULT_GAS_LIMIT,
+            0,
+        ));
+        messages
+    }
+
+    fn create_tx<Encoder: EncodeCall<Self::Module>>(
+        &self,
+        sender: &DefaultPrivateKey,
+        message: <Bank<DefaultContext> as Module>::CallMessage,
+        chain_id: u64,
+        gas_tip: u64,
+        gas_limit: u64,
+        nonce: u64,
+        _is_last: bool,
+    ) -> Transaction<DefaultContext> {
+        let message = Encoder::encode_call(message);
+        Transaction::<DefaultContext>::new_signed_tx(
+            sender, message, chain_id, gas_tip, gas_limit, nonce,
+        )
+    }
+}
+
 pub struct BadNonceBankCallMessages;
 
 impl BadNonceBankCallMessages {
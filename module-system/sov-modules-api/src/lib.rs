@@ -7,6 +7,8 @@ pub mod default_context;
 pub mod default_signature;
 pub mod hooks;
 mod pub_key_hex;
+pub mod secp256k1_context;
+pub mod secp256k1_signature;
 
 #[cfg(feature = "macros")]
 mod reexport_macros;
@@ -33,9 +35,10 @@ pub use clap;
 pub use sov_modules_core::PrivateKey;
 pub use sov_modules_core::{
     archival_state, runtime, AccessoryWorkingSet, Address, AddressBech32, CallResponse, Context,
-    DispatchCall, EncodeCall, GasUnit, Genesis, KernelModule, KernelWorkingSet, Module,
-    ModuleCallJsonSchema, ModuleError, ModuleError as Error, ModuleInfo, ModulePrefix, PublicKey,
-    Signature, Spec, StateCheckpoint, StateReaderAndWriter, VersionedWorkingSet, WorkingSet,
+    DecodeEvent, DispatchCall, EncodeCall, EventName, GasUnit, Genesis, KernelModule,
+    KernelWorkingSet, Module, ModuleCallJsonSchema, ModuleError, ModuleError as Error, ModuleInfo,
+    ModulePrefix, PublicKey, Signature, Spec, StateCheckpoint, StateReaderAndWriter,
+    VersionedWorkingSet, WorkingSet,
 };
 pub use sov_rollup_interface::da::{BlobReaderTrait, DaSpec};
 pub use sov_rollup_interface::services::da::SlotData;
@@ -5,6 +5,10 @@ pub use sov_modules_macros::DispatchCall;
 /// Derives the <runtime_name>Event enum for a given runtime.
 #[cfg(feature = "macros")]
 pub use sov_modules_macros::Event;
+/// Derives the [`EventName`](trait.EventName.html) trait for the event enum of a module,
+/// returning the name of the variant.
+#[cfg(feature = "macros")]
+pub use sov_modules_macros::EventName;
 /// Derives the [`Genesis`](trait.Genesis.html) trait for the underlying runtime
 /// `struct`.
 #[cfg(feature = "macros")]
@@ -0,0 +1,154 @@
+//! Contexts using secp256k1 signatures and Ethereum-style addresses,
+//! for rollups whose users sign with the keys of their Ethereum wallets.
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sov_modules_core::{Address, Context, PublicKey, Spec, TupleGasUnit};
+use sov_rollup_interface::RollupAddress;
+#[cfg(feature = "native")]
+use sov_state::ProverStorage;
+use sov_state::{ArrayWitness, DefaultStorageSpec, ZkStorage};
+
+#[cfg(feature = "native")]
+use crate::secp256k1_signature::private_key::Secp256k1PrivateKey;
+use crate::secp256k1_signature::{Secp256k1PublicKey, Secp256k1Signature};
+
+#[cfg(feature = "native")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Secp256k1Context {
+    pub sender: Address,
+    pub sequencer: Address,
+    /// The height to report. This is set by the kernel when the context is created
+    visible_height: u64,
+}
+
+#[cfg(feature = "native")]
+impl Spec for Secp256k1Context {
+    type Address = Address;
+    type Storage = ProverStorage<DefaultStorageSpec, sov_prover_storage_manager::SnapshotManager>;
+    type PrivateKey = Secp256k1PrivateKey;
+    type PublicKey = Secp256k1PublicKey;
+    type Hasher = sha2::Sha256;
+    type Signature = Secp256k1Signature;
+    type Witness = ArrayWitness;
+}
+
+#[cfg(feature = "native")]
+impl Context for Secp256k1Context {
+    type GasUnit = TupleGasUnit<2>;
+
+    fn sender(&self) -> &Self::Address {
+        &self.sender
+    }
+
+    fn sequencer(&self) -> &Self::Address {
+        &self.sequencer
+    }
+
+    fn new(sender: Self::Address, sequencer: Self::Address, height: u64) -> Self {
+        Self {
+            sender,
+            sequencer,
+            visible_height: height,
+        }
+    }
+
+    fn slot_height(&self) -> u64 {
+        self.visible_height
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ZkSecp256k1Context {
+    pub sender: Address,
+    pub sequencer: Address,
+    /// The height to report. This is set by the kernel when the context is created
+    visible_height: u64,
+}
+
+impl Spec for ZkSecp256k1Context {
+    type Address = Address;
+    type Storage = ZkStorage<DefaultStorageSpec>;
+    #[cfg(feature = "native")]
+    type PrivateKey = Secp256k1PrivateKey;
+    type PublicKey = Secp256k1PublicKey;
+    type Hasher = sha2::Sha256;
+    type Signature = Secp256k1Signature;
+    type Witness = ArrayWitness;
+}
+
+impl Context for ZkSecp256k1Context {
+    type GasUnit = TupleGasUnit<2>;
+
+    fn sender(&self) -> &Self::Address {
+        &self.sender
+    }
+
+    fn sequencer(&self) -> &Self::Address {
+        &self.sequencer
+    }
+
+    fn new(sender: Self::Address, sequencer: Self::Address, height: u64) -> Self {
+        Self {
+            sender,
+            sequencer,
+            visible_height: height,
+        }
+    }
+
+    fn slot_height(&self) -> u64 {
+        self.visible_height
+    }
+}
+
+/// The address of a secp256k1 public key is its Ethereum address, left-padded with zeros to 32 bytes,
+/// so an account can be recognized from the address shown by the wallet holding the key.
+impl PublicKey for Secp256k1PublicKey {
+    fn to_address<A: RollupAddress>(&self) -> A {
+        let mut address = [0; 32];
+        address[12..].copy_from_slice(&self.eth_address());
+        A::from(address)
+    }
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use sov_modules_core::PrivateKey;
+
+    use super::*;
+    use crate::transaction::Transaction;
+
+    #[test]
+    fn test_transaction_verify() {
+        let priv_key = Secp256k1PrivateKey::generate();
+        let tx =
+            Transaction::<Secp256k1Context>::new_signed_tx(&priv_key, vec![1, 2, 3], 0, 0, 0, 0);
+        assert!(tx.verify().is_ok());
+
+        let tx =
+            Transaction::<Secp256k1Context>::try_from_slice(&tx.try_to_vec().unwrap()).unwrap();
+        assert!(tx.verify().is_ok());
+
+        let other_key = Secp256k1PrivateKey::generate().pub_key();
+        let forged = Transaction::<Secp256k1Context>::new(
+            other_key,
+            tx.runtime_msg().to_vec(),
+            tx.signature().clone(),
+            0,
+            0,
+            0,
+            0,
+        );
+        assert!(forged.verify().is_err());
+    }
+
+    #[test]
+    fn test_address_is_eth_address() {
+        let pub_key = Secp256k1PrivateKey::generate().pub_key();
+        let address = pub_key.to_address::<<Secp256k1Context as Spec>::Address>();
+        assert_eq!([0u8; 12], address.as_ref()[..12]);
+        assert_eq!(pub_key.eth_address(), address.as_ref()[12..]);
+    }
+}
@@ -0,0 +1,523 @@
+//! Recoverable secp256k1 ECDSA signatures, as used by Ethereum wallets.
+//!
+//! Messages are signed like Ethereum wallets sign them with `personal_sign` (EIP-191):
+//! the keccak-256 hash of `"\x19Ethereum Signed Message:\n"`, the decimal length of the message
+//! and the message is signed. Signatures are serialized as the 65 bytes `r || s || v`,
+//! where `v` is the recovery id.
+use std::hash::Hash;
+#[cfg(feature = "native")]
+use std::str::FromStr;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use k256::ecdsa::{RecoveryId, Signature as EcdsaSignature, VerifyingKey};
+use sha3::{Digest, Keccak256};
+use sov_modules_core::{SigVerificationError, Signature};
+
+/// The length of a compressed SEC1-encoded public key.
+pub const PUBLIC_KEY_LENGTH: usize = 33;
+/// The length of a serialized [`Secp256k1Signature`].
+pub const SIGNATURE_LENGTH: usize = 65;
+
+#[cfg(feature = "native")]
+pub mod private_key {
+    use k256::ecdsa::SigningKey;
+    use rand::rngs::OsRng;
+    use sov_modules_core::{Address, PrivateKey, PublicKey};
+
+    use super::{eth_message_hash, Secp256k1PublicKey, Secp256k1Signature};
+
+    /// The length of a secp256k1 private key.
+    pub const SECRET_KEY_LENGTH: usize = 32;
+
+    /// A private key for the secp256k1 signature scheme.
+    #[derive(Clone)]
+    pub struct Secp256k1PrivateKey {
+        key: SigningKey,
+    }
+
+    impl core::fmt::Debug for Secp256k1PrivateKey {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("Secp256k1PrivateKey")
+                .field("public_key", &self.key.verifying_key())
+                .field("private_key", &"***REDACTED***")
+                .finish()
+        }
+    }
+
+    impl TryFrom<&[u8]> for Secp256k1PrivateKey {
+        type Error = anyhow::Error;
+
+        fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+            if value.len() != SECRET_KEY_LENGTH {
+                anyhow::bail!(
+                    "Invalid private key length: {}, expected {}",
+                    value.len(),
+                    SECRET_KEY_LENGTH
+                );
+            }
+            let key = SigningKey::from_slice(value).map_err(anyhow::Error::msg)?;
+            Ok(Self { key })
+        }
+    }
+
+    impl PrivateKey for Secp256k1PrivateKey {
+        type PublicKey = Secp256k1PublicKey;
+
+        type Signature = Secp256k1Signature;
+
+        fn generate() -> Self {
+            Self {
+                key: SigningKey::random(&mut OsRng),
+            }
+        }
+
+        fn pub_key(&self) -> Self::PublicKey {
+            Secp256k1PublicKey {
+                pub_key: *self.key.verifying_key(),
+            }
+        }
+
+        fn sign(&self, msg: &[u8]) -> Self::Signature {
+            let (msg_sig, recovery_id) = self
+                .key
+                .sign_prehash_recoverable(&eth_message_hash(msg))
+                .expect("Signing a 32 bytes hash is infallible");
+            Secp256k1Signature {
+                msg_sig,
+                recovery_id,
+            }
+        }
+    }
+
+    impl Secp256k1PrivateKey {
+        pub fn as_hex(&self) -> String {
+            hex::encode(self.key.to_bytes())
+        }
+
+        /// Parses a hex encoded private key, such as the ones exported by Ethereum wallets.
+        /// The `0x` prefix is optional.
+        pub fn from_hex(hex: &str) -> anyhow::Result<Self> {
+            let bytes = hex::decode(hex.strip_prefix("0x").unwrap_or(hex))?;
+            Self::try_from(&bytes[..])
+        }
+
+        pub fn default_address(&self) -> Address {
+            self.pub_key().to_address::<Address>()
+        }
+    }
+
+    impl serde::Serialize for Secp256k1PrivateKey {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            if serializer.is_human_readable() {
+                serde::Serialize::serialize(&self.as_hex(), serializer)
+            } else {
+                serde::Serialize::serialize(
+                    &<[u8; SECRET_KEY_LENGTH]>::from(self.key.to_bytes()),
+                    serializer,
+                )
+            }
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for Secp256k1PrivateKey {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            if deserializer.is_human_readable() {
+                let hex: String = serde::Deserialize::deserialize(deserializer)?;
+                Self::from_hex(&hex).map_err(serde::de::Error::custom)
+            } else {
+                let bytes: [u8; SECRET_KEY_LENGTH] = serde::Deserialize::deserialize(deserializer)?;
+                Self::try_from(&bytes[..]).map_err(serde::de::Error::custom)
+            }
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    impl<'a> arbitrary::Arbitrary<'a> for Secp256k1PrivateKey {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            use rand::rngs::StdRng;
+            use rand::SeedableRng;
+
+            // the key is generated deterministically from the arbitrary argument,
+            // so keys and signatures are reproducible for a given seed
+            let seed = <[u8; 32]>::arbitrary(u)?;
+            let rng = &mut StdRng::from_seed(seed);
+
+            Ok(Self {
+                key: SigningKey::random(rng),
+            })
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    impl<'a> arbitrary::Arbitrary<'a> for Secp256k1PublicKey {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            Secp256k1PrivateKey::arbitrary(u).map(|p| p.pub_key())
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    impl<'a> arbitrary::Arbitrary<'a> for Secp256k1Signature {
+        fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+            let payload_len = u.arbitrary_len::<u8>()?;
+            let payload = u.bytes(payload_len)?;
+            Secp256k1PrivateKey::arbitrary(u).map(|s| s.sign(payload))
+        }
+    }
+}
+
+/// A secp256k1 public key, serialized in the compressed SEC1 format.
+#[cfg_attr(feature = "native", derive(schemars::JsonSchema))]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Secp256k1PublicKey {
+    #[cfg_attr(
+        feature = "native",
+        schemars(with = "&[u8]", length(equal = "PUBLIC_KEY_LENGTH"))
+    )]
+    pub(crate) pub_key: VerifyingKey,
+}
+
+impl Secp256k1PublicKey {
+    /// Returns the public key in the compressed SEC1 format.
+    pub fn to_bytes(&self) -> [u8; PUBLIC_KEY_LENGTH] {
+        let mut bytes = [0; PUBLIC_KEY_LENGTH];
+        bytes.copy_from_slice(self.pub_key.to_encoded_point(true).as_bytes());
+        bytes
+    }
+
+    /// Returns the Ethereum address of the public key:
+    /// the last 20 bytes of the keccak-256 hash of the uncompressed key.
+    pub fn eth_address(&self) -> [u8; 20] {
+        let uncompressed = self.pub_key.to_encoded_point(false);
+        // Skips the SEC1 tag byte.
+        let hash = keccak256(&uncompressed.as_bytes()[1..]);
+        let mut address = [0; 20];
+        address.copy_from_slice(&hash[12..]);
+        address
+    }
+}
+
+impl Hash for Secp256k1PublicKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.to_bytes().hash(state);
+    }
+}
+
+impl BorshDeserialize for Secp256k1PublicKey {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut buffer = [0; PUBLIC_KEY_LENGTH];
+        reader.read_exact(&mut buffer)?;
+
+        Self::try_from(&buffer[..]).map_err(map_error)
+    }
+}
+
+impl BorshSerialize for Secp256k1PublicKey {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256k1PublicKey {
+    type Error = anyhow::Error;
+
+    /// Accepts both the compressed and the uncompressed SEC1 formats.
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pub_key: VerifyingKey::from_sec1_bytes(value).map_err(anyhow::Error::msg)?,
+        })
+    }
+}
+
+impl serde::Serialize for Secp256k1PublicKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serde::Serialize::serialize(&hex::encode(self.to_bytes()), serializer)
+        } else {
+            serde::Serialize::serialize(&self.to_bytes()[..], serializer)
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Secp256k1PublicKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bytes = if deserializer.is_human_readable() {
+            let hex: String = serde::Deserialize::deserialize(deserializer)?;
+            hex::decode(hex).map_err(serde::de::Error::custom)?
+        } else {
+            serde::Deserialize::deserialize(deserializer)?
+        };
+        Self::try_from(&bytes[..]).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A recoverable secp256k1 ECDSA signature of the EIP-191 hash of a message.
+#[cfg_attr(feature = "native", derive(schemars::JsonSchema))]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Secp256k1Signature {
+    #[cfg_attr(
+        feature = "native",
+        schemars(with = "&[u8]", length(equal = "SIGNATURE_LENGTH"))
+    )]
+    pub msg_sig: EcdsaSignature,
+    #[cfg_attr(feature = "native", schemars(with = "u8"))]
+    pub recovery_id: RecoveryId,
+}
+
+impl Secp256k1Signature {
+    /// Returns the signature as the 65 bytes `r || s || v`.
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_LENGTH] {
+        let mut bytes = [0; SIGNATURE_LENGTH];
+        bytes[..64].copy_from_slice(&self.msg_sig.to_bytes());
+        bytes[64] = self.recovery_id.to_byte();
+        bytes
+    }
+
+    /// Recovers the public key which signed the message.
+    pub fn recover(&self, msg: &[u8]) -> Result<Secp256k1PublicKey, SigVerificationError> {
+        // Rejects the malleable form of the signature, as Ethereum does.
+        if self.msg_sig.normalize_s().is_some() {
+            return Err(SigVerificationError::BadSignature(
+                "The signature is not normalized to a low S value".to_string(),
+            ));
+        }
+        let pub_key = VerifyingKey::recover_from_prehash(
+            &eth_message_hash(msg),
+            &self.msg_sig,
+            self.recovery_id,
+        )
+        .map_err(|e| SigVerificationError::BadSignature(e.to_string()))?;
+        Ok(Secp256k1PublicKey { pub_key })
+    }
+}
+
+impl BorshDeserialize for Secp256k1Signature {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut buffer = [0; SIGNATURE_LENGTH];
+        reader.read_exact(&mut buffer)?;
+
+        Self::try_from(&buffer[..]).map_err(map_error)
+    }
+}
+
+impl BorshSerialize for Secp256k1Signature {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+}
+
+impl TryFrom<&[u8]> for Secp256k1Signature {
+    type Error = anyhow::Error;
+
+    /// Accepts the recovery id either as `0`/`1`, or as `27`/`28` like Ethereum wallets do.
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() != SIGNATURE_LENGTH {
+            anyhow::bail!(
+                "Invalid signature length: {}, expected {}",
+                value.len(),
+                SIGNATURE_LENGTH
+            );
+        }
+        let v = value[64];
+        let recovery_id = RecoveryId::from_byte(if v >= 27 { v - 27 } else { v })
+            .ok_or_else(|| anyhow::anyhow!("Invalid recovery id {}", v))?;
+        Ok(Self {
+            msg_sig: EcdsaSignature::from_slice(&value[..64]).map_err(anyhow::Error::msg)?,
+            recovery_id,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Secp256k1Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serde::Serialize::serialize(&hex::encode(self.to_bytes()), serializer)
+        } else {
+            serde::Serialize::serialize(&self.to_bytes()[..], serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Secp256k1Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bytes = if deserializer.is_human_readable() {
+            let hex: String = serde::Deserialize::deserialize(deserializer)?;
+            hex::decode(hex).map_err(serde::de::Error::custom)?
+        } else {
+            serde::Deserialize::deserialize(deserializer)?
+        };
+        Self::try_from(&bytes[..]).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Signature for Secp256k1Signature {
+    type PublicKey = Secp256k1PublicKey;
+
+    fn verify(&self, pub_key: &Self::PublicKey, msg: &[u8]) -> Result<(), SigVerificationError> {
+        let recovered = self.recover(msg)?;
+        if &recovered != pub_key {
+            return Err(SigVerificationError::BadSignature(
+                "The signature was not made by the public key".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Returns the hash signed by Ethereum wallets for a message (EIP-191, version `0x45`):
+/// the keccak-256 hash of the message prefixed with `"\x19Ethereum Signed Message:\n"`
+/// and its length in decimal.
+pub fn eth_message_hash(msg: &[u8]) -> [u8; 32] {
+    Keccak256::new()
+        .chain_update(b"\x19Ethereum Signed Message:\n")
+        .chain_update(msg.len().to_string().as_bytes())
+        .chain_update(msg)
+        .finalize()
+        .into()
+}
+
+#[cfg(feature = "native")]
+fn map_error(e: anyhow::Error) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
+}
+#[cfg(not(feature = "native"))]
+fn map_error(_e: anyhow::Error) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Other, "Signature error")
+}
+
+#[cfg(feature = "native")]
+impl FromStr for Secp256k1PublicKey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s))?;
+        Self::try_from(&bytes[..])
+    }
+}
+
+#[cfg(feature = "native")]
+impl FromStr for Secp256k1Signature {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s))?;
+        Self::try_from(&bytes[..])
+    }
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use sov_modules_core::PrivateKey;
+
+    use super::private_key::Secp256k1PrivateKey;
+    use super::*;
+
+    // The first account of the "test test ... junk" mnemonic used by Ethereum development tools.
+    const ETH_PRIVATE_KEY: &str =
+        "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+    const ETH_ADDRESS: &str = "f39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+
+    #[test]
+    fn test_eth_address() {
+        let key = Secp256k1PrivateKey::from_hex(ETH_PRIVATE_KEY).unwrap();
+        assert_eq!(ETH_ADDRESS, hex::encode(key.pub_key().eth_address()));
+    }
+
+    #[test]
+    fn test_eth_signed_message() {
+        // The `personal_sign` example of the web3.js documentation.
+        let key = Secp256k1PrivateKey::from_hex(
+            "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+        )
+        .unwrap();
+        let msg = b"Some data";
+        let signature = Secp256k1Signature::from_str(
+            "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c",
+        )
+        .unwrap();
+
+        assert_eq!(
+            "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655",
+            hex::encode(eth_message_hash(msg))
+        );
+        assert_eq!(signature, key.sign(msg));
+        let recovered = signature.recover(msg).unwrap();
+        assert_eq!(
+            "2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+            hex::encode(recovered.eth_address())
+        );
+    }
+
+    #[test]
+    fn test_sign_and_recover() {
+        let key = Secp256k1PrivateKey::generate();
+        let signature = key.sign(b"message");
+
+        assert_eq!(key.pub_key(), signature.recover(b"message").unwrap());
+        assert!(signature.verify(&key.pub_key(), b"message").is_ok());
+        assert!(signature.verify(&key.pub_key(), b"other message").is_err());
+        let other_key = Secp256k1PrivateKey::generate();
+        assert!(signature.verify(&other_key.pub_key(), b"message").is_err());
+    }
+
+    #[test]
+    fn test_high_s_signature_is_rejected() {
+        let key = Secp256k1PrivateKey::generate();
+        let signature = key.sign(b"message");
+        let (r, s) = signature.msg_sig.split_scalars();
+        let malleated = Secp256k1Signature {
+            msg_sig: EcdsaSignature::from_scalars(r, -s).unwrap(),
+            recovery_id: RecoveryId::from_byte(signature.recovery_id.to_byte() ^ 1).unwrap(),
+        };
+        assert!(malleated.verify(&key.pub_key(), b"message").is_err());
+    }
+
+    #[test]
+    fn test_serialization_round_trip() {
+        let key = Secp256k1PrivateKey::generate();
+        let signature = key.sign(b"message");
+
+        let bytes = signature.try_to_vec().unwrap();
+        assert_eq!(SIGNATURE_LENGTH, bytes.len());
+        assert_eq!(
+            signature,
+            Secp256k1Signature::try_from_slice(&bytes).unwrap()
+        );
+
+        let pub_key = key.pub_key();
+        let json = serde_json::to_string(&pub_key).unwrap();
+        assert_eq!(pub_key, serde_json::from_str(&json).unwrap());
+        let bytes = pub_key.try_to_vec().unwrap();
+        assert_eq!(pub_key, Secp256k1PublicKey::try_from_slice(&bytes).unwrap());
+
+        let json = serde_json::to_string(&key).unwrap();
+        let output: Secp256k1PrivateKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(key.as_hex(), output.as_hex());
+        let serialized = bincode::serialize(&key).unwrap();
+        let output: Secp256k1PrivateKey = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(key.as_hex(), output.as_hex());
+    }
+}
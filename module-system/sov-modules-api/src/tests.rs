@@ -60,6 +60,10 @@ impl crate::ModuleInfo for Module {
     }
 
     fn prefix(&self) -> crate::ModulePrefix {
+        Self::module_prefix()
+    }
+
+    fn module_prefix() -> crate::ModulePrefix {
         crate::ModulePrefix::new_module(module_path!(), "Module")
     }
 
@@ -204,6 +204,12 @@ impl ModulePrefix {
         combined_prefix
     }
 
+    /// Returns the combined prefix: the module path, the module name and the storage name,
+    /// each followed by `/`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.combine_prefix()
+    }
+
     /// Returns the hash of the combined prefix.
     pub fn hash<C: Context>(&self) -> [u8; 32] {
         let combined_prefix = self.combine_prefix();
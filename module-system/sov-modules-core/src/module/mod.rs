@@ -5,6 +5,7 @@ use alloc::vec::Vec;
 use core::fmt::Debug;
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use sov_rollup_interface::stf::Event;
 
 use crate::common::{ModuleError, ModulePrefix};
 use crate::storage::WorkingSet;
@@ -32,7 +33,7 @@ pub trait Module {
     type CallMessage: Debug + BorshSerialize + BorshDeserialize;
 
     /// Module defined event resulting from a call method.
-    type Event: Debug + BorshSerialize + BorshDeserialize;
+    type Event: Debug + BorshSerialize + BorshDeserialize + EventName;
 
     /// Genesis is called when a rollup is deployed and can be used to set initial state values in the module.
     fn genesis(
@@ -87,10 +88,44 @@ pub trait ModuleInfo {
     /// Returns the prefix of the module.
     fn prefix(&self) -> ModulePrefix;
 
+    /// Returns the prefix of the module, without a module instance.
+    fn module_prefix() -> ModulePrefix
+    where
+        Self: Sized;
+
     /// Returns addresses of all the other modules this module is dependent on
     fn dependencies(&self) -> Vec<&<Self::Context as Spec>::Address>;
 }
 
+/// The name of a module event, used in its key by [`WorkingSet::emit_event`].
+/// It is implemented for the event enum of a module with `#[derive(EventName)]`,
+/// and returns the name of the variant.
+pub trait EventName {
+    /// Returns the name of the event.
+    fn event_name(&self) -> &'static str;
+}
+
+/// A module without events never emits one.
+impl EventName for () {
+    fn event_name(&self) -> &'static str {
+        ""
+    }
+}
+
+/// Decodes the typed events emitted by the modules of a runtime with [`WorkingSet::emit_event`].
+/// It is implemented by the event enum of the runtime, generated with `#[derive(Event)]`.
+pub trait DecodeEvent: Sized {
+    /// Decodes `event`, or returns `None` if it wasn't emitted by a module of the runtime.
+    fn decode_event(event: &Event) -> Option<Self>;
+}
+
+/// A runtime without event enum doesn't decode any event.
+impl DecodeEvent for () {
+    fn decode_event(_event: &Event) -> Option<Self> {
+        None
+    }
+}
+
 /// A trait that specifies how a runtime should encode the data for each module
 pub trait EncodeCall<M: Module> {
     /// The encoding function
@@ -33,6 +33,10 @@ pub trait Kernel<C: Context, Da: DaSpec>: BlobSelector<Da, Context = C> + Defaul
     fn true_height(&self, working_set: &mut WorkingSet<C>) -> u64;
     /// Return the height at which transactions currently *appear* to be executing.
     fn visible_height(&self, working_set: &mut WorkingSet<C>) -> u64;
+    /// Return the ID of the chain. Transactions signed for any other chain must be rejected.
+    fn chain_id(&self, working_set: &mut WorkingSet<C>) -> u64;
+    /// Return the base fee per gas unit that applies to the transactions of the current slot.
+    fn base_fee_per_gas(&self, working_set: &mut WorkingSet<C>) -> C::GasUnit;
 }
 
 /// Hooks allowing the kernel to get access to the DA layer state
@@ -45,8 +49,12 @@ pub trait KernelSlotHooks<C: Context, Da: DaSpec>: Kernel<C, Da> {
         pre_state_root: &<<Self::Context as Spec>::Storage as Storage>::Root,
         working_set: &mut WorkingSet<Self::Context>,
     );
-    /// Called at the end of a slot
-    fn end_slot_hook(&self, working_set: &mut WorkingSet<Self::Context>);
+    /// Called at the end of a slot, with the total gas consumed by the transactions of the slot
+    fn end_slot_hook(
+        &self,
+        gas_used: &<Self::Context as Context>::GasUnit,
+        working_set: &mut WorkingSet<Self::Context>,
+    );
 }
 
 /// BlobSelector decides which blobs to process in a current slot.
@@ -114,7 +122,7 @@ pub mod mocks {
     use sov_rollup_interface::da::DaSpec;
 
     use super::{BlobRefOrOwned, BlobSelector, Kernel};
-    use crate::{Context, WorkingSet};
+    use crate::{Context, GasUnit, WorkingSet};
 
     /// A mock kernel for use in tests
     #[derive(Debug, Clone)]
@@ -123,6 +131,8 @@ pub mod mocks {
         pub true_height: u64,
         /// The height at which transactions appear to be executing
         pub visible_height: u64,
+        /// The ID of the chain
+        pub chain_id: u64,
         phantom: core::marker::PhantomData<(C, Da)>,
     }
 
@@ -131,6 +141,7 @@ pub mod mocks {
             Self {
                 true_height: 0,
                 visible_height: 0,
+                chain_id: 0,
                 phantom: Default::default(),
             }
         }
@@ -142,6 +153,7 @@ pub mod mocks {
             Self {
                 true_height,
                 visible_height,
+                chain_id: 0,
                 phantom: core::marker::PhantomData,
             }
         }
@@ -154,6 +166,12 @@ pub mod mocks {
         fn visible_height(&self, _ws: &mut WorkingSet<C>) -> u64 {
             self.visible_height
         }
+        fn chain_id(&self, _ws: &mut WorkingSet<C>) -> u64 {
+            self.chain_id
+        }
+        fn base_fee_per_gas(&self, _ws: &mut WorkingSet<C>) -> C::GasUnit {
+            C::GasUnit::ZEROED
+        }
 
         type GenesisConfig = ();
 
@@ -9,7 +9,7 @@ use sov_rollup_interface::stf::Event;
 
 use crate::archival_state::{ArchivalAccessoryWorkingSet, ArchivalJmtWorkingSet};
 use crate::common::{GasMeter, Prefix};
-use crate::module::{Context, Spec};
+use crate::module::{Context, EventName, Module, ModuleInfo, Spec};
 use crate::storage::{
     CacheKey, CacheValue, EncodeKeyLike, NativeStorage, OrderedReadsAndWrites, StateCodec,
     StateValueCodec, Storage, StorageInternalCache, StorageKey, StorageProof, StorageValue,
@@ -444,6 +444,19 @@ impl<C: Context> WorkingSet<C> {
         self.events.push(Event::new(key, value));
     }
 
+    /// Adds a typed event emitted by the module `M` to the working set.
+    /// The event is borsh-encoded, and its key is the prefix of the module followed by the name
+    /// of the event, so that it can be filtered by name and decoded with the event enum of the runtime.
+    pub fn emit_event<M>(&mut self, event: M::Event)
+    where
+        M: Module<Context = C> + ModuleInfo,
+    {
+        let mut key = M::module_prefix().to_bytes();
+        key.extend_from_slice(event.event_name().as_bytes());
+        let value = borsh::to_vec(&event).expect("Serialization to vec is infallible");
+        self.events.push(Event::from_bytes(key, value));
+    }
+
     /// Extracts all events from this working set.
     pub fn take_events(&mut self) -> Vec<Event> {
         mem::take(&mut self.events)
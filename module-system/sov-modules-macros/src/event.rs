@@ -26,6 +26,40 @@ impl<'a> StructDef<'a> {
             })
             .collect()
     }
+
+    fn create_decode_event(&self) -> proc_macro2::TokenStream {
+        let enum_ident = self.enum_ident(EVENT);
+        let impl_generics = &self.impl_generics;
+        let type_generics = &self.type_generics;
+        let where_clause = self.where_clause;
+
+        let decode_legs = self.fields.iter().map(|field| {
+            let name = &field.ident;
+            let ty = &field.ty;
+
+            quote::quote!(
+                let prefix = <#ty as ::sov_modules_api::ModuleInfo>::module_prefix().to_bytes();
+                if let ::core::option::Option::Some(event_name) = key.strip_prefix(prefix.as_slice()) {
+                    return <<#ty as ::sov_modules_api::Module>::Event as ::borsh::BorshDeserialize>::try_from_slice(
+                        event.value().inner(),
+                    )
+                    .ok()
+                    .filter(|decoded| ::sov_modules_api::EventName::event_name(decoded).as_bytes() == event_name)
+                    .map(#enum_ident::#name);
+                }
+            )
+        });
+
+        quote::quote! {
+            impl #impl_generics ::sov_modules_api::DecodeEvent for #enum_ident #type_generics #where_clause {
+                fn decode_event(event: &::sov_modules_api::Event) -> ::core::option::Option<Self> {
+                    let key = event.key().inner();
+                    #(#decode_legs)*
+                    ::core::option::Option::None
+                }
+            }
+        }
+    }
 }
 
 impl EventMacro {
@@ -64,11 +98,55 @@ impl EventMacro {
 
         let event_enum_legs = struct_def.create_event_enum_legs();
         let event_enum = struct_def.create_enum(&event_enum_legs, EVENT, &serialization_methods);
+        let decode_event = struct_def.create_decode_event();
 
         Ok(quote::quote! {
             #[doc="This enum is generated from the underlying Runtime, the variants correspond to events from the relevant modules"]
             #event_enum
+
+            #decode_event
         }
             .into())
     }
 }
+
+/// Implements `EventName` for the event enum of a module, returning the name of each variant.
+pub(crate) fn derive_event_name(input: DeriveInput) -> Result<proc_macro::TokenStream, syn::Error> {
+    let DeriveInput {
+        data,
+        ident,
+        generics,
+        ..
+    } = input;
+
+    let variants = match data {
+        syn::Data::Enum(data_enum) => data_enum.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "The EventName macro can only be derived for enums",
+            ))
+        }
+    };
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let match_legs = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        let variant_name = variant_ident.to_string();
+
+        quote::quote!(
+            Self::#variant_ident { .. } => #variant_name,
+        )
+    });
+
+    Ok(quote::quote! {
+        impl #impl_generics ::sov_modules_api::EventName for #ident #type_generics #where_clause {
+            fn event_name(&self) -> &'static str {
+                match *self {
+                    #(#match_legs)*
+                }
+            }
+        }
+    }
+    .into())
+}
@@ -30,7 +30,7 @@ use default_runtime::DefaultRuntimeMacro;
 use dispatch::dispatch_call::DispatchCallMacro;
 use dispatch::genesis::GenesisMacro;
 use dispatch::message_codec::MessageCodec;
-use event::EventMacro;
+use event::{derive_event_name, EventMacro};
 use make_constants::{make_const, PartialItemConst};
 use module_call_json_schema::derive_module_call_json_schema;
 use module_info::ModuleType;
@@ -90,6 +90,12 @@ pub fn event(input: TokenStream) -> TokenStream {
     handle_macro_error(event_macro.derive_event_enum(input))
 }
 
+#[proc_macro_derive(EventName)]
+pub fn event_name(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input);
+    handle_macro_error(derive_event_name(input))
+}
+
 #[proc_macro_derive(ModuleCallJsonSchema)]
 pub fn module_call_json_schema(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input);
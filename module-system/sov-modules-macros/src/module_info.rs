@@ -261,6 +261,10 @@ fn make_module_prefix_fn(struct_ident: &Ident) -> proc_macro2::TokenStream {
     let body = make_module_prefix_fn_body(struct_ident);
     quote::quote! {
         fn prefix(&self) -> sov_modules_api::ModulePrefix {
+           <Self as sov_modules_api::ModuleInfo>::module_prefix()
+        }
+
+        fn module_prefix() -> sov_modules_api::ModulePrefix {
            #body
         }
     }
@@ -2,7 +2,9 @@ mod modules;
 use modules::{first_test_module, second_test_module};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::macros::DefaultRuntime;
-use sov_modules_api::{Context, DispatchCall, Event, Genesis, MessageCodec};
+use sov_modules_api::{
+    Context, DecodeEvent, DispatchCall, Event, EventName, Genesis, MessageCodec, ModuleInfo,
+};
 
 #[derive(Genesis, DispatchCall, Event, MessageCodec, DefaultRuntime)]
 #[serialization(borsh::BorshDeserialize, borsh::BorshSerialize)]
@@ -23,4 +25,32 @@ fn main() {
         ]));
     let _event =
         RuntimeEvent::<DefaultContext>::second(second_test_module::Event::SecondModuleEnum);
+
+    // Check that the events emitted by the modules are decoded into the runtime events
+    let prefix = first_test_module::FirstTestStruct::<DefaultContext>::module_prefix().to_bytes();
+    let event = first_test_module::Event::FirstModuleEnum1(10);
+    assert_eq!("FirstModuleEnum1", event.event_name());
+    let emitted = sov_modules_api::Event::from_bytes(
+        [prefix.clone(), b"FirstModuleEnum1".to_vec()].concat(),
+        borsh::to_vec(&event).unwrap(),
+    );
+    assert_eq!(
+        Some(RuntimeEvent::<DefaultContext>::first(
+            first_test_module::Event::FirstModuleEnum1(10)
+        )),
+        RuntimeEvent::<DefaultContext>::decode_event(&emitted)
+    );
+
+    // An event whose key doesn't name its variant isn't decoded
+    let mislabeled = sov_modules_api::Event::from_bytes(
+        [prefix, b"FirstModuleEnum2".to_vec()].concat(),
+        borsh::to_vec(&event).unwrap(),
+    );
+    assert_eq!(
+        None,
+        RuntimeEvent::<DefaultContext>::decode_event(&mislabeled)
+    );
+
+    let untyped = sov_modules_api::Event::new("first", "value");
+    assert_eq!(None, RuntimeEvent::<DefaultContext>::decode_event(&untyped));
 }
@@ -1,5 +1,5 @@
 use sov_modules_api::{
-    prelude::*, CallResponse, Context, Error, Module, ModuleInfo, StateValue, WorkingSet,
+    prelude::*, CallResponse, Context, Error, EventName, Module, ModuleInfo, StateValue, WorkingSet,
 };
 
 pub mod first_test_module {
@@ -20,7 +20,7 @@ pub mod first_test_module {
         }
     }
 
-    #[derive(borsh::BorshDeserialize, borsh::BorshSerialize, Debug, PartialEq)]
+    #[derive(borsh::BorshDeserialize, borsh::BorshSerialize, EventName, Debug, PartialEq)]
     pub enum Event {
         FirstModuleEnum1(u64),
         FirstModuleEnum2,
@@ -72,7 +72,7 @@ pub mod second_test_module {
         }
     }
 
-    #[derive(borsh::BorshDeserialize, borsh::BorshSerialize, Debug, PartialEq)]
+    #[derive(borsh::BorshDeserialize, borsh::BorshSerialize, EventName, Debug, PartialEq)]
     pub enum Event {
         SecondModuleEnum,
     }
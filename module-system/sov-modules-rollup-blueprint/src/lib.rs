@@ -70,6 +70,7 @@ pub trait RollupBlueprint: Sized + Send + Sync {
         storage: &<Self::NativeContext as Spec>::Storage,
         ledger_db: &LedgerDB,
         da_service: &Self::DaService,
+        rollup_config: &RollupConfig<Self::DaConfig>,
     ) -> Result<jsonrpsee::RpcModule<()>, anyhow::Error>;
 
     /// Creates GenesisConfig from genesis files.
@@ -111,6 +112,7 @@ pub trait RollupBlueprint: Sized + Send + Sync {
         prover_config: RollupProverConfig,
         rollup_config: &RollupConfig<Self::DaConfig>,
         da_service: &Self::DaService,
+        ledger_db: &LedgerDB,
     ) -> Self::ProverService;
 
     /// Creates instance of [`Self::StorageManager`].
@@ -126,6 +128,7 @@ pub trait RollupBlueprint: Sized + Send + Sync {
     }
 
     /// Creates a new rollup.
+    /// The rollup doesn't prove the DA blocks if `prover_config` is `None`.
     async fn create_new_rollup(
         &self,
         runtime_genesis_paths: &<Self::NativeRuntime as RuntimeTrait<
@@ -134,7 +137,7 @@ pub trait RollupBlueprint: Sized + Send + Sync {
         >>::GenesisPaths,
         kernel_genesis_config: <Self::NativeKernel as Kernel<Self::NativeContext, Self::DaSpec>>::GenesisConfig,
         rollup_config: RollupConfig<Self::DaConfig>,
-        prover_config: RollupProverConfig,
+        prover_config: Option<RollupProverConfig>,
     ) -> Result<Rollup<Self>, anyhow::Error>
     where
         <Self::NativeContext as Spec>::Storage: NativeStorage,
@@ -144,11 +147,15 @@ pub trait RollupBlueprint: Sized + Send + Sync {
         // Maybe whole "prev_root" can be initialized inside runner
         // Getting block here, so prover_service doesn't have to be `Send`
         let last_finalized_block_header = da_service.get_last_finalized_block_header().await?;
-        let prover_service = self
-            .create_prover_service(prover_config, &rollup_config, &da_service)
-            .await;
-
         let ledger_db = self.create_ledger_db(&rollup_config);
+        let prover_service = match prover_config {
+            Some(prover_config) => Some(
+                self.create_prover_service(prover_config, &rollup_config, &da_service, &ledger_db)
+                    .await,
+            ),
+            None => None,
+        };
+
         let genesis_config = self.create_genesis_config(
             runtime_genesis_paths,
             kernel_genesis_config,
@@ -164,7 +171,8 @@ pub trait RollupBlueprint: Sized + Send + Sync {
             .transpose()?;
 
         // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1218)
-        let rpc_methods = self.create_rpc_methods(&prover_storage, &ledger_db, &da_service)?;
+        let rpc_methods =
+            self.create_rpc_methods(&prover_storage, &ledger_db, &da_service, &rollup_config)?;
 
         let native_stf = StfBlueprint::new();
 
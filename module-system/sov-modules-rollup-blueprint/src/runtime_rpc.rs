@@ -1,9 +1,15 @@
 use anyhow::Context as _;
+use sov_chain_state::ChainState;
 use sov_db::ledger_db::LedgerDB;
 use sov_modules_api::{Context, Spec};
-use sov_modules_stf_blueprint::{Runtime as RuntimeTrait, SequencerOutcome, TxEffect};
+use sov_modules_stf_blueprint::{
+    Runtime as RuntimeTrait, SequencerOutcome, TxEffect, MAX_CHUNKS_PER_BATCH,
+};
+use sov_rollup_interface::chunks::CHUNK_ENVELOPE_SIZE;
 use sov_rollup_interface::services::da::DaService;
-use sov_sequencer::batch_builder::FiFoStrictBatchBuilder;
+use sov_sequencer::batch_builder::{ChainIdReader, FiFoStrictBatchBuilder};
+use sov_sequencer::priority_batch_builder::PriorityBatchBuilder;
+use sov_stf_runner::{MempoolPolicy, SequencerConfig};
 
 /// Register rollup's default rpc methods.
 pub fn register_rpc<RT, C, Da>(
@@ -11,6 +17,7 @@ pub fn register_rpc<RT, C, Da>(
     ledger_db: &LedgerDB,
     da_service: &Da,
     sequencer: C::Address,
+    sequencer_config: &SequencerConfig,
 ) -> Result<jsonrpsee::RpcModule<()>, anyhow::Error>
 where
     RT: RuntimeTrait<C, <Da as DaService>::Spec> + Send + Sync + 'static,
@@ -20,29 +27,100 @@ where
     // runtime rpc.
     let mut rpc_methods = RT::rpc_methods(storage.clone());
 
+    // kernel rpc.
+    {
+        rpc_methods.merge(sov_chain_state::get_chain_state_rpc_methods::<
+            C,
+            <Da as DaService>::Spec,
+        >(storage.clone()))?;
+    }
+
     // ledger rpc.
     {
         rpc_methods.merge(sov_ledger_rpc::server::rpc_module::<
             LedgerDB,
             SequencerOutcome<<C as Spec>::Address>,
             TxEffect,
+            RT::Event,
         >(ledger_db.clone())?)?;
     }
 
+    // prover rpc.
+    {
+        rpc_methods.merge(sov_stf_runner::get_prover_rpc(ledger_db.clone()))?;
+    }
+
     // sequencer rpc.
     {
-        let batch_builder = FiFoStrictBatchBuilder::new(
-            1024 * 100,
-            u32::MAX as usize,
-            RT::default(),
-            storage.clone(),
-            sequencer,
-        );
-
-        let sequencer_rpc = sov_sequencer::get_sequencer_rpc(batch_builder, da_service.clone());
-        rpc_methods
-            .merge(sequencer_rpc)
-            .context("Failed to merge Txs RPC modules")?;
+        // The sequencer serves the status of the transactions from the mempool as well as the ledger.
+        rpc_methods.remove_method("tx_getStatus");
+
+        let SequencerConfig {
+            mempool_policy,
+            mempool_max_txs_count,
+            max_batch_size_bytes,
+            max_blob_size_bytes,
+        } = sequencer_config.clone();
+
+        if let Some(max_blob_size_bytes) = max_blob_size_bytes {
+            // A batch split into more chunks than the rollup accepts would get the sequencer slashed.
+            let chunk_data_size = max_blob_size_bytes.saturating_sub(CHUNK_ENVELOPE_SIZE);
+            anyhow::ensure!(
+                chunk_data_size > 0
+                    && max_batch_size_bytes.div_ceil(chunk_data_size)
+                        <= MAX_CHUNKS_PER_BATCH as usize,
+                "The maximum batch size of {} bytes doesn't fit in {} blobs of {} bytes",
+                max_batch_size_bytes,
+                MAX_CHUNKS_PER_BATCH,
+                max_blob_size_bytes
+            );
+        }
+
+        // Transactions signed for another chain are rejected before entering the mempool.
+        let chain_id: ChainIdReader<C> = |working_set| {
+            ChainState::<C, <Da as DaService>::Spec>::default().get_chain_id(working_set)
+        };
+
+        match mempool_policy {
+            MempoolPolicy::FiFo => {
+                let batch_builder = FiFoStrictBatchBuilder::new(
+                    max_batch_size_bytes,
+                    mempool_max_txs_count,
+                    RT::default(),
+                    storage.clone(),
+                    sequencer,
+                    chain_id,
+                );
+                let sequencer_rpc = sov_sequencer::get_sequencer_rpc::<_, _, _, TxEffect>(
+                    batch_builder,
+                    da_service.clone(),
+                    ledger_db.clone(),
+                    max_blob_size_bytes,
+                );
+                rpc_methods
+                    .merge(sequencer_rpc)
+                    .context("Failed to merge Txs RPC modules")?;
+            }
+            MempoolPolicy::Priority => {
+                let batch_builder = PriorityBatchBuilder::new(
+                    max_batch_size_bytes,
+                    mempool_max_txs_count,
+                    RT::default(),
+                    storage.clone(),
+                    sequencer,
+                    chain_id,
+                );
+                let sequencer_rpc = sov_sequencer::get_sequencer_rpc::<_, _, _, TxEffect>(
+                    batch_builder,
+                    da_service.clone(),
+                    ledger_db.clone(),
+                    max_blob_size_bytes,
+                );
+                rpc_methods
+                    .merge(sequencer_rpc)
+                    .context("Failed to merge Txs RPC modules")?;
+            }
+        }
     }
 
     Ok(rpc_methods)
@@ -0,0 +1,276 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use sov_modules_api::macros::config_constant;
+use sov_modules_api::prelude::*;
+use sov_modules_api::{
+    BasicAddress, BlobReaderTrait, Context, Spec, StateMap, StateValue, WorkingSet,
+};
+use sov_rollup_interface::chunks::BlobChunk;
+use sov_rollup_interface::digest::Digest;
+use sov_state::Prefix;
+use tracing::{debug, error};
+
+use crate::SlashingReason;
+
+/// The maximum number of chunks a batch can be split into.
+#[config_constant]
+pub const MAX_CHUNKS_PER_BATCH: u32;
+
+/// For how many slots the chunks of a batch are kept, waiting for the rest of the batch.
+/// The sender of a batch which is still incomplete after that is slashed.
+#[config_constant]
+pub const CHUNKED_BATCH_TIMEOUT_SLOTS: u64;
+
+/// Identifies a batch being received: the DA address of its sender and the batch id.
+type PendingBatchKey = (Vec<u8>, [u8; 32]);
+
+/// The state of a batch whose first chunk has been received.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+enum PendingBatch {
+    /// Some chunks of the batch are still missing.
+    Receiving { chunks: Vec<Option<Vec<u8>>> },
+    /// The batch has been reassembled. It's remembered until its deadline,
+    /// so that the copies of its chunks resent by the sequencer are ignored.
+    Completed { total: u32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+struct PendingBatchDeadline {
+    key: PendingBatchKey,
+    /// The height after which the sender is slashed if the batch is still incomplete.
+    expires_at: u64,
+}
+
+/// What happened to a blob given to the [`ChunkAccumulator`].
+pub(crate) enum ChunkOutcome<A: BasicAddress> {
+    /// The blob contains a whole batch.
+    NotAChunk,
+    /// The chunk has been stored, and the batch waits for its remaining chunks.
+    Stored,
+    /// The chunk has already been received, for example because the sequencer retried its submission.
+    Duplicate,
+    /// The chunk completed its batch.
+    Completed(ReassembledBlob<A>),
+}
+
+/// A batch reassembled from its chunks, which is executed in the same way as a batch
+/// contained in a single blob.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct ReassembledBlob<A> {
+    sender: A,
+    /// The hash of the reassembled batch, rather than its id, which is chosen by the sender.
+    hash: [u8; 32],
+    data: Vec<u8>,
+}
+
+impl<A: BasicAddress> BlobReaderTrait for ReassembledBlob<A> {
+    type Address = A;
+
+    fn sender(&self) -> Self::Address {
+        self.sender.clone()
+    }
+
+    fn hash(&self) -> [u8; 32] {
+        self.hash
+    }
+
+    // All the chunks have been fully read before the reassembly.
+    fn verified_data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn total_len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[cfg(feature = "native")]
+    fn advance(&mut self, _num_bytes: usize) -> &[u8] {
+        &self.data
+    }
+}
+
+/// Accumulates the chunks of the batches spanning several DA blobs, possibly across several slots,
+/// until the batches are complete.
+pub(crate) struct ChunkAccumulator<C: Context> {
+    pending_batches: StateMap<PendingBatchKey, PendingBatch>,
+    /// The deadlines of the pending batches, in the order they were started.
+    deadlines: StateValue<Vec<PendingBatchDeadline>>,
+    phantom: std::marker::PhantomData<C>,
+}
+
+impl<C: Context> Default for ChunkAccumulator<C> {
+    fn default() -> Self {
+        Self {
+            pending_batches: StateMap::new(Prefix::new(
+                b"sov_modules_stf_blueprint/ChunkAccumulator/pending_batches/".to_vec(),
+            )),
+            deadlines: StateValue::new(Prefix::new(
+                b"sov_modules_stf_blueprint/ChunkAccumulator/deadlines/".to_vec(),
+            )),
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<C: Context> ChunkAccumulator<C> {
+    /// Stores the chunk carried by the blob, and returns the reassembled batch once all its chunks are received.
+    /// Malformed chunks, and chunks inconsistent with the ones received before, result in sequencer slashing.
+    pub(crate) fn accept<B: BlobReaderTrait>(
+        &self,
+        blob: &mut B,
+        current_height: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<ChunkOutcome<B::Address>, SlashingReason> {
+        let data = crate::stf_blueprint::data_for_deserialization(blob);
+        if !BlobChunk::is_chunk(data) {
+            return Ok(ChunkOutcome::NotAChunk);
+        }
+        assert_eq!(
+            blob.verified_data().len(),
+            blob.total_len(),
+            "Some data of a chunk was not provided. The prover might be malicious"
+        );
+
+        let chunk = BlobChunk::decode(blob.verified_data()).map_err(|e| {
+            error!("Unable to deserialize chunk: {}", e);
+            SlashingReason::InvalidChunk
+        })?;
+        if chunk.total == 0 || chunk.total > MAX_CHUNKS_PER_BATCH || chunk.index >= chunk.total {
+            error!(
+                "Chunk #{} of batch 0x{} is invalid: the batch has {} chunks, but the maximum is {}",
+                chunk.index,
+                hex::encode(chunk.batch_id),
+                chunk.total,
+                MAX_CHUNKS_PER_BATCH
+            );
+            return Err(SlashingReason::InvalidChunk);
+        }
+
+        let sender = blob.sender();
+        let key = (sender.as_ref().to_vec(), chunk.batch_id);
+        let index = chunk.index as usize;
+        let mut chunks = match self.pending_batches.get(&key, working_set) {
+            Some(PendingBatch::Receiving { chunks }) => chunks,
+            Some(PendingBatch::Completed { total }) if total == chunk.total => {
+                debug!(
+                    "Ignoring chunk #{} of the already reassembled batch 0x{}",
+                    chunk.index,
+                    hex::encode(chunk.batch_id)
+                );
+                return Ok(ChunkOutcome::Duplicate);
+            }
+            Some(PendingBatch::Completed { .. }) => {
+                error!(
+                    "Chunk #{} of batch 0x{} is inconsistent with the chunks received before",
+                    chunk.index,
+                    hex::encode(chunk.batch_id)
+                );
+                return Err(SlashingReason::InvalidChunk);
+            }
+            None => {
+                self.add_deadline(
+                    key.clone(),
+                    current_height + CHUNKED_BATCH_TIMEOUT_SLOTS,
+                    working_set,
+                );
+                vec![None; chunk.total as usize]
+            }
+        };
+
+        if chunks.len() != chunk.total as usize {
+            error!(
+                "Chunk #{} of batch 0x{} is inconsistent with the chunks received before",
+                chunk.index,
+                hex::encode(chunk.batch_id)
+            );
+            self.remove(&key, working_set);
+            return Err(SlashingReason::InvalidChunk);
+        }
+        match &chunks[index] {
+            Some(data) if data == &chunk.data => {
+                debug!(
+                    "Ignoring the copy of chunk #{} of batch 0x{}",
+                    chunk.index,
+                    hex::encode(chunk.batch_id)
+                );
+                return Ok(ChunkOutcome::Duplicate);
+            }
+            Some(_) => {
+                error!(
+                    "Chunk #{} of batch 0x{} differs from the copy received before",
+                    chunk.index,
+                    hex::encode(chunk.batch_id)
+                );
+                self.remove(&key, working_set);
+                return Err(SlashingReason::InvalidChunk);
+            }
+            None => chunks[index] = Some(chunk.data),
+        }
+
+        if chunks.iter().any(Option::is_none) {
+            debug!(
+                "Stored chunk #{} of batch 0x{}",
+                chunk.index,
+                hex::encode(chunk.batch_id)
+            );
+            self.pending_batches
+                .set(&key, &PendingBatch::Receiving { chunks }, working_set);
+            return Ok(ChunkOutcome::Stored);
+        }
+
+        // The deadline is kept, so that the batch is remembered as completed until then.
+        self.pending_batches.set(
+            &key,
+            &PendingBatch::Completed { total: chunk.total },
+            working_set,
+        );
+        let data: Vec<u8> = chunks.into_iter().flatten().flatten().collect();
+        Ok(ChunkOutcome::Completed(ReassembledBlob {
+            sender,
+            hash: <C as Spec>::Hasher::digest(&data).into(),
+            data,
+        }))
+    }
+
+    /// Forgets the batches whose deadline is over at `current_height`, and returns the ids of the ones
+    /// which are still incomplete, along with the raw DA addresses of their senders.
+    pub(crate) fn take_expired(
+        &self,
+        current_height: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> Vec<PendingBatchKey> {
+        let deadlines = self.deadlines.get(working_set).unwrap_or_default();
+        if deadlines.is_empty() {
+            return Vec::new();
+        }
+
+        let (expired, remaining): (Vec<_>, Vec<_>) = deadlines
+            .into_iter()
+            .partition(|deadline| deadline.expires_at < current_height);
+        let mut incomplete = Vec::new();
+        for deadline in expired {
+            if let Some(PendingBatch::Receiving { .. }) =
+                self.pending_batches.get(&deadline.key, working_set)
+            {
+                incomplete.push(deadline.key.clone());
+            }
+            self.pending_batches.delete(&deadline.key, working_set);
+        }
+        self.deadlines.set(&remaining, working_set);
+
+        incomplete
+    }
+
+    fn add_deadline(&self, key: PendingBatchKey, expires_at: u64, working_set: &mut WorkingSet<C>) {
+        let mut deadlines = self.deadlines.get(working_set).unwrap_or_default();
+        deadlines.push(PendingBatchDeadline { key, expires_at });
+        self.deadlines.set(&deadlines, working_set);
+    }
+
+    fn remove(&self, key: &PendingBatchKey, working_set: &mut WorkingSet<C>) {
+        self.pending_batches.delete(key, working_set);
+        let mut deadlines = self.deadlines.get(working_set).unwrap_or_default();
+        deadlines.retain(|deadline| &deadline.key != key);
+        self.deadlines.set(&deadlines, working_set);
+    }
+}
@@ -42,6 +42,12 @@ impl<C: Context, Da: DaSpec> Kernel<C, Da> for BasicKernel<C, Da> {
     fn true_height(&self, working_set: &mut WorkingSet<C>) -> u64 {
         self.chain_state.true_slot_height(working_set)
     }
+    fn chain_id(&self, working_set: &mut WorkingSet<C>) -> u64 {
+        self.chain_state.get_chain_id(working_set)
+    }
+    fn base_fee_per_gas(&self, working_set: &mut WorkingSet<C>) -> C::GasUnit {
+        self.chain_state.get_base_fee_per_gas(working_set)
+    }
     fn visible_height(&self, working_set: &mut WorkingSet<C>) -> u64 {
         self.chain_state.true_slot_height(working_set)
     }
@@ -89,8 +95,12 @@ impl<C: Context, Da: DaSpec> KernelSlotHooks<C, Da> for BasicKernel<C, Da> {
             .begin_slot_hook(slot_header, validity_condition, pre_state_root, &mut ws);
     }
 
-    fn end_slot_hook(&self, working_set: &mut sov_modules_api::WorkingSet<Self::Context>) {
+    fn end_slot_hook(
+        &self,
+        gas_used: &C::GasUnit,
+        working_set: &mut sov_modules_api::WorkingSet<Self::Context>,
+    ) {
         let mut ws = sov_modules_api::KernelWorkingSet::from_kernel(self, working_set);
-        self.chain_state.end_slot_hook(&mut ws);
+        self.chain_state.end_slot_hook(gas_used, &mut ws);
     }
 }
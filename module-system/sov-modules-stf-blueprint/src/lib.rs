@@ -2,16 +2,18 @@
 #![doc = include_str!("../README.md")]
 
 mod batch;
+mod chunks;
 pub mod kernels;
 mod stf_blueprint;
 mod tx_verifier;
 
 pub use batch::Batch;
+pub use chunks::{CHUNKED_BATCH_TIMEOUT_SLOTS, MAX_CHUNKS_PER_BATCH};
 use sov_modules_api::hooks::{ApplyBlobHooks, FinalizeHook, SlotHooks, TxHooks};
 use sov_modules_api::runtime::capabilities::{Kernel, KernelSlotHooks};
 use sov_modules_api::{
-    BasicAddress, BlobReaderTrait, Context, DaSpec, DispatchCall, Genesis, KernelWorkingSet, Spec,
-    StateCheckpoint, Zkvm,
+    BasicAddress, BlobReaderTrait, Context, DaSpec, DispatchCall, GasUnit, Genesis,
+    KernelWorkingSet, Spec, StateCheckpoint, Zkvm,
 };
 pub use sov_rollup_interface::stf::BatchReceipt;
 use sov_rollup_interface::stf::{SlotResult, StateTransitionFunction};
@@ -55,6 +57,10 @@ pub trait Runtime<C: Context, Da: DaSpec>:
     /// GenesisPaths type.
     type GenesisPaths: Send + Sync;
 
+    #[cfg(feature = "native")]
+    /// The events emitted by the modules of the runtime, as they are returned by the ledger RPC.
+    type Event: sov_modules_api::DecodeEvent + serde::Serialize + Send + Sync + 'static;
+
     #[cfg(feature = "native")]
     /// Default rpc methods.
     fn rpc_methods(storage: <C as Spec>::Storage) -> jsonrpsee::RpcModule<()>;
@@ -79,7 +85,13 @@ pub enum TxEffect {
 /// Represents the different outcomes that can occur for a sequencer after batch processing.
 pub enum SequencerOutcome<A: BasicAddress> {
     /// Sequencer receives reward amount in defined token and can withdraw its deposit
-    Rewarded(u64),
+    Rewarded {
+        /// Amount of gas tokens collected as transaction tips, paid to the sequencer.
+        reward: u64,
+        #[serde(bound(deserialize = ""))]
+        /// Sequencer address on DA.
+        sequencer_da_address: A,
+    },
     /// Sequencer loses its deposit and receives no reward
     Slashed {
         /// Reason why sequencer was slashed.
@@ -109,6 +121,10 @@ pub enum SlashingReason {
     StatelessVerificationFailed,
     /// This status indicates problem with transaction deserialization.
     InvalidTransactionEncoding,
+    /// A chunk of a batch spanning several blobs is malformed, or inconsistent with the other chunks of the batch.
+    InvalidChunk,
+    /// Some chunks of a batch spanning several blobs were not received in time.
+    IncompleteChunkSet,
 }
 
 impl<C, RT, Vm, Da, K> StfBlueprint<C, Da, Vm, RT, K>
@@ -150,6 +166,7 @@ where
         &self,
         storage: C::Storage,
         checkpoint: StateCheckpoint<C>,
+        gas_used: &C::GasUnit,
     ) -> (
         <<C as Spec>::Storage as Storage>::Root,
         <<C as Spec>::Storage as Storage>::Witness,
@@ -157,6 +174,7 @@ where
     ) {
         // Run end end_slot_hook
         let mut working_set = checkpoint.to_revertable();
+        self.kernel.end_slot_hook(gas_used, &mut working_set);
         self.runtime.end_slot_hook(&mut working_set);
         // Save checkpoint
         let mut checkpoint = working_set.checkpoint();
@@ -279,6 +297,8 @@ where
 
         let mut batch_receipts = vec![];
 
+        checkpoint = self.slash_incomplete_chunked_batches(checkpoint, &mut batch_receipts);
+
         for (blob_idx, mut blob) in selected_blobs.into_iter().enumerate() {
             let (apply_blob_result, checkpoint_after_blob) =
                 self.apply_blob(checkpoint, blob.as_mut_ref());
@@ -303,7 +323,8 @@ where
             batch_receipts.push(batch_receipt);
         }
 
-        let (state_root, witness, storage) = self.end_slot(pre_state, checkpoint);
+        let gas_used = slot_gas_used::<C, _>(&batch_receipts);
+        let (state_root, witness, storage) = self.end_slot(pre_state, checkpoint, &gas_used);
         SlotResult {
             state_root,
             change_set: storage,
@@ -312,3 +333,12 @@ where
         }
     }
 }
+
+/// Sums the gas consumed by all the transactions of a slot.
+fn slot_gas_used<C: Context, B>(batch_receipts: &[BatchReceipt<B, TxEffect>]) -> C::GasUnit {
+    let mut gas_used = C::GasUnit::ZEROED;
+    for tx_receipt in batch_receipts.iter().flat_map(|b| b.tx_receipts.iter()) {
+        gas_used.combine(&C::GasUnit::from_arbitrary_dimensions(&tx_receipt.gas_used));
+    }
+    gas_used
+}
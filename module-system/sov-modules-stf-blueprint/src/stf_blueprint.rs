@@ -1,7 +1,7 @@
 use std::marker::PhantomData;
 
 use borsh::BorshDeserialize;
-use sov_modules_api::runtime::capabilities::KernelSlotHooks;
+use sov_modules_api::runtime::capabilities::{Kernel, KernelSlotHooks};
 use sov_modules_api::{
     BasicAddress, BlobReaderTrait, Context, DaSpec, DispatchCall, GasUnit, StateCheckpoint,
 };
@@ -9,6 +9,7 @@ use sov_modules_core::WorkingSet;
 use sov_rollup_interface::stf::{BatchReceipt, TransactionReceipt};
 use tracing::{debug, error};
 
+use crate::chunks::{ChunkAccumulator, ChunkOutcome, CHUNKED_BATCH_TIMEOUT_SLOTS};
 use crate::tx_verifier::{verify_txs_stateless, TransactionAndRawHash};
 use crate::{Batch, Runtime, RuntimeTxHook, SequencerOutcome, SlashingReason, TxEffect};
 
@@ -30,6 +31,7 @@ pub struct StfBlueprint<C: Context, Da: DaSpec, Vm, RT: Runtime<C, Da>, K: Kerne
     /// The runtime includes all the modules that the rollup supports.
     pub(crate) runtime: RT,
     pub(crate) kernel: K,
+    pub(crate) chunks: ChunkAccumulator<C>,
     phantom_context: PhantomData<C>,
     phantom_vm: PhantomData<Vm>,
     phantom_da: PhantomData<Da>,
@@ -94,6 +96,7 @@ where
         Self {
             runtime: RT::default(),
             kernel: K::default(),
+            chunks: ChunkAccumulator::default(),
             phantom_context: PhantomData,
             phantom_vm: PhantomData,
             phantom_da: PhantomData,
@@ -132,38 +135,134 @@ where
         // TODO: don't ignore these events: https://github.com/Sovereign-Labs/sovereign/issues/350
         let _ = batch_workspace.take_events();
 
-        let (txs, messages) = match self.pre_process_batch(blob) {
-            Ok((txs, messages)) => (txs, messages),
-            Err(reason) => {
-                // Explicitly revert on slashing, even though nothing has changed in pre_process.
-                let mut batch_workspace = batch_workspace.checkpoint().to_revertable();
-                let sequencer_da_address = blob.sender();
-                let sequencer_outcome = SequencerOutcome::Slashed {
-                    reason,
-                    sequencer_da_address: sequencer_da_address.clone(),
+        let chain_id = self.kernel.chain_id(&mut batch_workspace);
+        let current_height = self.kernel.true_height(&mut batch_workspace);
+
+        // The chunks of a batch spanning several blobs are stored until the batch is complete.
+        match self
+            .chunks
+            .accept(blob, current_height, &mut batch_workspace)
+        {
+            Ok(ChunkOutcome::NotAChunk) => self.apply_batch(batch_workspace, blob, chain_id),
+            Ok(ChunkOutcome::Stored | ChunkOutcome::Duplicate) => {
+                let sequencer_outcome = SequencerOutcome::Rewarded {
+                    reward: 0,
+                    sequencer_da_address: blob.sender(),
                 };
-                let checkpoint = match self
+                if let Err(e) = self
                     .runtime
-                    .end_blob_hook(sequencer_outcome, &mut batch_workspace)
+                    .end_blob_hook(sequencer_outcome.clone(), &mut batch_workspace)
                 {
-                    Ok(()) => {
-                        // TODO: will be covered in https://github.com/Sovereign-Labs/sovereign-sdk/issues/421
-                        batch_workspace.checkpoint()
-                    }
-                    Err(e) => {
-                        error!("End blob hook failed: {}", e);
-                        batch_workspace.revert()
-                    }
+                    // TODO: will be covered in https://github.com/Sovereign-Labs/sovereign-sdk/issues/421
+                    error!("Failed on `end_blob_hook`: {}", e);
                 };
 
-                return (
-                    Err(ApplyBatchError::Slashed {
-                        hash: blob.hash(),
-                        reason,
-                        sequencer_da_address,
+                (
+                    Ok(BatchReceipt {
+                        batch_hash: blob.hash(),
+                        tx_receipts: Vec::new(),
+                        inner: sequencer_outcome,
                     }),
-                    checkpoint,
-                );
+                    batch_workspace.checkpoint(),
+                )
+            }
+            Ok(ChunkOutcome::Completed(mut batch)) => {
+                self.apply_batch(batch_workspace, &mut batch, chain_id)
+            }
+            Err(reason) => {
+                self.slash_sequencer(batch_workspace, blob.hash(), reason, blob.sender())
+            }
+        }
+    }
+
+    /// Slashes the senders of the batches whose chunks were not all received in time.
+    pub(crate) fn slash_incomplete_chunked_batches(
+        &self,
+        mut checkpoint: StateCheckpoint<C>,
+        batch_receipts: &mut Vec<
+            BatchReceipt<
+                SequencerOutcome<<Da::BlobTransaction as BlobReaderTrait>::Address>,
+                TxEffect,
+            >,
+        >,
+    ) -> StateCheckpoint<C> {
+        let mut working_set = checkpoint.to_revertable();
+        let current_height = self.kernel.true_height(&mut working_set);
+        let expired_batches = self.chunks.take_expired(current_height, &mut working_set);
+        checkpoint = working_set.checkpoint();
+
+        for (sender, batch_id) in expired_batches {
+            let sequencer_da_address = Da::Address::try_from(&sender[..])
+                .expect("The sender of a chunk was stored from a valid address");
+            error!(
+                "Batch 0x{} from sequencer {} is incomplete after {} slots",
+                hex::encode(batch_id),
+                sequencer_da_address,
+                CHUNKED_BATCH_TIMEOUT_SLOTS
+            );
+            let (apply_blob_result, checkpoint_after_slashing) = self.slash_sequencer(
+                checkpoint.to_revertable(),
+                batch_id,
+                SlashingReason::IncompleteChunkSet,
+                sequencer_da_address,
+            );
+            checkpoint = checkpoint_after_slashing;
+            batch_receipts.push(apply_blob_result.unwrap_or_else(Into::into));
+        }
+
+        checkpoint
+    }
+
+    fn slash_sequencer(
+        &self,
+        batch_workspace: WorkingSet<C>,
+        hash: [u8; 32],
+        reason: SlashingReason,
+        sequencer_da_address: <Da::BlobTransaction as BlobReaderTrait>::Address,
+    ) -> (ApplyBatch<Da>, StateCheckpoint<C>) {
+        // The changes made before the slashing are kept, even if the `end_blob_hook` fails.
+        let mut batch_workspace = batch_workspace.checkpoint().to_revertable();
+        let sequencer_outcome = SequencerOutcome::Slashed {
+            reason,
+            sequencer_da_address: sequencer_da_address.clone(),
+        };
+        let checkpoint = match self
+            .runtime
+            .end_blob_hook(sequencer_outcome, &mut batch_workspace)
+        {
+            Ok(()) => {
+                // TODO: will be covered in https://github.com/Sovereign-Labs/sovereign-sdk/issues/421
+                batch_workspace.checkpoint()
+            }
+            Err(e) => {
+                error!("End blob hook failed: {}", e);
+                batch_workspace.revert()
+            }
+        };
+
+        (
+            Err(ApplyBatchError::Slashed {
+                hash,
+                reason,
+                sequencer_da_address,
+            }),
+            checkpoint,
+        )
+    }
+
+    fn apply_batch<B>(
+        &self,
+        mut batch_workspace: WorkingSet<C>,
+        blob: &mut B,
+        chain_id: u64,
+    ) -> (ApplyBatch<Da>, StateCheckpoint<C>)
+    where
+        B: BlobReaderTrait<Address = <Da::BlobTransaction as BlobReaderTrait>::Address>,
+    {
+        let (txs, messages) = match self.pre_process_batch(blob) {
+            Ok((txs, messages)) => (txs, messages),
+            Err(reason) => {
+                return self.slash_sequencer(batch_workspace, blob.hash(), reason, blob.sender());
             }
         };
 
@@ -174,8 +273,7 @@ where
             "Error in preprocessing batch, there should be same number of txs and messages"
         );
 
-        // TODO fetch gas price from chain state
-        let gas_elastic_price = [0, 0];
+        let gas_price = self.kernel.base_fee_per_gas(&mut batch_workspace);
         let mut sequencer_reward = 0u64;
 
         let mut tx_receipts = Vec::with_capacity(txs.len());
@@ -183,14 +281,17 @@ where
         let mut batch_workspace = self.apply_txs(
             txs,
             messages,
-            &gas_elastic_price,
+            chain_id,
+            &gas_price,
             &mut tx_receipts,
             batch_workspace,
             &mut sequencer_reward,
         );
 
-        // TODO: calculate the amount based of gas and fees
-        let sequencer_outcome = SequencerOutcome::Rewarded(sequencer_reward);
+        let sequencer_outcome = SequencerOutcome::Rewarded {
+            reward: sequencer_reward,
+            sequencer_da_address: blob.sender(),
+        };
 
         if let Err(e) = self
             .runtime
@@ -238,7 +339,8 @@ where
         &self,
         txs: Vec<TransactionAndRawHash<C>>,
         messages: Vec<<RT as DispatchCall>::Decodable>,
-        gas_elastic_price: &[u64],
+        chain_id: u64,
+        gas_price: &C::GasUnit,
         tx_receipts: &mut Vec<TransactionReceipt<TxEffect>>,
         mut batch_workspace: WorkingSet<C>,
         sequencer_reward: &mut u64,
@@ -248,10 +350,30 @@ where
             txs.into_iter().zip(messages.into_iter())
         {
             // Update the working set gas meter with the available funds
-            let gas_price = C::GasUnit::from_arbitrary_dimensions(gas_elastic_price);
             let gas_limit = tx.gas_limit();
             let gas_tip = tx.gas_tip();
-            batch_workspace.set_gas(gas_limit, gas_price);
+            batch_workspace.set_gas(gas_limit, gas_price.clone());
+
+            // The chain id is part of the signed payload, so a transaction signed for another chain
+            // can't be tampered with to pass this check. Skipping it here prevents cross-chain replays.
+            // Like a bad nonce, it only reverts the transaction: the rest of the batch is still applied.
+            if tx.chain_id() != chain_id {
+                error!(
+                    "Tx 0x{} has chain id {}, but the rollup chain id is {}",
+                    hex::encode(raw_tx_hash),
+                    tx.chain_id(),
+                    chain_id
+                );
+                let gas_used = batch_workspace.gas_used().to_dimensions();
+                tx_receipts.push(TransactionReceipt {
+                    tx_hash: raw_tx_hash,
+                    body_to_save: None,
+                    events: batch_workspace.take_events(),
+                    receipt: TxEffect::Reverted,
+                    gas_used,
+                });
+                continue;
+            }
 
             // Pre dispatch hook
             // TODO set the sequencer pubkey
@@ -287,16 +409,12 @@ where
 
             let tx_result = self.runtime.dispatch_call(msg, &mut batch_workspace, &ctx);
 
-            let remaining_gas = batch_workspace.gas_remaining_funds();
-            let gas_reward = gas_limit
-                .saturating_add(gas_tip)
-                .saturating_sub(remaining_gas);
-
-            *sequencer_reward = sequencer_reward.saturating_add(gas_reward);
+            // The consumed gas is burned by the bank, so the sequencer is only rewarded with the tip.
+            *sequencer_reward = sequencer_reward.saturating_add(gas_tip);
             debug!(
                 "Tx {} sequencer reward: {}",
                 hex::encode(raw_tx_hash),
-                gas_reward
+                gas_tip
             );
 
             let events = batch_workspace.take_events();
@@ -397,11 +515,11 @@ where
 }
 
 #[cfg(feature = "native")]
-fn data_for_deserialization(blob: &mut impl BlobReaderTrait) -> &[u8] {
+pub(crate) fn data_for_deserialization(blob: &mut impl BlobReaderTrait) -> &[u8] {
     blob.full_data()
 }
 
 #[cfg(not(feature = "native"))]
-fn data_for_deserialization(blob: &mut impl BlobReaderTrait) -> &[u8] {
+pub(crate) fn data_for_deserialization(blob: &mut impl BlobReaderTrait) -> &[u8] {
     blob.verified_data()
 }
@@ -5,9 +5,7 @@ use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 use crate::maybestd::vec::Vec;
-#[cfg(feature = "native")]
-use crate::stf::Event;
-use crate::stf::EventKey;
+use crate::stf::{Event, EventKey};
 
 /// A struct containing enough information to uniquely specify single batch.
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -190,6 +188,91 @@ pub enum ItemOrHash<T> {
     Full(T),
 }
 
+/// A filter selecting events by key, and by the transactions which emitted them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventFilter {
+    /// Only the events whose key starts with these hex-encoded bytes are selected.
+    /// The typed events of a module are keyed by the prefix of the module.
+    #[serde(default, with = "utils::rpc_hex")]
+    pub key_prefix: Vec<u8>,
+    /// The number of the first transaction whose events are selected.
+    #[serde(default)]
+    pub start_tx: u64,
+    /// The number of the transaction following the last one whose events are selected.
+    /// If `None`, the events of all the transactions from `start_tx` are selected.
+    #[serde(default)]
+    pub end_tx: Option<u64>,
+}
+
+impl EventFilter {
+    /// Returns `true` if an event with the given key, emitted by the transaction `tx_number`,
+    /// is selected by the filter.
+    pub fn matches(&self, key: &EventKey, tx_number: u64) -> bool {
+        key.inner().starts_with(&self.key_prefix)
+            && tx_number >= self.start_tx
+            && self.end_tx.map_or(true, |end_tx| tx_number < end_tx)
+    }
+}
+
+/// An event, along with its position in the ledger.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumberedEvent {
+    /// The number of the event.
+    pub number: u64,
+    /// The number of the transaction which emitted the event.
+    pub tx_number: u64,
+    /// The event itself.
+    #[serde(flatten)]
+    pub event: Event,
+}
+
+/// The position from which a query by [`EventFilter`] resumes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventCursor {
+    /// The hex-encoded key of the next event.
+    #[serde(with = "utils::rpc_hex")]
+    pub key: Vec<u8>,
+    /// The number of the transaction which emitted the next event.
+    pub tx_number: u64,
+    /// The number of the next event.
+    pub number: u64,
+}
+
+/// A page of the events selected by an [`EventFilter`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventPage<E> {
+    /// The events of the page.
+    pub events: Vec<E>,
+    /// The cursor to query the next page with, or `None` if there are no more events.
+    pub next_cursor: Option<EventCursor>,
+}
+
+/// The status of a transaction executed by the rollup.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LedgerTxStatus<R> {
+    /// The transaction was executed in a slot which isn't finalized yet.
+    /// The slot may still be dropped by a reorganization of the DA layer.
+    Executed {
+        /// The hash of the slot the transaction was executed in.
+        #[serde(with = "utils::rpc_hex")]
+        slot_hash: [u8; 32],
+        /// The receipt of the transaction, which tells whether it was reverted.
+        receipt: R,
+    },
+    /// The transaction was executed in a finalized slot, and committed to the ledger.
+    Finalized {
+        /// The number of the transaction in the ledger.
+        tx_number: u64,
+        /// The receipt of the transaction, which tells whether it was reverted.
+        receipt: R,
+    },
+    /// The slot the transaction was executed in was dropped by a reorganization of the DA layer.
+    Dropped {
+        /// Why the transaction was dropped.
+        reason: String,
+    },
+}
+
 /// A LedgerRpcProvider provides a way to query the ledger for information about slots, batches, transactions, and events.
 #[cfg(feature = "native")]
 pub trait LedgerRpcProvider {
@@ -301,8 +384,37 @@ pub trait LedgerRpcProvider {
         query_mode: QueryMode,
     ) -> Result<Vec<Option<TxResponse<T>>>, anyhow::Error>;
 
+    /// Get the status of a transaction by hash, including the transactions executed in slots
+    /// which aren't finalized yet.
+    fn get_tx_status<T: DeserializeOwned>(
+        &self,
+        hash: &[u8; 32],
+    ) -> Result<Option<LedgerTxStatus<T>>, anyhow::Error>;
+
+    /// Get a page of at most `limit` events selected by `filter`, starting at `cursor`,
+    /// or from the beginning if `None`.
+    /// The events are ordered by key, then by number: the events with a given key are in the order
+    /// they were emitted.
+    fn get_events_by_filter(
+        &self,
+        filter: &EventFilter,
+        cursor: Option<&EventCursor>,
+        limit: u64,
+    ) -> Result<EventPage<NumberedEvent>, anyhow::Error>;
+
     /// Get a notification each time a slot is processed
     fn subscribe_slots(&self) -> Result<tokio::sync::broadcast::Receiver<u64>, anyhow::Error>;
+
+    /// Get the events emitted in each slot, when the slot is processed.
+    fn subscribe_events(
+        &self,
+    ) -> Result<tokio::sync::broadcast::Receiver<std::sync::Arc<Vec<NumberedEvent>>>, anyhow::Error>;
+
+    /// Get the hashes of the transactions whose status changed, each time a slot is executed,
+    /// finalized or dropped by a reorganization.
+    fn subscribe_tx_statuses(
+        &self,
+    ) -> Result<tokio::sync::broadcast::Receiver<std::sync::Arc<Vec<[u8; 32]>>>, anyhow::Error>;
 }
 
 /// JSON-RPC -related utilities. Occasionally useful but unimportant for most
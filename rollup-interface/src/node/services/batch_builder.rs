@@ -1,7 +1,26 @@
 //! This module defines the trait that is used to build batches of transactions.
 
+use serde::{Deserialize, Serialize};
+
+use crate::maybestd::string::String;
 use crate::maybestd::vec::Vec;
 
+/// Status of a transaction submitted to the [`BatchBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TxStatus {
+    /// The transaction is in the mempool and can be included in the next batch.
+    Pending,
+    /// The transaction is in the mempool, waiting for the transactions of its sender with lower nonces.
+    Queued,
+    /// The transaction was included in a batch.
+    Included,
+    /// The transaction was dropped from the mempool.
+    Dropped {
+        /// Why the transaction was dropped.
+        reason: String,
+    },
+}
+
 /// BlockBuilder trait is responsible for managing mempool and building batches.
 pub trait BatchBuilder {
     /// Accept a new transaction.
@@ -11,4 +30,21 @@ pub trait BatchBuilder {
     /// Builds a new batch out of transactions in mempool.
     /// Logic of which transactions and how many of them is included in batch is up to implementation.
     fn get_next_blob(&mut self) -> anyhow::Result<Vec<Vec<u8>>>;
+
+    /// Puts back in the mempool the transactions of a batch built by [`BatchBuilder::get_next_blob`]
+    /// which couldn't be sent to the DA layer, so they are included in a later batch.
+    fn return_batch(&mut self, txs: Vec<Vec<u8>>) -> anyhow::Result<()> {
+        txs.into_iter().try_for_each(|tx| self.accept_tx(tx))
+    }
+
+    /// Returns the nonce of the next transaction of the account with the given serialized public key,
+    /// following its transactions in the mempool.
+    fn get_pending_nonce(&self, _pub_key: &[u8]) -> anyhow::Result<u64> {
+        anyhow::bail!("Pending nonces are not tracked by this batch builder")
+    }
+
+    /// Returns the status of the transaction with the given hash, or `None` if it is unknown.
+    fn get_tx_status(&self, _tx_hash: &[u8; 32]) -> Option<TxStatus> {
+        None
+    }
 }
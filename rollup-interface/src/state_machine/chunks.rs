@@ -0,0 +1,120 @@
+//! Defines the envelope format of batches which are too large to fit in a single DA blob.
+//!
+//! Such a batch is split into several [`BlobChunk`]s, each of them sent in its own blob.
+//! The chunks may be spread over several DA blocks, and the rollup executes the batch
+//! once all of its chunks have been received.
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::maybestd::io;
+use crate::maybestd::vec::Vec;
+
+/// The prefix of the blobs carrying a [`BlobChunk`] rather than a whole batch.
+///
+/// Read as the length of a borsh-encoded batch, the prefix would announce more than a billion
+/// transactions, so it can't be mistaken for the beginning of a batch that fits in a DA blob.
+pub const CHUNK_PREFIX: [u8; 8] = *b"SOVCHUNK";
+
+/// A piece of a batch spanning several DA blobs.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct BlobChunk {
+    /// The identifier of the batch, chosen by its sender.
+    /// It must be unique among the batches of the sender which haven't been fully received yet.
+    pub batch_id: [u8; 32],
+    /// The position of the chunk in the batch, starting from 0.
+    pub index: u32,
+    /// The number of chunks the batch is split into.
+    pub total: u32,
+    /// The bytes of the batch carried by this chunk.
+    pub data: Vec<u8>,
+}
+
+impl BlobChunk {
+    /// Returns `true` if the blob carries a chunk, rather than a whole batch.
+    pub fn is_chunk(blob: &[u8]) -> bool {
+        blob.starts_with(&CHUNK_PREFIX)
+    }
+
+    /// Serializes the chunk into the contents of a DA blob.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut blob = CHUNK_PREFIX.to_vec();
+        self.serialize(&mut blob)
+            .expect("Serialization to vec is infallible");
+        blob
+    }
+
+    /// Deserializes a chunk from the contents of a DA blob.
+    pub fn decode(blob: &[u8]) -> Result<Self, io::Error> {
+        let data = blob
+            .strip_prefix(&CHUNK_PREFIX)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "The blob is not a chunk"))?;
+        Self::try_from_slice(data)
+    }
+}
+
+/// Splits a serialized batch into the contents of DA blobs of at most `max_blob_size` bytes.
+/// A batch which fits in a single blob is returned as it is.
+///
+/// # Panics
+///
+/// Panics if `max_blob_size` is too small to hold the envelope of a chunk and some data.
+pub fn split_batch(batch: &[u8], batch_id: [u8; 32], max_blob_size: usize) -> Vec<Vec<u8>> {
+    if batch.len() <= max_blob_size {
+        return alloc::vec![batch.to_vec()];
+    }
+
+    let chunk_data_size = max_blob_size
+        .checked_sub(CHUNK_ENVELOPE_SIZE)
+        .filter(|size| *size > 0)
+        .expect("The maximum blob size is too small to hold a chunk");
+    let total = batch.len().div_ceil(chunk_data_size) as u32;
+
+    batch
+        .chunks(chunk_data_size)
+        .enumerate()
+        .map(|(index, data)| {
+            BlobChunk {
+                batch_id,
+                index: index as u32,
+                total,
+                data: data.to_vec(),
+            }
+            .encode()
+        })
+        .collect()
+}
+
+/// The size of a chunk blob without its data: the prefix, the batch id,
+/// the index, the total and the length of the data.
+pub const CHUNK_ENVELOPE_SIZE: usize = CHUNK_PREFIX.len() + 32 + 4 + 4 + 4;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_batch_round_trip() {
+        let batch: Vec<u8> = (0..=255).collect();
+        let blobs = split_batch(&batch, [1; 32], CHUNK_ENVELOPE_SIZE + 100);
+        assert_eq!(3, blobs.len());
+
+        let mut reassembled = Vec::new();
+        for (index, blob) in blobs.iter().enumerate() {
+            assert!(blob.len() <= CHUNK_ENVELOPE_SIZE + 100);
+            assert!(BlobChunk::is_chunk(blob));
+            let chunk = BlobChunk::decode(blob).unwrap();
+            assert_eq!([1; 32], chunk.batch_id);
+            assert_eq!(index as u32, chunk.index);
+            assert_eq!(3, chunk.total);
+            reassembled.extend(chunk.data);
+        }
+        assert_eq!(batch, reassembled);
+    }
+
+    #[test]
+    fn test_small_batch_is_not_split() {
+        let batch = vec![1, 2, 3];
+        assert_eq!(vec![batch.clone()], split_batch(&batch, [1; 32], 3));
+        assert!(!BlobChunk::is_chunk(&batch));
+    }
+}
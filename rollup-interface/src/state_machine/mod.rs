@@ -1,5 +1,6 @@
 //! Defines types, traits, and helpers that are used by the core state-machine of the rollup.
 //! Items in this module must be fully deterministic, since they are expected to be executed inside of zkVMs.
+pub mod chunks;
 pub mod crypto;
 pub mod da;
 pub mod stf;
@@ -183,6 +183,14 @@ impl Event {
         }
     }
 
+    /// Create a new event from the raw bytes of its key and value
+    pub fn from_bytes(key: Vec<u8>, value: Vec<u8>) -> Self {
+        Self {
+            key: EventKey(key),
+            value: EventValue(value),
+        }
+    }
+
     /// Get the event key
     pub fn key(&self) -> &EventKey {
         &self.key
@@ -218,6 +226,12 @@ impl EventKey {
     }
 }
 
+impl From<Vec<u8>> for EventKey {
+    fn from(key: Vec<u8>) -> Self {
+        Self(key)
+    }
+}
+
 /// The value of an event. This is a wrapper around a `Vec<u8>`.
 #[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
 #[cfg_attr(any(test, feature = "fuzzing"), derive(proptest_derive::Arbitrary))]
@@ -17,7 +17,7 @@ use serde::{Deserialize, Serialize};
 use crate::da::DaSpec;
 
 /// The ZK proof generated by the [`ZkvmHost::run`] method.
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Proof {
     /// Only public input was generated.
     PublicInput(Vec<u8>),
@@ -129,6 +129,63 @@ pub struct StateTransition<Da: DaSpec, Root> {
     pub validity_condition: Da::ValidityCondition,
 }
 
+/// The public output of an aggregated proof, which chains the [`StateTransition`]s of a range
+/// of consecutive slots on the DA layer into a single claim.
+#[derive(Clone, Debug, Serialize, Deserialize, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
+pub struct AggregatedStateTransition<Da: DaSpec, Root> {
+    /// The state of the rollup before the first slot of the range
+    pub initial_state_root: Root,
+    /// The state of the rollup after the last slot of the range
+    pub final_state_root: Root,
+    /// The hash of the first slot of the range
+    pub initial_slot_hash: Da::SlotHash,
+    /// The hash of the last slot of the range
+    pub final_slot_hash: Da::SlotHash,
+    /// The validity conditions of all the slots of the range, combined into one.
+    pub validity_condition: Da::ValidityCondition,
+}
+
+impl<Da: DaSpec, Root: PartialEq> AggregatedStateTransition<Da, Root> {
+    /// Chains the state transitions of consecutive slots, given in order.
+    ///
+    /// Returns an error if there are no state transitions, if a state transition doesn't start
+    /// from the final state root of the previous one, or if the validity conditions can't be combined.
+    pub fn aggregate<H: Digest>(
+        state_transitions: impl IntoIterator<Item = StateTransition<Da, Root>>,
+    ) -> Result<Self, anyhow::Error> {
+        let mut state_transitions = state_transitions.into_iter();
+        let first = state_transitions
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("No state transitions to aggregate"))?;
+
+        let mut aggregated = Self {
+            initial_state_root: first.initial_state_root,
+            final_state_root: first.final_state_root,
+            initial_slot_hash: first.slot_hash.clone(),
+            final_slot_hash: first.slot_hash,
+            validity_condition: first.validity_condition,
+        };
+
+        for state_transition in state_transitions {
+            anyhow::ensure!(
+                state_transition.initial_state_root == aggregated.final_state_root,
+                "The state transition of slot {:?} doesn't start from the final state root of slot {:?}",
+                state_transition.slot_hash,
+                aggregated.final_slot_hash
+            );
+
+            aggregated.validity_condition = aggregated
+                .validity_condition
+                .combine::<H>(state_transition.validity_condition)
+                .map_err(Into::into)?;
+            aggregated.final_state_root = state_transition.final_state_root;
+            aggregated.final_slot_hash = state_transition.slot_hash;
+        }
+
+        Ok(aggregated)
+    }
+}
+
 /// This trait expresses that a type can check a validity condition.
 pub trait ValidityConditionChecker<Condition: ValidityCondition>:
     BorshDeserialize + BorshSerialize + Debug